@@ -1,11 +1,12 @@
 use std::time::Duration;
 
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use poly_ring_xnp1::{rand::CoeffsRangeInclusive, zq::ZqI64, Polynomial};
 use rand::{rng, RngExt};
 use ring_zk::{
-    LinearProofProver, LinearProofVerifier, OpenProofProver, OpenProofVerifier, Params,
-    SumProofProver, SumProofVerifier,
+    LinearProofProver, LinearProofVerifier, OpenProofProver, OpenProofResponse,
+    OpenProofVerificationContext, OpenProofVerifier, Params, SumProofProver, SumProofResponse,
+    SumProofVerificationContext, SumProofVerifier,
 };
 
 criterion_group! {
@@ -26,7 +27,32 @@ criterion_group! {
     targets = bench_sum_proof_commit, bench_sum_proof_generate_challenge, bench_sum_proof_create_response, bench_sum_proof_verify,
 }
 
-criterion_main!(open_proof, linear_proof, sum_proof);
+criterion_group! {
+    name = open_proof_batch;
+    config = Criterion::default().warm_up_time(Duration::from_secs(1)).sample_size(10).measurement_time(Duration::from_millis(4000));
+    targets = bench_open_proof_verify_sequential, bench_open_proof_verify_batch,
+}
+
+criterion_group! {
+    name = sum_proof_batch;
+    config = Criterion::default().warm_up_time(Duration::from_secs(1)).sample_size(10).measurement_time(Duration::from_millis(8000));
+    targets = bench_sum_proof_verify_sequential, bench_sum_proof_verify_many,
+}
+
+criterion_group! {
+    name = commit;
+    config = Criterion::default().warm_up_time(Duration::from_secs(1)).sample_size(10).measurement_time(Duration::from_millis(1000));
+    targets = bench_commit,
+}
+
+criterion_main!(
+    open_proof,
+    linear_proof,
+    sum_proof,
+    open_proof_batch,
+    sum_proof_batch,
+    commit
+);
 
 const N: usize = 512;
 
@@ -108,6 +134,39 @@ fn bench_open_proof_verify(c: &mut Criterion) {
     });
 }
 
+// ... bench functions for open_proof_batch ...
+
+const BATCH_SIZE: usize = 100;
+
+fn bench_open_proof_verify_sequential(c: &mut Criterion) {
+    let items = setup_open_proof_batch();
+    let (_, _, verifier) = &items.0;
+
+    c.bench_function("open_proof_verify_sequential", |b| {
+        b.iter_batched(
+            || items.1.clone(),
+            |items| {
+                _ = items.into_iter().all(|(response, verification_ctx)| {
+                    verifier.verify(response, verification_ctx)
+                });
+            },
+            criterion::BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_open_proof_verify_batch(c: &mut Criterion) {
+    let rng = &mut rng();
+    let items = setup_open_proof_batch();
+    let (_, _, verifier) = &items.0;
+
+    c.bench_function("open_proof_verify_batch", |b| {
+        b.iter(|| {
+            _ = verifier.verify_batch(rng, &items.1);
+        })
+    });
+}
+
 // ... bench functions for linear_proof ...
 
 fn bench_linear_proof_commit(c: &mut Criterion) {
@@ -198,106 +257,150 @@ fn bench_linear_proof_verify(c: &mut Criterion) {
 // ... bench functions for sum_proof ...
 
 const VL: usize = 4; // number of variables
+const VLS: [usize; 3] = [2, 4, 8]; // numbers of variables swept by the sum_proof group
 
 fn bench_sum_proof_commit(c: &mut Criterion) {
     let rng = &mut rng();
 
     let (params, prover, _) = setup_sum_proof_elements();
-    let bound = params.q.clone().into();
 
-    let xs = (0..VL)
-        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
-        .collect::<Vec<_>>();
-    let gs = (0..VL)
-        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
-        .collect::<Vec<_>>();
-
-    c.bench_function("sum_proof_commit", |b| {
-        b.iter_batched(
-            || (gs.clone(), xs.clone()),
-            |(gs, xs)| {
-                _ = prover.commit(rng, gs, xs);
-            },
-            criterion::BatchSize::SmallInput,
-        )
-    });
+    let mut group = c.benchmark_group("sum_proof_commit");
+    for vl in VLS {
+        let (gs, xs) = setup_sum_proof_values(rng, &params, vl);
+        group.bench_with_input(BenchmarkId::from_parameter(vl), &vl, |b, _| {
+            b.iter_batched(
+                || (gs.clone(), xs.clone()),
+                |(gs, xs)| {
+                    _ = prover.commit(rng, gs, xs);
+                },
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
 }
 
 fn bench_sum_proof_generate_challenge(c: &mut Criterion) {
     let rng = &mut rng();
 
     let (params, prover, verifier) = setup_sum_proof_elements();
-    let bound = params.q.clone().into();
 
-    let xs = (0..VL)
-        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
-        .collect::<Vec<_>>();
-    let gs = (0..VL)
-        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
-        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("sum_proof_generate_challenge");
+    for vl in VLS {
+        let (gs, xs) = setup_sum_proof_values(rng, &params, vl);
+        let (_, commitment) = prover.commit(rng, gs, xs);
+
+        group.bench_with_input(BenchmarkId::from_parameter(vl), &vl, |b, _| {
+            b.iter_batched(
+                || commitment.clone(),
+                |commitment| {
+                    _ = verifier.generate_challenge(rng, commitment);
+                },
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
 
-    let (_, commitment) = prover.commit(rng, gs, xs);
+fn bench_sum_proof_create_response(c: &mut Criterion) {
+    let rng = &mut rng();
 
-    c.bench_function("sum_proof_commit", |b| {
-        b.iter_batched(
-            || commitment.clone(),
-            |commitment| {
-                _ = verifier.generate_challenge(rng, commitment);
-            },
-            criterion::BatchSize::SmallInput,
-        )
-    });
+    let (params, prover, verifier) = setup_sum_proof_elements();
+
+    let mut group = c.benchmark_group("sum_proof_create_response");
+    for vl in VLS {
+        let (gs, xs) = setup_sum_proof_values(rng, &params, vl);
+        let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+        let (_, challenge) = verifier.generate_challenge(rng, commitment);
+
+        group.bench_with_input(BenchmarkId::from_parameter(vl), &vl, |b, _| {
+            b.iter_batched(
+                || (response_ctx.clone(), challenge.clone()),
+                |(response_ctx, challenge)| {
+                    _ = prover.create_response(response_ctx, challenge);
+                },
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
 }
 
-fn bench_sum_proof_create_response(c: &mut Criterion) {
+fn bench_sum_proof_verify(c: &mut Criterion) {
     let rng = &mut rng();
 
     let (params, prover, verifier) = setup_sum_proof_elements();
-    let bound = params.q.clone().into();
 
-    let xs = (0..VL)
-        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
-        .collect::<Vec<_>>();
-    let gs = (0..VL)
-        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
-        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("sum_proof_verify");
+    for vl in VLS {
+        let (gs, xs) = setup_sum_proof_values(rng, &params, vl);
+        let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+
+        group.bench_with_input(BenchmarkId::from_parameter(vl), &vl, |b, _| {
+            b.iter_batched(
+                || (verification_ctx.clone(), response.clone()),
+                |(verification_ctx, response)| {
+                    verifier.verify(response, verification_ctx);
+                },
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
 
-    let (response_ctx, commitment) = prover.commit(rng, gs, xs);
-    let (_, challenge) = verifier.generate_challenge(rng, commitment);
+// ... bench functions for sum_proof_batch ...
+
+const SUM_BATCH_SIZE: usize = 50;
+
+fn bench_sum_proof_verify_sequential(c: &mut Criterion) {
+    let items = setup_sum_proof_batch();
+    let (_, _, verifier) = &items.0;
 
-    c.bench_function("sum_proof_create_response", |b| {
+    c.bench_function("sum_proof_verify_sequential", |b| {
         b.iter_batched(
-            || (response_ctx.clone(), challenge.clone()),
-            |(response_ctx, challenge)| {
-                _ = prover.create_response(response_ctx, challenge);
+            || items.1.clone(),
+            |items| {
+                _ = items.into_iter().all(|(response, verification_ctx)| {
+                    verifier.verify(response, verification_ctx)
+                });
             },
             criterion::BatchSize::SmallInput,
         )
     });
 }
 
-fn bench_sum_proof_verify(c: &mut Criterion) {
+fn bench_sum_proof_verify_many(c: &mut Criterion) {
     let rng = &mut rng();
+    let items = setup_sum_proof_batch();
+    let (_, _, verifier) = &items.0;
 
-    let (params, prover, verifier) = setup_sum_proof_elements();
-    let bound = params.q.clone().into();
+    c.bench_function("sum_proof_verify_many", |b| {
+        b.iter(|| {
+            _ = verifier.verify_many(rng, &items.1);
+        })
+    });
+}
 
-    let xs = (0..VL)
-        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
-        .collect::<Vec<_>>();
-    let gs = (0..VL)
-        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
-        .collect::<Vec<_>>();
+// ... bench functions for commit ...
 
-    let (response_ctx, commitment) = prover.commit(rng, gs, xs);
-    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
-    let response = prover.create_response(response_ctx, challenge);
+// The commitment is dominated by the matrix products with the commitment key (k = 3 for the default
+// parameters). Run with `--features parallel` to compare against the parallel matrix multiplication,
+// whose rows are computed in parallel, so it only helps for the commitment keys with multiple rows (n + l > 1).
+fn bench_commit(c: &mut Criterion) {
+    let rng = &mut rng();
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
 
-    c.bench_function("sum_proof_verify", |b| {
+    c.bench_function("commit", |b| {
         b.iter_batched(
-            || (verification_ctx.clone(), response.clone()),
-            |(verification_ctx, response)| {
-                verifier.verify(response, verification_ctx);
+            || x.clone(),
+            |x| {
+                _ = ck.commit(rng, x, &params);
             },
             criterion::BatchSize::SmallInput,
         )
@@ -321,6 +424,36 @@ fn setup_open_proof_elements() -> (
     (params, prover, verifier)
 }
 
+#[allow(clippy::type_complexity)]
+fn setup_open_proof_batch() -> (
+    (
+        Params<ZqI64<3515337053_i64>>,
+        OpenProofProver<ZqI64<3515337053_i64>, N>,
+        OpenProofVerifier<ZqI64<3515337053_i64>, N>,
+    ),
+    Vec<(
+        OpenProofResponse<ZqI64<3515337053_i64>, N>,
+        OpenProofVerificationContext<ZqI64<3515337053_i64>, N>,
+    )>,
+) {
+    let rng = &mut rng();
+
+    let (params, prover, verifier) = setup_open_proof_elements();
+    let items = (0..BATCH_SIZE)
+        .map(|_| {
+            let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+            let (response_ctx, commitment) = prover.commit(rng, x);
+            let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+            (
+                prover.create_response(response_ctx, challenge),
+                verification_ctx,
+            )
+        })
+        .collect();
+
+    ((params, prover, verifier), items)
+}
+
 fn setup_linear_proof_elements() -> (
     Params<ZqI64<3515337053_i64>>,
     LinearProofProver<ZqI64<3515337053_i64>, N>,
@@ -351,6 +484,55 @@ fn setup_sum_proof_elements() -> (
     (params, prover, verifier)
 }
 
+#[allow(clippy::type_complexity)]
+fn setup_sum_proof_batch() -> (
+    (
+        Params<ZqI64<3515337053_i64>>,
+        SumProofProver<ZqI64<3515337053_i64>, N>,
+        SumProofVerifier<ZqI64<3515337053_i64>, N>,
+    ),
+    Vec<(
+        SumProofResponse<ZqI64<3515337053_i64>, N>,
+        SumProofVerificationContext<ZqI64<3515337053_i64>, N>,
+    )>,
+) {
+    let rng = &mut rng();
+
+    let (params, prover, verifier) = setup_sum_proof_elements();
+    let items = (0..SUM_BATCH_SIZE)
+        .map(|_| {
+            let (gs, xs) = setup_sum_proof_values(rng, &params, VL);
+            let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+            let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+            (
+                prover.create_response(response_ctx, challenge),
+                verification_ctx,
+            )
+        })
+        .collect();
+
+    ((params, prover, verifier), items)
+}
+
+#[allow(clippy::type_complexity)]
+fn setup_sum_proof_values(
+    rng: &mut impl RngExt,
+    params: &Params<ZqI64<3515337053_i64>>,
+    vl: usize,
+) -> (
+    Vec<Polynomial<ZqI64<3515337053_i64>, N>>,
+    Vec<Vec<Polynomial<ZqI64<3515337053_i64>, N>>>,
+) {
+    let bound = params.q.clone().into();
+    let gs = (0..vl)
+        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+        .collect();
+    let xs = (0..vl)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect();
+    (gs, xs)
+}
+
 fn random_value(rng: &mut impl RngExt, bound: i64) -> Vec<i64> {
     let range = CoeffsRangeInclusive::from(-bound..=bound);
     let p: Polynomial<i64, N> = rng.random_range(range);
@@ -1,15 +1,37 @@
 //! Defines the Challenge Space C and its difference C-bar, defined in section 2.2 of the paper.
+//!
+//! The challenges of the proofs in this library are sampled from the Challenge Space C. The functions are
+//! public for building custom Sigma protocols on top of the commitment scheme.
 
-use std::ops::{Add, Mul, Neg, Sub};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
 
 use num::{One, Zero};
 use poly_ring_xnp1::Polynomial;
-use rand::{distr::uniform::SampleUniform, seq::SliceRandom, RngExt};
+use rand::{
+    distr::uniform::SampleUniform, rngs::ChaCha20Rng, seq::SliceRandom, RngExt, SeedableRng,
+};
 
 /// Create a random polynomial in Challenge Space C.
 /// The Challenge Space C is defined as  `{c in R_q | norm_infinity(c) = 1, norm_1(c) = kappa}`.
 /// In other words, there exists exactly `kappa` amount of coefficients = 1 or -1, and the rest are 0.
-pub(crate) fn random_polynomial_from_challenge_set<I, const N: usize>(
+/// The `kappa` must not be larger than `N`, otherwise all the `N` coefficients are 1 or -1.
+///
+/// It is [random_polynomial_in_challenge_set] with `eta = 1`.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::challenge_space::random_polynomial_from_challenge_set;
+///
+/// let rng = &mut rand::rng();
+/// let c = random_polynomial_from_challenge_set::<i64, 256>(rng, 60);
+///
+/// // norm_1(c) = kappa and norm_infinity(c) = 1
+/// assert_eq!(c.iter().map(|c| c.abs()).sum::<i64>(), 60);
+/// assert_eq!(c.iter().map(|c| c.abs()).max(), Some(1));
+/// ```
+pub fn random_polynomial_from_challenge_set<I, const N: usize>(
     rng: &mut impl RngExt,
     kappa: usize,
 ) -> Polynomial<I, N>
@@ -17,26 +39,157 @@ where
     I: Clone + One + Zero + SampleUniform,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
 {
+    random_polynomial_in_challenge_set(rng, kappa, 1)
+}
+
+/// Create a random polynomial with exactly `kappa` non-zero coefficients, each uniform in
+/// `[-eta, eta] \ {0}`, and the rest are 0. It generalizes Challenge Space C to the set
+/// `{c in R_q | norm_infinity(c) <= eta, c has kappa non-zero coefficients}`, so that
+/// `kappa <= norm_1(c) <= eta * kappa` and `1 <= norm_infinity(c) <= eta`. The norm bounds of the
+/// proofs (which assume `eta = 1`) grow by the factor `eta` for the challenges from this set.
+///
+/// The `kappa` must not be larger than `N`, otherwise all the `N` coefficients are non-zero.
+///
+/// ## Panics
+/// Panics if `eta` is 0.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::challenge_space::random_polynomial_in_challenge_set;
+///
+/// let rng = &mut rand::rng();
+/// let c = random_polynomial_in_challenge_set::<i64, 256>(rng, 60, 2);
+///
+/// // 60 coefficients in {-2, -1, 1, 2}
+/// assert_eq!(c.iter().filter(|c| **c != 0).count(), 60);
+/// assert!(c.iter().all(|c| (-2..=2).contains(c)));
+/// ```
+pub fn random_polynomial_in_challenge_set<I, const N: usize>(
+    rng: &mut impl RngExt,
+    kappa: usize,
+    eta: usize,
+) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero + SampleUniform,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    assert!(eta > 0, "eta must be positive");
     let zero = I::zero();
-    let bound = I::one(); // since norm_infinity(c) = 1, the coefficients are in the range [-1, 1]
+    // the magnitudes 1, 2, ..., eta
+    let magnitudes = (1..eta).fold(vec![I::one()], |mut m, _| {
+        m.push(&m[m.len() - 1] + &I::one());
+        m
+    });
     let mut coeffs = (0..N).map(|_| zero.clone()).collect::<Vec<_>>();
     coeffs.iter_mut().take(kappa).for_each(|c| {
-        // either 1 or -1
+        let magnitude = if eta == 1 {
+            &magnitudes[0]
+        } else {
+            &magnitudes[rng.random_range(0..eta)]
+        };
+        // either positive or negative
         *c = if rng.random_bool(0.5) {
-            bound.clone()
+            magnitude.clone()
         } else {
-            &zero - &bound
+            &zero - magnitude
         };
     });
     coeffs.shuffle(rng);
     Polynomial::new(coeffs)
 }
 
+/// Create a random polynomial in Challenge Space C which is invariant under the automorphism `X -> X^(-1)`
+/// of `R_q`, i.e. `c(X^(-1)) = c(X)`. Since `X^(-1) = -X^(N-1)`, its coefficients satisfy `c_(N-i) = -c_i` for
+/// `0 < i < N`, so the non-zero coefficients are the constant term and the pairs `(c_i, c_(N-i))` for
+/// `0 < i < N/2`. There are at most `kappa` coefficients = 1 or -1 (exactly `kappa` if `kappa < N`),
+/// and the rest are 0.
+///
+/// The challenges are used by the proofs which check that a committed polynomial is fixed by the
+/// automorphism (e.g. the proof of range). The set is about the square root of the size of Challenge
+/// Space C for `N` much larger than `kappa`, so the soundness error of such proofs is larger.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::challenge_space::random_invariant_polynomial_from_challenge_set;
+///
+/// let rng = &mut rand::rng();
+/// let c = random_invariant_polynomial_from_challenge_set::<i64, 256>(rng, 60);
+/// let coeffs = (0..256).map(|i| c.iter().nth(i).copied().unwrap_or(0)).collect::<Vec<_>>();
+///
+/// // norm_1(c) = kappa and c_(N-i) = -c_i
+/// assert_eq!(coeffs.iter().map(|c| c.abs()).sum::<i64>(), 60);
+/// assert!((1..256).all(|i| coeffs[256 - i] == -coeffs[i]));
+/// ```
+pub fn random_invariant_polynomial_from_challenge_set<I, const N: usize>(
+    rng: &mut impl RngExt,
+    kappa: usize,
+) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    let (zero, one) = (I::zero(), I::one());
+    // either positive or negative
+    let sign = |positive: bool| {
+        if positive {
+            one.clone()
+        } else {
+            &zero - &one
+        }
+    };
+    // the positions 0 < i < N/2 of the pairs (c_i, c_(N-i)), and the constant term for the odd remainder
+    let pairs = (kappa / 2).min((N / 2).saturating_sub(1));
+    let mut positions = (1..N / 2).collect::<Vec<_>>();
+    positions.shuffle(rng);
+
+    let mut coeffs = (0..N).map(|_| zero.clone()).collect::<Vec<_>>();
+    if kappa > 2 * pairs {
+        coeffs[0] = sign(rng.random_bool(0.5));
+    }
+    positions.into_iter().take(pairs).for_each(|i| {
+        let c = sign(rng.random_bool(0.5));
+        coeffs[N - i] = &zero - &c;
+        coeffs[i] = c;
+    });
+    Polynomial::new(coeffs)
+}
+
+/// Create a polynomial in Challenge Space C deterministically from a 32-byte seed. The seed is
+/// expanded by a ChaCha20 stream, so that the same seed always gives the same polynomial.
+pub(crate) fn polynomial_from_challenge_set_with_seed<I, const N: usize>(
+    seed: [u8; 32],
+    kappa: usize,
+) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero + SampleUniform,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    let rng = &mut ChaCha20Rng::from_seed(seed);
+    random_polynomial_from_challenge_set(rng, kappa)
+}
+
 /// Create a random polynomial in Set difference (C-bar) in Challenge Space C.
 /// Defines C-bar as `{c - c', where c, c' in C}`. This difference `c - c'` has
-/// a special property that the returned polynomial is invertible in `R_q`.
-#[allow(unused)]
-pub(crate) fn random_polynomial_from_challenge_set_difference<I, const N: usize>(
+/// a special property that the returned polynomial is invertible in `R_q`, if the modulus
+/// satisfies the conditions of Lemma 1 of the paper (e.g. the modulus of the default `Params`).
+/// The returned polynomial is never zero, since `c` and `c'` are sampled until they differ.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::challenge_space::random_polynomial_from_challenge_set_difference;
+///
+/// let rng = &mut rand::rng();
+/// let c = random_polynomial_from_challenge_set_difference::<i64, 256>(rng, 60);
+///
+/// // the coefficients of c - c' are in the range [-2, 2], and norm_1(c - c') <= 2 * kappa
+/// assert!(c.iter().all(|c| (-2..=2).contains(c)));
+/// assert!(c.iter().map(|c| c.abs()).sum::<i64>() <= 120);
+/// assert!(c.iter().any(|c| *c != 0));
+/// ```
+pub fn random_polynomial_from_challenge_set_difference<I, const N: usize>(
     rng: &mut impl RngExt,
     kappa: usize,
 ) -> Polynomial<I, N>
@@ -53,10 +206,50 @@ where
     }
 }
 
+/// Empirical statistics of the polynomials sampled from Challenge Space C.
+#[cfg(test)]
+#[derive(Clone, Debug)]
+pub(crate) struct Stats {
+    /// Fraction of coefficients equal to 1.
+    pub(crate) plus_one: f64,
+    /// Fraction of coefficients equal to -1.
+    pub(crate) minus_one: f64,
+    /// Fraction of coefficients equal to 0.
+    pub(crate) zero: f64,
+    /// Average number of non-zero coefficients per polynomial.
+    pub(crate) avg_nonzero: f64,
+}
+
+/// Sample `samples` polynomials from Challenge Space C and collect the statistics of their
+/// coefficients. It is used for asserting the sampler is unbiased.
+#[cfg(test)]
+pub(crate) fn distribution_stats<const N: usize>(
+    rng: &mut impl RngExt,
+    kappa: usize,
+    samples: usize,
+) -> Stats {
+    let (mut plus_one, mut minus_one, mut zero) = (0usize, 0usize, 0usize);
+    for _ in 0..samples {
+        let c = random_polynomial_from_challenge_set::<i64, N>(rng, kappa);
+        // trailing zero coefficients are trimmed in the polynomial
+        let nonzero = c.iter().filter(|&&c| c != 0).count();
+        plus_one += c.iter().filter(|&&c| c == 1).count();
+        minus_one += c.iter().filter(|&&c| c == -1).count();
+        zero += N - nonzero;
+    }
+    let total = (samples * N) as f64;
+    Stats {
+        plus_one: plus_one as f64 / total,
+        minus_one: minus_one as f64 / total,
+        zero: zero as f64 / total,
+        avg_nonzero: (plus_one + minus_one) as f64 / samples as f64,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::polynomial::{norm_1, norm_infinity};
+    use crate::polynomial::{conjugate, norm_1, norm_infinity};
     use num::ToPrimitive;
 
     const N: usize = 256;
@@ -70,6 +263,56 @@ mod tests {
         assert_eq!(norm_infinity(&c).to_usize().unwrap(), 1);
     }
 
+    #[test]
+    fn test_random_polynomial_in_challenge_set_eta_2() {
+        let mut rng = rand::rng();
+        let kappa = 60;
+        let (mut ones, mut twos) = (0, 0);
+        for _ in 0..100 {
+            let c = random_polynomial_in_challenge_set::<i32, N>(&mut rng, kappa, 2);
+            assert_eq!(c.iter().filter(|c| **c != 0).count(), kappa);
+            let norm_1 = norm_1(&c).to_usize().unwrap();
+            assert!((kappa..=2 * kappa).contains(&norm_1));
+            assert!(norm_infinity(&c).to_usize().unwrap() <= 2);
+            ones += c.iter().filter(|c| c.abs() == 1).count();
+            twos += c.iter().filter(|c| c.abs() == 2).count();
+        }
+        // the magnitudes are uniform in {1, 2}
+        let ratio = ones as f64 / (ones + twos) as f64;
+        assert!((ratio - 0.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_random_invariant_polynomial_from_challenge_set() {
+        let mut rng = rand::rng();
+        for kappa in [60, 61] {
+            let c = random_invariant_polynomial_from_challenge_set::<i32, N>(&mut rng, kappa);
+            assert_eq!(norm_1(&c).to_usize().unwrap(), kappa);
+            assert_eq!(norm_infinity(&c).to_usize().unwrap(), 1);
+            assert_eq!(conjugate(&c), c);
+        }
+        // at most N - 1 coefficients are non-zero, since c_(N/2) = -c_(N/2)
+        let c = random_invariant_polynomial_from_challenge_set::<i32, 4>(&mut rng, 5);
+        assert_eq!(norm_1(&c).to_usize().unwrap(), 3);
+        assert_eq!(conjugate(&c), c);
+    }
+
+    #[test]
+    fn test_polynomial_from_challenge_set_with_seed() {
+        let kappa = 60;
+        let c = polynomial_from_challenge_set_with_seed::<i32, N>([1u8; 32], kappa);
+        assert_eq!(norm_1(&c).to_usize().unwrap(), kappa);
+        assert_eq!(norm_infinity(&c).to_usize().unwrap(), 1);
+        assert_eq!(
+            c,
+            polynomial_from_challenge_set_with_seed::<i32, N>([1u8; 32], kappa)
+        );
+        assert_ne!(
+            c,
+            polynomial_from_challenge_set_with_seed::<i32, N>([2u8; 32], kappa)
+        );
+    }
+
     #[test]
     fn test_random_polynomial_from_challenge_set_difference() {
         let mut rng = rand::rng();
@@ -79,4 +322,16 @@ mod tests {
             assert!(c >= &-2i32); // the coefficients are in the range [-1, 1] so the possible max is 2
         });
     }
+
+    #[test]
+    fn test_distribution_stats() {
+        let mut rng = rand::rng();
+        let kappa = 60;
+        let stats = distribution_stats::<N>(&mut rng, kappa, 1000);
+        assert_eq!(stats.avg_nonzero, kappa as f64);
+        assert!((stats.plus_one + stats.minus_one + stats.zero - 1.0).abs() < 1e-9);
+
+        let sign_ratio = stats.plus_one / (stats.plus_one + stats.minus_one);
+        assert!((sign_ratio - 0.5).abs() < 0.02);
+    }
 }
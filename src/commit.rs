@@ -1,13 +1,21 @@
 //! Definition of the commitment scheme, defined in section 4.1 of the paper.
 
-use std::ops::{Add, Mul, Sub};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
 
 use num::{FromPrimitive, One, ToPrimitive, Zero};
 use poly_ring_xnp1::Polynomial;
-use rand::{distr::uniform::SampleUniform, RngExt};
+use rand::{rngs::ChaCha20Rng, RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
 
-use crate::{mat::Mat, params::Params, polynomial::random_polynomial_within};
+use crate::{
+    challenge_space::random_polynomial_from_challenge_set_difference,
+    error::{CommitError, DecodeError, RingZkError},
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    polynomial::{centered_reduce, random_polynomial_within},
+};
 
 /// The commitment key for the commitment scheme. It is used by both the prover and the verifier.
 /// The prover uses it to commit to the message while the verifier uses it to verify the commitment.
@@ -16,17 +24,101 @@ use crate::{mat::Mat, params::Params, polynomial::random_polynomial_within};
 /// The size of the commitment key contains (n + l) x k polynomials, where n, k, and l are the parameters
 /// defined in the `Params` struct.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    from = "CommitmentKeyParts<I, N>",
+    into = "CommitmentKeyParts<I, N>",
+    bound(
+        serialize = "I: Clone + Serialize",
+        deserialize = "I: Clone + Deserialize<'de>"
+    )
+)]
 pub struct CommitmentKey<I, const N: usize>
 where
     I: Zero,
 {
     pub(crate) a1: Mat<I, N>, // n x k matrix
     pub(crate) a2: Mat<I, N>, // l x k matrix
+    // [a1 a2], the (n + l) x k matrix precomputed from a1 and a2. The key must be created by
+    // `from_parts` so that it stays consistent with a1 and a2.
+    a: Mat<I, N>,
 }
 
 impl<I, const N: usize> CommitmentKey<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
+    I: Clone + Zero,
+{
+    /// Create the commitment key from the matrices `a1` and `a2`, and precompute `[a1 a2]`.
+    pub(crate) fn from_parts(a1: Mat<I, N>, a2: Mat<I, N>) -> Self {
+        let mut a = a1.clone();
+        a.extend_rows(a2.clone());
+        CommitmentKey { a1, a2, a }
+    }
+
+    /// Returns the combined matrix `[a1 a2]`.
+    pub(crate) fn a(&self) -> &Mat<I, N> {
+        &self.a
+    }
+
+    /// Returns the matrix `a1` (an `n x k` matrix), which binds the randomness in the part `c1` of the
+    /// commitment.
+    pub fn a1(&self) -> &Mat<I, N> {
+        &self.a1
+    }
+}
+
+/// Returns the block-diagonal matrix `diag(mat, .., mat)` with `m` blocks.
+fn block_diag<I, const N: usize>(mat: &Mat<I, N>, m: usize) -> Mat<I, N>
+where
+    I: Clone + One + Zero,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    let (_, cols) = mat.dim();
+    let polynomials = (0..m)
+        .flat_map(|i| {
+            mat.polynomials.iter().map(move |row| {
+                let mut block_row = vec![Polynomial::<I, N>::zero(); m * cols];
+                block_row[i * cols..(i + 1) * cols].clone_from_slice(row);
+                block_row
+            })
+        })
+        .collect();
+    Mat { polynomials }
+}
+
+/// The serialized form of [CommitmentKey], which excludes the precomputed matrix `[a1 a2]`.
+#[derive(Clone, Serialize, Deserialize)]
+struct CommitmentKeyParts<I, const N: usize>
+where
+    I: Zero,
+{
+    a1: Mat<I, N>,
+    a2: Mat<I, N>,
+}
+
+impl<I, const N: usize> From<CommitmentKeyParts<I, N>> for CommitmentKey<I, N>
+where
+    I: Clone + Zero,
+{
+    fn from(parts: CommitmentKeyParts<I, N>) -> Self {
+        CommitmentKey::from_parts(parts.a1, parts.a2)
+    }
+}
+
+impl<I, const N: usize> From<CommitmentKey<I, N>> for CommitmentKeyParts<I, N>
+where
+    I: Zero,
+{
+    fn from(ck: CommitmentKey<I, N>) -> Self {
+        CommitmentKeyParts {
+            a1: ck.a1,
+            a2: ck.a2,
+        }
+    }
+}
+
+impl<I, const N: usize> CommitmentKey<I, N>
+where
+    I: RingInteger,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
 {
     /// Generate a random new commitment key given the parameters.
@@ -56,7 +148,67 @@ where
             tmp
         };
 
-        CommitmentKey { a1, a2 }
+        CommitmentKey::from_parts(a1, a2)
+    }
+
+    /// Generate a commitment key deterministically from a 32-byte seed. The same seed and parameters
+    /// always give the same commitment key, so that the key can be shared in compact form as a
+    /// [SeededCommitmentKey].
+    pub fn from_seed(seed: [u8; 32], params: &Params<I>) -> Self {
+        CommitmentKey::new(&mut ChaCha20Rng::from_seed(seed), params)
+    }
+
+    /// Returns the compact binary encoding of the commitment key. It is the matrix `a1` followed by the
+    /// matrix `a2`, each encoded in the same form as [Commitment::to_bytes]: the number of rows and
+    /// columns (4 bytes each), followed by the `N` coefficients of each polynomial reduced into `[0, q')`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{CommitmentKey, Params};
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let bytes = ck.to_bytes(&params);
+    /// let decoded = CommitmentKey::<_, N>::from_bytes(&bytes, &params).unwrap();
+    /// assert_eq!(decoded, ck);
+    /// ```
+    pub fn to_bytes(&self, params: &Params<I>) -> Vec<u8> {
+        let modulus = modulus(params);
+        let mut bytes = Vec::new();
+        encode_mat(&mut bytes, &self.a1, modulus);
+        encode_mat(&mut bytes, &self.a2, modulus);
+        bytes
+    }
+
+    /// Decode the commitment key from the encoding returned by [CommitmentKey::to_bytes]. The coefficients
+    /// are decoded into the centered range of the modulus q' = 2q + 1.
+    ///
+    /// ## Errors
+    /// Returns [DecodeError] if the input is truncated or has trailing bytes, the dimension of `a1` is not
+    /// `n x k` or that of `a2` is not `l x k`, or a coefficient is not less than `q'`.
+    pub fn from_bytes(bytes: &[u8], params: &Params<I>) -> Result<Self, DecodeError> {
+        let modulus = modulus(params);
+        let mut reader = ByteReader { bytes };
+        let a1 = reader.read_mat(modulus, (params.n, params.k))?;
+        let a2 = reader.read_mat(modulus, (params.l, params.k))?;
+        reader.finish()?;
+        Ok(CommitmentKey::from_parts(a1, a2))
+    }
+
+    /// Returns the commitment key of the commitments created by [Commitment::concat] from `m` commitments
+    /// under this key, i.e. the block-diagonal key with `a1' = diag(a1, .., a1)` (`mn x mk`) and
+    /// `a2' = diag(a2, .., a2)` (`ml x mk`). It is used with the parameters returned by [Params::concat].
+    ///
+    /// ## Panics
+    /// Panics if `m` is zero.
+    pub fn concat(&self, m: usize) -> Self {
+        assert!(m > 0, "at least one block is required");
+        CommitmentKey::from_parts(block_diag(&self.a1, m), block_diag(&self.a2, m))
     }
 
     /// Commit to the message `x` using the commitment key. It returns the opening and the commitment.
@@ -81,51 +233,368 @@ where
     /// This method contains a loop that generates a random polynomial `r` until the commitment constraint
     /// defined in the `Params` struct is satisfied. This check is to ensure the comitment can be verified
     /// correctly.
-    /// So it is important to ensure that the parameters are set carefully to avoid an infinite loop.
+    /// So it is important to ensure that the parameters are set carefully. The loop is bounded by
+    /// [DEFAULT_MAX_COMMIT_ATTEMPTS]; use [CommitmentKey::commit_with_limit] to choose the bound and handle
+    /// the error.
     ///
     /// ## Panics
-    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct, or
+    /// the constraint is not satisfied in [DEFAULT_MAX_COMMIT_ATTEMPTS] attempts.
     pub fn commit(
         &self,
         rng: &mut impl RngExt,
         x: Vec<Polynomial<I, N>>,
         params: &Params<I>,
     ) -> (Opening<I, N>, Commitment<I, N>) {
-        let Params { b, n, k, l, .. } = params.clone();
-        assert_eq!(l, x.len());
-
-        let x_mat = Mat::<I, N>::from_vec(x.clone());
-        let r = {
-            let mut tmp;
-            loop {
-                tmp = Mat::<I, N>::new_with(k, 1, || random_polynomial_within(rng, b.clone()));
-                if params.check_commit_constraint(&tmp) {
-                    break;
-                }
-            }
-            tmp
-        };
+        match self.commit_with_limit(rng, x, params, DEFAULT_MAX_COMMIT_ATTEMPTS) {
+            Ok(result) => result,
+            Err(e) => panic!("{e}"),
+        }
+    }
 
-        let a = {
-            // [a1 a2]
-            let mut a1 = self.a1.clone();
-            a1.extend_rows(self.a2.clone());
-            a1
-        };
+    /// Commit to the message `x` as [CommitmentKey::commit] does, but sample the randomness `r` at most
+    /// `max_attempts` times.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{CommitError, Params};
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (open, com) = ck.commit_with_limit(rng, x.clone(), &params, 10).unwrap();
+    /// assert!(com.verify(&open, &ck, &params));
+    ///
+    /// assert_eq!(
+    ///     ck.commit_with_limit(rng, x, &params, 0),
+    ///     Err(CommitError::RejectionLimitExceeded { attempts: 0 })
+    /// );
+    /// ```
+    ///
+    /// ## Errors
+    /// Returns [CommitError::RejectionLimitExceeded] if the commitment constraint is not satisfied in
+    /// `max_attempts` attempts.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit_with_limit(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+        params: &Params<I>,
+        max_attempts: usize,
+    ) -> Result<(Opening<I, N>, Commitment<I, N>), CommitError> {
+        assert_eq!(params.l, x.len());
+        let r = sample_randomness(rng, params, max_attempts)?;
+        Ok(self.commit_with_randomness(x, r, params.n))
+    }
 
-        let z = {
-            // [0_n x]
-            let mut tmp = Mat::<I, N>::from_element(n, 1, Polynomial::<I, N>::zero());
-            tmp.extend_rows(x_mat);
-            tmp
+    /// Commit to the message `x` as [CommitmentKey::commit] does, but place `x` at the rows
+    /// `[offset, offset + l)` of the commitment, i.e. `c = [a1 a2] * r + z` where `z` is `x` at those rows
+    /// and zero elsewhere. The default layout of the paper is `offset = n`, i.e. `z = [0_n x]`.
+    ///
+    /// It is for the compatibility with the variants of the scheme in external proof formats. Note that
+    /// the rest of the library assumes the default layout, e.g. [Commitment::verify] and the proofs only
+    /// accept the commitments with `offset = n`.
+    ///
+    /// ## Panics
+    /// Panics if
+    /// - the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    /// - `offset + l` exceeds `n + l`, i.e. `offset > n`.
+    /// - the constraint is not satisfied in [DEFAULT_MAX_COMMIT_ATTEMPTS] attempts.
+    pub fn commit_at_offset(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+        offset: usize,
+        params: &Params<I>,
+    ) -> (Opening<I, N>, Commitment<I, N>) {
+        assert_eq!(params.l, x.len());
+        assert!(
+            offset <= params.n,
+            "offset + l must not exceed n + l: offset = {offset}, n = {}",
+            params.n
+        );
+        let r = match sample_randomness(rng, params, DEFAULT_MAX_COMMIT_ATTEMPTS) {
+            Ok(r) => r,
+            Err(e) => panic!("{e}"),
         };
+        self.commit_with_randomness(x, r, offset)
+    }
 
+    /// Commit to the message `x` with the randomness `r`, placing `x` at the rows `[offset, offset + l)`.
+    fn commit_with_randomness(
+        &self,
+        x: Vec<Polynomial<I, N>>,
+        r: Mat<I, N>,
+        offset: usize,
+    ) -> (Opening<I, N>, Commitment<I, N>) {
         // Defined in equation (7) of the paper:
         // [c1 c2] = [a1 a2] * r + [0_n x]
-        let c = a.dot(&r).add(&z);
+        // The message is added to the rows [offset, offset + l) in place, so that the padded vector
+        // [0_offset x 0] is not materialized.
+        let mut c = self.a().dot(&r);
+        for (row, xi) in c.polynomials[offset..].iter_mut().zip(x.iter()) {
+            row[0] = core::mem::replace(&mut row[0], Polynomial::zero()) + xi.clone();
+        }
 
         (Opening { x, r, f: None }, Commitment { c })
     }
+
+    /// Commit to `m` messages (blocks) in one operation. It returns the openings and the commitments of the
+    /// blocks, in the same order as `blocks`.
+    ///
+    /// The blocks are committed under the block-diagonal key `diag([a1 a2], .., [a1 a2])` with the randomness
+    /// `[r_1 .. r_m]`, where each `r_i` is sampled independently as in [CommitmentKey::commit]. Because the key
+    /// is block-diagonal, the commitment to block `i` depends only on `r_i`, so each block has its own opening
+    /// and can be verified independently. The cost is amortized by computing `[a1 a2] * [r_1 .. r_m]` as one
+    /// matrix product.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let blocks = (0..4)
+    ///     .map(|i| params.prepare_value(vec![vec![i, i + 1, i + 2]]))
+    ///     .collect::<Vec<_>>();
+    /// let (opens, coms) = ck.commit_block(rng, blocks, &params);
+    /// assert_eq!(coms.len(), 4);
+    /// for (open, com) in opens.iter().zip(coms.iter()) {
+    ///     assert!(com.verify(open, &ck, &params));
+    /// }
+    /// assert!(!coms[0].verify(&opens[1], &ck, &params));
+    /// ```
+    ///
+    /// ## Safety
+    /// The same as [CommitmentKey::commit], the randomness of each block is sampled in a loop until the
+    /// commitment constraint is satisfied.
+    ///
+    /// ## Panics
+    /// Panics if the length of any block is not equal to the length of `l` defined in the `Params` struct,
+    /// or the constraint is not satisfied in [DEFAULT_MAX_COMMIT_ATTEMPTS] attempts for any block.
+    pub fn commit_block(
+        &self,
+        rng: &mut impl RngExt,
+        blocks: Vec<Vec<Polynomial<I, N>>>,
+        params: &Params<I>,
+    ) -> (Vec<Opening<I, N>>, Vec<Commitment<I, N>>) {
+        let Params { n, l, .. } = params.clone();
+        blocks.iter().for_each(|x| assert_eq!(l, x.len()));
+        if blocks.is_empty() {
+            return (vec![], vec![]);
+        }
+
+        let rs = blocks
+            .iter()
+            .map(
+                |_| match sample_randomness(rng, params, DEFAULT_MAX_COMMIT_ATTEMPTS) {
+                    Ok(r) => r,
+                    Err(e) => panic!("{e}"),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        // [a1 a2]
+        let a = self.a();
+
+        // [r_1 .. r_m] (k x m) and [0_n x_1 .. 0_n x_m] ((n + l) x m)
+        let mut r_mat = rs[0].clone();
+        let mut z = Mat::<I, N>::from_element(n, 1, Polynomial::<I, N>::zero());
+        z.extend_rows(Mat::<I, N>::from_vec(blocks[0].clone()));
+        for (r, x) in rs.iter().zip(blocks.iter()).skip(1) {
+            r_mat.extend_cols(r.clone());
+            let mut z_i = Mat::<I, N>::from_element(n, 1, Polynomial::<I, N>::zero());
+            z_i.extend_rows(Mat::<I, N>::from_vec(x.clone()));
+            z.extend_cols(z_i);
+        }
+
+        // [c_1 .. c_m] = [a1 a2] * [r_1 .. r_m] + [0_n x_1 .. 0_n x_m]
+        let c = a.dot(&r_mat).add(&z);
+
+        let coms = (0..blocks.len())
+            .map(|j| Commitment {
+                c: Mat::<I, N>::from_vec(c.polynomials.iter().map(|row| row[j].clone()).collect()),
+            })
+            .collect();
+        let opens = blocks
+            .into_iter()
+            .zip(rs)
+            .map(|(x, r)| Opening { x, r, f: None })
+            .collect();
+        (opens, coms)
+    }
+}
+
+/// The default maximum number of attempts to sample the randomness in [CommitmentKey::commit]. The
+/// commitment constraint is satisfied in the first attempt with an overwhelming probability for sane
+/// parameters, so the bound is only hit by a bad combination of `b` and `sigma`.
+pub const DEFAULT_MAX_COMMIT_ATTEMPTS: usize = 1 << 16;
+
+/// Sample the randomness `r` (k x 1) until the commitment constraint defined in the `Params` struct is
+/// satisfied, in at most `max_attempts` attempts.
+fn sample_randomness<I, const N: usize>(
+    rng: &mut impl RngExt,
+    params: &Params<I>,
+    max_attempts: usize,
+) -> Result<Mat<I, N>, CommitError>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    for _ in 0..max_attempts {
+        let r = Mat::<I, N>::new_with(params.k, 1, || {
+            random_polynomial_within(rng, params.b.clone())
+        });
+        if params.check_commit_constraint(&r) {
+            return Ok(r);
+        }
+    }
+    Err(CommitError::RejectionLimitExceeded {
+        attempts: max_attempts,
+    })
+}
+
+/// The compact form of a commitment key generated by [CommitmentKey::from_seed]. It contains only the
+/// seed and the parameters, and is expanded to the full commitment key on demand.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::{CommitmentKey, Params, SeededCommitmentKey};
+///
+/// const N: usize = 512; // Must be a power of two
+///
+/// let params = Params::default();
+/// let seed = [1u8; 32];
+/// let ck = CommitmentKey::<_, N>::from_seed(seed, &params);
+///
+/// let seeded_ck = SeededCommitmentKey::new(seed, params.clone());
+/// let bytes = bincode::serialize(&seeded_ck).unwrap();
+/// assert!(bytes.len() < bincode::serialize(&ck).unwrap().len());
+///
+/// let seeded_ck: SeededCommitmentKey<_> = bincode::deserialize(&bytes).unwrap();
+/// assert_eq!(seeded_ck.expand::<N>(), ck);
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SeededCommitmentKey<I> {
+    seed: [u8; 32],
+    params: Params<I>,
+}
+
+impl<I> SeededCommitmentKey<I>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    pub fn new(seed: [u8; 32], params: Params<I>) -> Self {
+        SeededCommitmentKey { seed, params }
+    }
+
+    /// Expand the seed to the full commitment key, which is the same as the one generated by
+    /// [CommitmentKey::from_seed] with the same seed and parameters.
+    pub fn expand<const N: usize>(&self) -> CommitmentKey<I, N> {
+        CommitmentKey::from_seed(self.seed, &self.params)
+    }
+}
+
+/// The builder to commit to a message given one polynomial at a time, for the messages with a large `l`.
+/// The message polynomials are pushed in order by [CommitmentBuilder::push], and the commitment is created
+/// by [CommitmentBuilder::build], which is the same as [CommitmentKey::commit] with the pushed polynomials.
+///
+/// The caller does not need to hold the message in a separate vector before committing, and the message
+/// rows are added into `[a1 a2] * r` in place without materializing the padded vector `[0_n x]`. The
+/// randomness `r` is sampled in [CommitmentBuilder::build], because the rejection sampling of `r` does
+/// not depend on the message.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::{CommitmentBuilder, Params};
+///
+/// const N: usize = 512; // Must be a power of two
+///
+/// let rng = &mut rand::rng();
+/// let params = Params::default();
+/// let ck = params.generate_commitment_key::<N>(rng);
+///
+/// let mut builder = CommitmentBuilder::new(&ck);
+/// for x in params.prepare_value(vec![vec![1, 2, 3, 4]]) {
+///     builder.push(x);
+/// }
+/// let (open, com) = builder.build(rng, &params);
+/// assert!(com.verify(&open, &ck, &params));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommitmentBuilder<'a, I, const N: usize>
+where
+    I: Zero,
+{
+    ck: &'a CommitmentKey<I, N>,
+    x: Vec<Polynomial<I, N>>,
+}
+
+impl<'a, I, const N: usize> CommitmentBuilder<'a, I, N>
+where
+    I: RingInteger,
+    for<'b> &'b I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Create the builder committing with the commitment key `ck`.
+    pub fn new(ck: &'a CommitmentKey<I, N>) -> Self {
+        let (l, _) = ck.a2.dim();
+        CommitmentBuilder {
+            ck,
+            x: Vec::with_capacity(l),
+        }
+    }
+
+    /// Push the next polynomial of the message.
+    ///
+    /// ## Panics
+    /// Panics if `l` polynomials (the number of rows of `a2` in the commitment key) have already been pushed.
+    pub fn push(&mut self, x: Polynomial<I, N>) {
+        let (l, _) = self.ck.a2.dim();
+        assert!(
+            self.x.len() < l,
+            "the message must have at most l = {l} polynomials"
+        );
+        self.x.push(x);
+    }
+
+    /// Returns the number of the polynomials pushed so far.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Returns `true` if no polynomial has been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Commit to the pushed polynomials. It returns the opening and the commitment, which are the same as
+    /// those returned by [CommitmentKey::commit] with the same message and random number generator.
+    ///
+    /// ## Panics
+    /// Panics if the number of the pushed polynomials is not equal to `l` defined in the `Params` struct, or
+    /// the constraint is not satisfied in [DEFAULT_MAX_COMMIT_ATTEMPTS] attempts.
+    pub fn build(
+        self,
+        rng: &mut impl RngExt,
+        params: &Params<I>,
+    ) -> (Opening<I, N>, Commitment<I, N>) {
+        self.ck.commit(rng, self.x, params)
+    }
 }
 
 /// The commitment in the commitment scheme.
@@ -142,7 +611,7 @@ where
 
 impl<I, const N: usize> Commitment<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
+    I: RingInteger,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
 {
     /// Verify the validity of opening r.s.t the commitment.
@@ -176,24 +645,50 @@ where
         ck: &CommitmentKey<I, N>,
         params: &Params<I>,
     ) -> bool {
-        let Params { n, .. } = params.clone();
         let Opening { x, r, f } = opening;
+        self.verify_parts(x, r, f.as_ref(), ck, params)
+    }
+
+    /// Verify the validity of the opening given by its parts, i.e. the message `x`, the randomness `r` and the
+    /// optional relaxation factor `f`, as [Commitment::verify] does. It is convenient when the opening is
+    /// not available as an [Opening], e.g. it is split across the fields of an external format.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (open, com) = ck.commit(rng, x.clone(), &params);
+    /// assert!(com.verify_parts(&x, open.r(), open.f(), &ck, &params));
+    /// ```
+    pub fn verify_parts(
+        &self,
+        x: &[Polynomial<I, N>],
+        r: &Mat<I, N>,
+        f: Option<&Polynomial<I, N>>,
+        ck: &CommitmentKey<I, N>,
+        params: &Params<I>,
+    ) -> bool {
+        let Params { n, .. } = params.clone();
 
         if !params.check_commit_constraint(r) {
             return false;
         }
 
-        let a = {
-            // [a1 a2]
-            let mut a1 = ck.a1.clone();
-            a1.extend_rows(ck.a2.clone());
-            a1
-        };
+        // [a1 a2]
+        let a = ck.a();
 
         let z = {
             // [0_n x]
             let mut tmp = Mat::<I, N>::from_element(n, 1, Polynomial::<I, N>::zero());
-            tmp.extend_rows(Mat::<I, N>::from_vec(x.clone()));
+            tmp.extend_rows(Mat::<I, N>::from_vec(x.to_vec()));
             tmp
         };
 
@@ -201,35 +696,724 @@ where
         // f * [c1 c2] = [a1 a2] * r + f * [0_n x]
         match f {
             Some(f) => {
-                let lhs = self.c.componentwise_mul(f);
-                let rhs = a.dot(r).add(&z.componentwise_mul(f));
+                let lhs = self.c.scalar_mul(f);
+                let rhs = a.dot(r).add(&z.scalar_mul(f));
                 lhs == rhs
             }
             None => a.dot(r).add(&z) == self.c,
         }
     }
 
-    /// Split the commitment into two parts: c1 (dim: n x 1) and c2 (dim: l x 1).
-    pub(crate) fn c1_c2(&self, params: &Params<I>) -> (Mat<I, N>, Mat<I, N>)
-    where
-        I: Clone,
-    {
-        self.c.clone().split_rows(params.n)
+    /// Check whether `open_a` and `open_b` break the binding property of the commitment, i.e. both are valid
+    /// openings of it (by [Commitment::verify]) but to different messages ([Opening::differs_from]). It should
+    /// never return `true` for honestly generated parameters, as finding such openings is computationally
+    /// infeasible under the Module-SIS assumption. It is meant for negative tests of the binding property.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let (open, com) = ck.commit(rng, params.prepare_value(vec![vec![1, 2, 3, 4]]), &params);
+    /// let (open2, _) = ck.commit(rng, params.prepare_value(vec![vec![4, 5, 6, 7]]), &params);
+    /// assert!(open.differs_from(&open2));
+    /// assert!(!com.check_binding(&open, &open2, &ck, &params));
+    /// ```
+    pub fn check_binding(
+        &self,
+        open_a: &Opening<I, N>,
+        open_b: &Opening<I, N>,
+        ck: &CommitmentKey<I, N>,
+        params: &Params<I>,
+    ) -> bool {
+        open_a.differs_from(open_b)
+            && self.verify(open_a, ck, params)
+            && self.verify(open_b, ck, params)
     }
-}
 
-/// The opening in the commitment scheme.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Opening<I, const N: usize>
-where
-    I: Zero,
-{
-    /// The committed value.
-    pub(crate) x: Vec<Polynomial<I, N>>,
-    /// The randomness used in the commit method.
-    pub(crate) r: Mat<I, N>,
-    /// Additional randomness for randomizing the opening.
-    /// The polynomial must be **non-zero** in Challenge Space.
-    /// None means the `f` is the `identity`` for verification.
-    pub(crate) f: Option<Polynomial<I, N>>,
+    /// Split the commitment into two parts: c1 (a vector of `n` polynomials) and c2 (a vector of `l` polynomials).
+    /// The parts can be transmitted separately and combined again by [Commitment::from_parts]. See
+    /// [Commitment::parts] for the meaning of the parts.
+    pub fn split(&self, params: &Params<I>) -> (Vec<Polynomial<I, N>>, Vec<Polynomial<I, N>>) {
+        let (c1, c2) = self.c1_c2(params);
+        (c1.one_d_mat_to_vec(), c2.one_d_mat_to_vec())
+    }
+
+    /// Reconstruct the commitment from the two parts c1 (a vector of `n` polynomials) and c2 (a vector
+    /// of `l` polynomials), which are usually obtained from [Commitment::split].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{Commitment, Params};
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (open, com) = ck.commit(rng, x, &params);
+    ///
+    /// let (c1, c2) = com.split(&params);
+    /// let com2 = Commitment::from_parts(c1, c2, &params).unwrap();
+    /// assert_eq!(com, com2);
+    /// assert!(com2.verify(&open, &ck, &params));
+    ///
+    /// let (c1, _) = com.split(&params);
+    /// assert!(Commitment::<_, N>::from_parts(c1, vec![], &params).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// Returns [RingZkError::DimensionMismatch] if the length of `c1` is not `n` or the length of `c2` is not `l`.
+    pub fn from_parts(
+        c1: Vec<Polynomial<I, N>>,
+        c2: Vec<Polynomial<I, N>>,
+        params: &Params<I>,
+    ) -> Result<Self, RingZkError> {
+        if c1.len() != params.n {
+            return Err(RingZkError::DimensionMismatch {
+                expected: params.n,
+                got: c1.len(),
+            });
+        }
+        if c2.len() != params.l {
+            return Err(RingZkError::DimensionMismatch {
+                expected: params.l,
+                got: c2.len(),
+            });
+        }
+        let mut c = Mat::<I, N>::from_vec(c1);
+        c.extend_rows(Mat::<I, N>::from_vec(c2));
+        Ok(Commitment { c })
+    }
+
+    /// Add the commitments homomorphically. The result is a commitment to `x_a + x_b` with randomness
+    /// `r_a + r_b`, where `x_a` and `x_b` are the values committed by `self` and `other` respectively. The
+    /// result is opened by the sum of the openings ([Opening::add]).
+    ///
+    /// ## Safety
+    /// The norm of the randomness grows with the additions, so the summed opening may exceed the bound in
+    /// the commitment constraint and be rejected by [Commitment::verify] after many additions.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let (open_a, com_a) = ck.commit(rng, params.prepare_value(vec![vec![1, 2, 3]]), &params);
+    /// let (open_b, com_b) = ck.commit(rng, params.prepare_value(vec![vec![4, 5]]), &params);
+    ///
+    /// let com = com_a.add(&com_b);
+    /// let open = open_a.add(&open_b);
+    /// assert!(com.verify(&open, &ck, &params));
+    /// ```
+    pub fn add(&self, other: &Commitment<I, N>) -> Commitment<I, N> {
+        Commitment {
+            c: self.c.add(&other.c),
+        }
+    }
+
+    /// Concatenate the commitments to `x_1, .., x_m` into a commitment to the stacked message
+    /// `[x_1 .. x_m]`, which is opened by the concatenation of the openings ([Opening::concat]). The result
+    /// is `[c1_1 .. c1_m c2_1 .. c2_m]`, i.e. a commitment under the block-diagonal key
+    /// ([CommitmentKey::concat]) with the parameters [Params::concat], where `n`, `k` and `l` are multiplied
+    /// by `m`, and the randomness is the stacked `[r_1 .. r_m]`.
+    ///
+    /// The result has the same size as the `m` commitments, so it does not save space; it allows the
+    /// message vector to be handled as a single commitment, e.g. by [Commitment::verify] and the proofs
+    /// with the concatenated parameters. Each polynomial of the randomness is unchanged, so the opening
+    /// satisfies the commitment constraint of the concatenated parameters, whose norm bounds are larger
+    /// by about `sqrt(m)` because the standard deviation grows with `sqrt(k)`. The larger bound weakens
+    /// the binding property accordingly (see [Params::security_estimate]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{Commitment, Opening, Params};
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let (open_a, com_a) = ck.commit(rng, params.prepare_value(vec![vec![1, 2, 3]]), &params);
+    /// let (open_b, com_b) = ck.commit(rng, params.prepare_value(vec![vec![4, 5]]), &params);
+    ///
+    /// let com = Commitment::concat(&[com_a, com_b], &params);
+    /// let open = Opening::concat(&[open_a, open_b]);
+    /// assert!(com.verify(&open, &ck.concat(2), &params.concat(2)));
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if `commitments` is empty.
+    pub fn concat(commitments: &[Commitment<I, N>], params: &Params<I>) -> Commitment<I, N> {
+        assert!(
+            !commitments.is_empty(),
+            "at least one commitment is required"
+        );
+        let (mut c, mut c2) = commitments[0].c1_c2(params);
+        for commitment in &commitments[1..] {
+            let (c1_i, c2_i) = commitment.c1_c2(params);
+            c.extend_rows(c1_i);
+            c2.extend_rows(c2_i);
+        }
+        c.extend_rows(c2);
+        Commitment { c }
+    }
+
+    /// Rerandomize the commitment, so that it can be forwarded without being linked to the original one.
+    /// It returns a new commitment to the same value as `self` (opened by `opening`) and the opening of it.
+    ///
+    /// The new commitment is `c + [a1 a2] * r'` for fresh randomness `r'`. The new opening has the additional
+    /// randomness `f` sampled from the set difference of Challenge Space C, which is non-zero and invertible
+    /// in `R_q`, and the randomness `f * (r + r')` s.t. `f * c' = [a1 a2] * f * (r + r') + f * [0 x]`. If
+    /// `opening` has `f` already, the new `f` is multiplied by it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let (open, com) = ck.commit(rng, params.prepare_value(vec![vec![1, 2, 3]]), &params);
+    /// let (com2, open2) = com.rerandomize(rng, &open, &ck, &params);
+    /// assert_ne!(com, com2);
+    /// assert!(com2.verify(&open2, &ck, &params));
+    /// assert!(!com.verify(&open2, &ck, &params));
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the fresh randomness does not satisfy the commitment constraint in [DEFAULT_MAX_COMMIT_ATTEMPTS]
+    /// attempts.
+    pub fn rerandomize(
+        &self,
+        rng: &mut impl RngExt,
+        opening: &Opening<I, N>,
+        ck: &CommitmentKey<I, N>,
+        params: &Params<I>,
+    ) -> (Commitment<I, N>, Opening<I, N>)
+    where
+        for<'a> &'a I: Neg<Output = I>,
+    {
+        let rp = match sample_randomness::<I, N>(rng, params, DEFAULT_MAX_COMMIT_ATTEMPTS) {
+            Ok(rp) => rp,
+            Err(e) => panic!("{e}"),
+        };
+        // [a1 a2]
+        let a = ck.a();
+        let c = self.c.add(&a.dot(&rp));
+
+        let f = random_polynomial_from_challenge_set_difference::<I, N>(rng, params.kappa);
+        let (r, f) = match &opening.f {
+            Some(f_old) => (
+                opening.r.add(&rp.scalar_mul(f_old)).scalar_mul(&f),
+                f * f_old.clone(),
+            ),
+            None => (opening.r.add(&rp).scalar_mul(&f), f),
+        };
+        (
+            Commitment { c },
+            Opening {
+                x: opening.x.clone(),
+                r,
+                f: Some(f),
+            },
+        )
+    }
+
+    /// Reduce the coefficients of the commitment into the centered range of the modulus q' = 2q + 1,
+    /// without changing their residues. See [Opening::canonicalize].
+    pub fn canonicalize(&mut self, params: &Params<I>) {
+        let modulus = modulus(params);
+        self.c
+            .polynomials
+            .iter_mut()
+            .flatten()
+            .for_each(|p| *p = centered_reduce(p, modulus));
+    }
+
+    /// Returns the compact binary encoding of the commitment. It is the number of rows and columns
+    /// (4 bytes each), followed by the `N` coefficients of each polynomial reduced into `[0, q')`, each
+    /// packed into the minimum number of bytes to represent `q' - 1`, all in little-endian form.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{Commitment, Params};
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (open, com) = ck.commit(rng, x, &params);
+    /// let bytes = com.to_bytes(&params);
+    /// let decoded = Commitment::<_, N>::from_bytes(&bytes, &params).unwrap();
+    /// assert!(decoded.verify(&open, &ck, &params));
+    /// ```
+    pub fn to_bytes(&self, params: &Params<I>) -> Vec<u8> {
+        let modulus = modulus(params);
+        let mut bytes = Vec::new();
+        encode_mat(&mut bytes, &self.c, modulus);
+        bytes
+    }
+
+    /// Decode the commitment from the encoding returned by [Commitment::to_bytes]. The coefficients
+    /// are decoded into the centered range of the modulus q' = 2q + 1 (see [Commitment::canonicalize]).
+    ///
+    /// ## Errors
+    /// Returns [DecodeError] if the input is truncated or has trailing bytes, the dimension is not
+    /// `(n + l) x 1`, or a coefficient is not less than `q'`.
+    pub fn from_bytes(bytes: &[u8], params: &Params<I>) -> Result<Self, DecodeError> {
+        let mut reader = ByteReader { bytes };
+        let c = reader.read_mat(modulus(params), (params.n + params.l, 1))?;
+        reader.finish()?;
+        Ok(Commitment { c })
+    }
+
+    /// Split the commitment into its two parts `(c1, c2)`, where
+    /// - `c1 = A1 * r` (dim: n x 1) is the binding part, which only depends on the randomness `r`, and
+    /// - `c2 = A2 * r + x` (dim: l x 1) carries the message `x` offset by `A2 * r`.
+    ///
+    /// See [Commitment::split] for the parts as vectors of polynomials.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (_, com) = ck.commit(rng, x, &params);
+    /// let (c1, c2) = com.parts(&params);
+    /// assert_eq!(c1.dim(), (params.n, 1));
+    /// assert_eq!(c2.dim(), (params.l, 1));
+    /// ```
+    pub fn parts(&self, params: &Params<I>) -> (Mat<I, N>, Mat<I, N>)
+    where
+        I: Clone,
+    {
+        self.c1_c2(params)
+    }
+
+    /// Split the commitment into two parts: c1 (dim: n x 1) and c2 (dim: l x 1), i.e. c2 is the last `l` rows.
+    pub(crate) fn c1_c2(&self, params: &Params<I>) -> (Mat<I, N>, Mat<I, N>)
+    where
+        I: Clone,
+    {
+        self.c.clone().split_rows(params.l)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<I, const N: usize> Commitment<I, N>
+where
+    I: Zero + ToPrimitive,
+{
+    /// Compare the commitments in constant time w.r.t. their coefficients, e.g. for comparing a received
+    /// commitment to an expected one derived from a secret. It returns `Choice(1)` if and only if the
+    /// commitments are equal (`==`). The derived `==` returns at the first differing coefficient, so it is for
+    /// the comparisons of non-secret commitments only.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let (_, com) = ck.commit(rng, params.prepare_value(vec![vec![1, 2, 3]]), &params);
+    /// let (_, com2) = ck.commit(rng, params.prepare_value(vec![vec![1, 2, 3]]), &params);
+    /// assert!(bool::from(com.ct_eq(&com.clone())));
+    /// assert!(!bool::from(com.ct_eq(&com2)));
+    /// ```
+    pub fn ct_eq(&self, other: &Commitment<I, N>) -> subtle::Choice {
+        self.c.ct_eq(&other.c)
+    }
+}
+
+/// The opening in the commitment scheme.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Opening<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The committed value.
+    pub(crate) x: Vec<Polynomial<I, N>>,
+    /// The randomness used in the commit method.
+    pub(crate) r: Mat<I, N>,
+    /// Additional randomness for randomizing the opening.
+    /// The polynomial must be **non-zero** in Challenge Space.
+    /// None means the `f` is the `identity`` for verification.
+    pub(crate) f: Option<Polynomial<I, N>>,
+}
+
+impl<I, const N: usize> Opening<I, N>
+where
+    I: Zero,
+{
+    /// The committed message `x`.
+    pub fn x(&self) -> &[Polynomial<I, N>] {
+        &self.x
+    }
+
+    /// The randomness `r` (a `k x 1` matrix) used in the commit method.
+    pub fn r(&self) -> &Mat<I, N> {
+        &self.r
+    }
+
+    /// The relaxation factor `f`, or `None` if it is the identity.
+    pub fn f(&self) -> Option<&Polynomial<I, N>> {
+        self.f.as_ref()
+    }
+}
+
+impl<I, const N: usize> Opening<I, N>
+where
+    I: Zero + PartialEq,
+{
+    /// Returns `true` if the committed messages `x` of the openings are different, regardless of the
+    /// randomness. Note that the coefficients are compared as they are, so the messages should be prepared
+    /// in the same way (e.g. by [Params::prepare_value]).
+    pub fn differs_from(&self, other: &Opening<I, N>) -> bool {
+        self.x != other.x
+    }
+}
+
+impl<I, const N: usize> Opening<I, N>
+where
+    I: Clone + One + Zero + FromPrimitive + ToPrimitive,
+{
+    /// Reduce the coefficients of the randomness `r` into the centered range `[-q, q]` of the modulus
+    /// q' = 2q + 1, without changing their residues. After homomorphic operations on openings, the
+    /// coefficients may fall outside the range of representatives even though the norm is small, so
+    /// the opening should be canonicalized before [Commitment::verify] (together with the commitment
+    /// by [Commitment::canonicalize]) to make the comparison independent of representatives.
+    ///
+    /// For coefficient types which are always reduced (e.g. `ZqI64`), it is a no-op.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (mut open, mut com) = ck.commit(rng, x, &params);
+    /// open.canonicalize(&params);
+    /// com.canonicalize(&params);
+    /// assert!(com.verify(&open, &ck, &params));
+    /// ```
+    pub fn canonicalize(&mut self, params: &Params<I>) {
+        let modulus = modulus(params);
+        self.r
+            .polynomials
+            .iter_mut()
+            .flatten()
+            .for_each(|p| *p = centered_reduce(p, modulus));
+    }
+
+    /// Returns the coefficients of the committed message `x`, reduced into the centered range `[-q, q]`
+    /// of the modulus q' = 2q + 1, i.e. `(-q'/2, q'/2]`. It is the inverse of [Params::prepare_value] for
+    /// the values in that range, so that the party who learns the opening can recover the exact message.
+    /// The trailing zero coefficients of each polynomial are omitted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use num::ToPrimitive;
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, -2, 3, 4]]);
+    /// let (open, _) = ck.commit(rng, x, &params);
+    /// let coeffs = open.message_coeffs(&params);
+    /// let coeffs = coeffs[0].iter().map(|c| c.to_i64().unwrap()).collect::<Vec<_>>();
+    /// assert_eq!(coeffs, vec![1, -2, 3, 4]);
+    /// ```
+    pub fn message_coeffs(&self, params: &Params<I>) -> Vec<Vec<I>> {
+        let modulus = modulus(params);
+        self.x
+            .iter()
+            .map(|p| centered_reduce(p, modulus).iter().cloned().collect())
+            .collect()
+    }
+}
+
+impl<I, const N: usize> Opening<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Returns `true` if the randomness `r` satisfies the norm bound of the commitment constraint, i.e. the
+    /// same check on `r` as [Commitment::verify] performs (in the norm selected by [Params::norm]). It detects
+    /// an opening whose norm has grown beyond the bound, e.g. after many homomorphic additions ([Opening::add]),
+    /// without verifying it against a commitment.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (open, _) = ck.commit(rng, x, &params);
+    /// assert!(open.is_valid_norm(&params));
+    /// ```
+    pub fn is_valid_norm(&self, params: &Params<I>) -> bool {
+        params.check_commit_constraint(&self.r)
+    }
+
+    /// Returns the maximum norm over the polynomials of the randomness `r`, together with the bound
+    /// ([Params::commit_norm_bound]) it is checked against, in the norm selected by [Params::norm]. It tells
+    /// how close the randomness is to the bound, e.g. a norm close to the bound for the 2-norm suggests
+    /// that `b` is too large relative to `sigma`, so that the rejection sampling in
+    /// [CommitmentKey::commit] takes many attempts.
+    ///
+    /// Unlike [Opening::is_valid_norm], it is not constant-time with the feature `constant-time`, as the
+    /// norm itself is reported.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512; // Must be a power of two
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value(vec![vec![1, 2, 3, 4]]);
+    /// let (open, _) = ck.commit(rng, x, &params);
+    /// let diagnostics = open.norm_diagnostics(&params);
+    /// assert!(diagnostics.max_norm <= diagnostics.bound);
+    /// ```
+    pub fn norm_diagnostics(&self, params: &Params<I>) -> NormDiagnostics {
+        NormDiagnostics {
+            max_norm: params.max_norm(&self.r),
+            bound: params.commit_norm_bound::<N>(),
+        }
+    }
+}
+
+/// The norm of the randomness of an opening and its bound, returned by [Opening::norm_diagnostics].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NormDiagnostics {
+    /// The maximum norm over the polynomials of the randomness `r`.
+    pub max_norm: u128,
+    /// The bound of the commitment constraint ([Params::commit_norm_bound]).
+    pub bound: u128,
+}
+
+impl NormDiagnostics {
+    /// The ratio of the norm to the bound. The randomness satisfies the constraint iff it is at most 1.
+    pub fn ratio(&self) -> f64 {
+        self.max_norm as f64 / self.bound as f64
+    }
+}
+
+impl<I, const N: usize> Opening<I, N>
+where
+    I: Clone + One + Zero,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Add the openings, so that the result opens the sum of the commitments ([Commitment::add]).
+    /// The committed values are added. If any of the openings has the randomness `f`, the result
+    /// has randomness `r = f_b * r_a + f_a * r_b` and `f = f_a * f_b`, which satisfies
+    /// `f * (c_a + c_b) = [a1 a2] * r + f * [0 (x_a + x_b)]`.
+    ///
+    /// ## Panics
+    /// Panics if the openings have different dimensions.
+    pub fn add(&self, other: &Opening<I, N>) -> Opening<I, N> {
+        assert!(self.x.len() == other.x.len() && self.r.dim() == other.r.dim());
+        let x = self
+            .x
+            .iter()
+            .zip(other.x.iter())
+            .map(|(a, b)| a.clone() + b.clone())
+            .collect();
+        let (r, f) = match (&self.f, &other.f) {
+            (None, None) => (self.r.add(&other.r), None),
+            (Some(f), None) => (self.r.add(&other.r.scalar_mul(f)), Some(f.clone())),
+            (None, Some(f)) => (self.r.scalar_mul(f).add(&other.r), Some(f.clone())),
+            (Some(f_a), Some(f_b)) => (
+                self.r.scalar_mul(f_b).add(&other.r.scalar_mul(f_a)),
+                Some(f_a.clone() * f_b.clone()),
+            ),
+        };
+        Opening { x, r, f }
+    }
+
+    /// Concatenate the openings of `x_1, .., x_m` into the opening of the stacked message `[x_1 .. x_m]`
+    /// with the stacked randomness `[r_1 .. r_m]`. It opens the commitment returned by [Commitment::concat].
+    ///
+    /// ## Panics
+    /// Panics if `openings` is empty, or any of the openings has the randomness `f` (a relaxed opening).
+    pub fn concat(openings: &[Opening<I, N>]) -> Opening<I, N> {
+        assert!(!openings.is_empty(), "at least one opening is required");
+        assert!(
+            openings.iter().all(|o| o.f.is_none()),
+            "relaxed openings cannot be concatenated"
+        );
+        let x = openings.iter().flat_map(|o| o.x.iter().cloned()).collect();
+        let mut r = openings[0].r.clone();
+        openings[1..]
+            .iter()
+            .for_each(|o| r.extend_rows(o.r.clone()));
+        Opening { x, r, f: None }
+    }
+}
+
+/// Returns the number of bytes to represent the integers in `[0, modulus)`.
+fn coefficient_width(modulus: i128) -> usize {
+    let bits = 128 - (modulus - 1).leading_zeros() as usize;
+    bits.div_ceil(8).max(1)
+}
+
+/// Reads the bytes sequentially from the input.
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn read(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < len {
+            return Err(DecodeError::Truncated);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    /// Reads a matrix in the form written by [encode_mat], and checks that its dimension is `expected`.
+    /// The coefficients are decoded into the centered range of the `modulus`.
+    fn read_mat<I, const N: usize>(
+        &mut self,
+        modulus: i128,
+        expected: (usize, usize),
+    ) -> Result<Mat<I, N>, DecodeError>
+    where
+        I: Zero + FromPrimitive,
+    {
+        let width = coefficient_width(modulus);
+        let rows = u32::from_le_bytes(self.read(4)?.try_into().unwrap()) as usize;
+        let cols = u32::from_le_bytes(self.read(4)?.try_into().unwrap()) as usize;
+        if (rows, cols) != expected {
+            return Err(DecodeError::DimensionMismatch {
+                expected,
+                got: (rows, cols),
+            });
+        }
+
+        let half = modulus / 2;
+        let mut read_polynomial = || {
+            let coeffs = (0..N)
+                .map(|_| {
+                    let mut buf = [0u8; 16];
+                    buf[..width].copy_from_slice(self.read(width)?);
+                    let c = u128::from_le_bytes(buf) as i128;
+                    if c >= modulus {
+                        return Err(DecodeError::CoefficientOutOfRange);
+                    }
+                    let c = if c > half { c - modulus } else { c };
+                    I::from_i128(c).ok_or(DecodeError::CoefficientOutOfRange)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Polynomial::new(coeffs))
+        };
+        let polynomials = (0..rows)
+            .map(|_| (0..cols).map(|_| read_polynomial()).collect())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Mat { polynomials })
+    }
+
+    /// Checks that all the input has been read.
+    fn finish(&self) -> Result<(), DecodeError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(DecodeError::TrailingBytes {
+                len: self.bytes.len(),
+            })
+        }
+    }
+}
+
+/// Writes the number of rows and columns of the matrix (4 bytes each), followed by the `N` coefficients
+/// of each polynomial in row-major order, reduced into `[0, modulus)` and packed into the minimum number
+/// of bytes to represent `modulus - 1`, all in little-endian form.
+fn encode_mat<I, const N: usize>(bytes: &mut Vec<u8>, mat: &Mat<I, N>, modulus: i128)
+where
+    I: Zero + ToPrimitive,
+{
+    let width = coefficient_width(modulus);
+    let (rows, cols) = mat.dim();
+    bytes.reserve(8 + rows * cols * N * width);
+    bytes.extend_from_slice(&(rows as u32).to_le_bytes());
+    bytes.extend_from_slice(&(cols as u32).to_le_bytes());
+    for p in mat.polynomials.iter().flatten() {
+        let mut coeffs = p
+            .iter()
+            .map(|c| c.to_i128().unwrap().rem_euclid(modulus) as u128)
+            .collect::<Vec<_>>();
+        coeffs.resize(N, 0);
+        for c in coeffs {
+            bytes.extend_from_slice(&c.to_le_bytes()[..width]);
+        }
+    }
+}
+
+/// Returns the modulus q' = 2q + 1, where `q` is defined in the `Params` struct.
+fn modulus<I>(params: &Params<I>) -> i128
+where
+    I: ToPrimitive,
+{
+    2 * params.q.to_i128().unwrap() + 1
 }
@@ -0,0 +1,69 @@
+//! An utilities module for the Chinese Remainder Theorem (CRT). It packs residues under
+//! pairwise coprime moduli into a single integer and recovers them.
+
+use alloc::vec::Vec;
+use num::{integer::Integer, One};
+
+/// Returns the product of the moduli.
+///
+/// ## Panics
+/// Panics if the product overflows `u64`.
+pub(crate) fn crt_modulus(moduli: &[u64]) -> u64 {
+    moduli
+        .iter()
+        .try_fold(1u64, |acc, m| acc.checked_mul(*m))
+        .expect("product of moduli overflows")
+}
+
+/// Returns the unique integer `x` in `[0, m_1 * m_2 * ...)` s.t. `x = r_i (mod m_i)` for all `i`.
+///
+/// ## Panics
+/// Panics if the lengths of `residues` and `moduli` are not equal, the moduli are not pairwise
+/// coprime, or any residue is not less than its modulus.
+pub(crate) fn crt_combine(residues: &[u64], moduli: &[u64]) -> u64 {
+    assert_eq!(residues.len(), moduli.len());
+    let m = crt_modulus(moduli) as i128;
+    residues
+        .iter()
+        .zip(moduli)
+        .map(|(&r, &m_i)| {
+            assert!(r < m_i, "residue is not less than its modulus");
+            let m_i = m_i as i128;
+            let m_div_mi = m / m_i;
+            // inverse of (m / m_i) modulo m_i
+            let egcd = (m_div_mi % m_i).extended_gcd(&m_i);
+            assert!(egcd.gcd.is_one(), "moduli are not pairwise coprime");
+            let inv = egcd.x.rem_euclid(m_i);
+            (r as i128 * inv).rem_euclid(m_i) * m_div_mi
+        })
+        .fold(0i128, |acc, x| (acc + x) % m) as u64
+}
+
+/// Returns the residues of `x` modulo each of the moduli.
+pub(crate) fn crt_reduce(x: i128, moduli: &[u64]) -> Vec<u64> {
+    moduli
+        .iter()
+        .map(|&m_i| x.rem_euclid(m_i as i128) as u64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_crt_combine() {
+        let moduli = [3, 5, 7];
+        let x = crt_combine(&[2, 3, 2], &moduli);
+        assert_eq!(x, 23);
+        assert_eq!(crt_reduce(x as i128, &moduli), vec![2, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crt_combine_not_coprime() {
+        crt_combine(&[1, 1], &[4, 6]);
+    }
+}
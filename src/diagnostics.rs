@@ -0,0 +1,137 @@
+//! Utilities for inspecting the distributions of polynomials, enabled by the feature `diagnostics`.
+//!
+//! They are intended for tuning the parameters and debugging, e.g. checking that the coefficients of the
+//! masking polynomials `y` (see [OpenProofResponseContext::y](crate::OpenProofResponseContext::y)) spread
+//! around zero with the standard deviation [Params::sigma_exact](crate::Params::sigma_exact). They are not
+//! needed by the protocols.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{diagnostics::coeff_histogram, OpenProofProver, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let prover = OpenProofProver::new(ck, params.clone());
+//!
+//! let ys = (0..10)
+//!     .flat_map(|_| {
+//!         let (response_ctx, _) = prover.commit(rng, params.prepare_value::<N>(vec![vec![1]]));
+//!         response_ctx.y().iter().cloned().collect::<Vec<_>>()
+//!     })
+//!     .collect::<Vec<_>>();
+//!
+//! let histogram = coeff_histogram(&ys, 9);
+//! assert_eq!(histogram.iter().sum::<usize>(), ys.len() * N);
+//! // the middle bucket (around zero) is the largest one
+//! assert_eq!(histogram.iter().max(), Some(&histogram[4]));
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+use num::ToPrimitive;
+use poly_ring_xnp1::Polynomial;
+
+/// Count the coefficients of `polys` in `buckets` buckets of equal width, which span the range
+/// `[-m, m]` where `m` is the largest absolute value of the coefficients. The `i`-th bucket counts the
+/// coefficients `c` s.t. `floor((c + m) * buckets / (2m + 1)) = i`, so an odd `buckets` has a bucket
+/// centered at zero. The coefficients are read by their signed representatives (as the norms do), and
+/// each polynomial contributes exactly `N` coefficients, including the zero ones.
+///
+/// If all the coefficients are zero, they are counted in the first bucket.
+///
+/// ## Panics
+/// Panics if `buckets` is 0, or a coefficient cannot be converted to `i128`.
+pub fn coeff_histogram<I, const N: usize>(polys: &[Polynomial<I, N>], buckets: usize) -> Vec<usize>
+where
+    I: Clone + ToPrimitive,
+{
+    assert!(buckets > 0, "buckets must be positive");
+    let coeffs = polys
+        .iter()
+        .flat_map(|p| {
+            let mut coeffs = p
+                .iter()
+                .map(|c| c.to_i128().expect("coefficient out of range of i128"))
+                .collect::<Vec<_>>();
+            coeffs.resize(N, 0);
+            coeffs
+        })
+        .collect::<Vec<_>>();
+
+    let m = coeffs.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0);
+    let width = 2 * m + 1;
+    let mut histogram = vec![0; buckets];
+    coeffs.iter().for_each(|c| {
+        // c + m in [0, 2m]
+        let offset = m.wrapping_add_signed(*c);
+        histogram[(offset * buckets as u128 / width) as usize] += 1;
+    });
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Zero;
+    use poly_ring_xnp1::zq::ZqI64;
+
+    use super::*;
+    use crate::{
+        params::Params,
+        sampler::{DiscreteGaussianSampler, GaussianSampler},
+    };
+
+    type I = ZqI64<3515337053>;
+
+    #[test]
+    fn test_coeff_histogram_small() {
+        let p = Polynomial::<i64, 8>::new(vec![-3, -1, 0, 1, 3]);
+        // range [-3, 3] in 7 buckets of width 1, the last 3 coefficients are zero
+        assert_eq!(
+            coeff_histogram(core::slice::from_ref(&p), 7),
+            vec![1, 0, 1, 4, 1, 0, 1]
+        );
+        assert_eq!(coeff_histogram(&[p], 1), vec![8]);
+        assert_eq!(
+            coeff_histogram(&[Polynomial::<i64, 8>::zero()], 3),
+            vec![8, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_coeff_histogram_bell_shaped() {
+        const N: usize = 512;
+
+        let rng = &mut rand::rng();
+        let params = Params::default();
+        let sigma = params.sigma_exact::<N>();
+
+        let ys = (0..200)
+            .map(|_| DiscreteGaussianSampler.sample_polynomial::<I, N>(rng, sigma))
+            .collect::<Vec<_>>();
+        let histogram = coeff_histogram(&ys, 11);
+        assert_eq!(histogram.iter().sum::<usize>(), 200 * N);
+
+        // increasing towards the middle bucket and decreasing after it
+        assert!(histogram[..=5].windows(2).all(|w| w[0] <= w[1]));
+        assert!(histogram[5..].windows(2).all(|w| w[0] >= w[1]));
+        // roughly symmetric around zero
+        for i in 0..5 {
+            let (a, b) = (histogram[i] as f64, histogram[10 - i] as f64);
+            assert!((a - b).abs() <= 0.2 * a.max(b) + 50.0);
+        }
+
+        // the empirical standard deviation matches sigma
+        let sum_sq = ys
+            .iter()
+            .flat_map(|y| y.iter())
+            .map(|c| (c.to_i128().unwrap() as f64).powi(2))
+            .sum::<f64>();
+        let std_dev = (sum_sq / (200 * N) as f64).sqrt();
+        assert!((std_dev - sigma).abs() < 0.05 * sigma);
+    }
+}
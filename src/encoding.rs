@@ -0,0 +1,36 @@
+//! The `bincode` encoding of the proofs, enabled by the feature `bincode`.
+//!
+//! The proofs are encoded through their `serde` derives with the default configuration of `bincode`
+//! (`bincode::serialize`), i.e. little-endian fixed-size integers, except that the decoding rejects trailing
+//! bytes.
+
+use alloc::vec::Vec;
+
+use bincode::Options;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::DecodeError;
+
+/// Encode `value` by `bincode`.
+pub(crate) fn to_bincode<T: Serialize>(value: &T) -> Vec<u8> {
+    bincode::serialize(value).expect("serializing to a vector never fails")
+}
+
+/// Decode the value from the encoding returned by [to_bincode].
+pub(crate) fn from_bincode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, DecodeError> {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+        .deserialize(bytes)
+        .map_err(|e| match *e {
+            bincode::ErrorKind::Io(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                DecodeError::Truncated
+            }
+            _ => DecodeError::Malformed,
+        })
+}
+
+/// The length of the encoding returned by [to_bincode], without encoding `value`.
+pub(crate) fn bincode_size<T: Serialize>(value: &T) -> usize {
+    bincode::serialized_size(value).expect("the size of a proof fits into u64") as usize
+}
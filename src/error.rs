@@ -0,0 +1,208 @@
+//! Defines the errors returned by the library.
+
+use core::fmt::{self, Display};
+
+/// The error type of the library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RingZkError {
+    /// The number of polynomials does not match the dimension defined in the `Params` struct.
+    DimensionMismatch { expected: usize, got: usize },
+    /// The degree `N` embedded in a proof does not match the degree of the verifier.
+    DegreeMismatch { expected: usize, got: usize },
+    /// The prover could not create a response within the norm bound in the maximum number of attempts.
+    RetryExhausted { attempts: usize },
+    /// The opening does not satisfy the relation to be proven, e.g. a coefficient of the value committed in
+    /// the proof of binary value is not 0 or 1.
+    InvalidWitness,
+}
+
+impl Display for RingZkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RingZkError::DimensionMismatch { expected, got } => {
+                write!(f, "dimension mismatch: expected {expected}, got {got}")
+            }
+            RingZkError::DegreeMismatch { expected, got } => {
+                write!(f, "degree mismatch: expected {expected}, got {got}")
+            }
+            RingZkError::RetryExhausted { attempts } => {
+                write!(f, "retry exhausted after {attempts} attempts")
+            }
+            RingZkError::InvalidWitness => {
+                write!(f, "the opening does not satisfy the relation to be proven")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RingZkError {}
+
+/// The reason why a response of a proof is rejected by the verifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    /// A response exceeds the norm bound (see `Params::norm`).
+    NormConstraintViolated,
+    /// The equation on the first part of the commitments, `A1 * z = t + c1 * d`, does not hold.
+    FirstEquationMismatch,
+    /// The relation on the second part of the commitments (the one involving `A2`) does not hold.
+    SecondEquationMismatch,
+    /// The number of responses does not match the number of commitments in the context.
+    LengthMismatch { expected: usize, got: usize },
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::NormConstraintViolated => write!(f, "norm constraint violated"),
+            VerifyError::FirstEquationMismatch => write!(f, "first equation mismatch"),
+            VerifyError::SecondEquationMismatch => write!(f, "second equation mismatch"),
+            VerifyError::LengthMismatch { expected, got } => {
+                write!(f, "length mismatch: expected {expected}, got {got}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for VerifyError {}
+
+/// The error returned when the input does not fit the parameters defined in the `Params` struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamsError {
+    /// The number of message polynomials is not equal to the message length `l`.
+    WrongMessageLength { expected: usize, got: usize },
+    /// The degree `N` is not a power of two.
+    NPowerOfTwo,
+    /// The width `k` of the commitment matrices is not greater than the height `n` of `a1`.
+    KNotGreaterThanN { k: usize, n: usize },
+    /// The height `n` of `a1` is less than the message length `l`.
+    NLessThanL { n: usize, l: usize },
+    /// The width `k` of the commitment matrices is less than `n + l`, i.e. `k - n - l` underflows.
+    KLessThanNPlusL { k: usize, n: usize, l: usize },
+    /// The splitting parameter `d` is not a power of two.
+    DNotPowerOfTwo { d: usize },
+    /// The modulus `q' = 2q + 1` is not congruent to `2d + 1` modulo `4d` (5 modulo 8 for `d = 2`).
+    ModulusNotCongruent { modulus: i128, d: usize },
+    /// The data is longer than the capacity of the message polynomials.
+    DataTooLong { capacity: usize, got: usize },
+}
+
+impl Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::WrongMessageLength { expected, got } => {
+                write!(f, "wrong message length: expected {expected}, got {got}")
+            }
+            ParamsError::NPowerOfTwo => write!(f, "N must be a power of two"),
+            ParamsError::KNotGreaterThanN { k, n } => {
+                write!(f, "k ({k}) must be greater than n ({n})")
+            }
+            ParamsError::NLessThanL { n, l } => {
+                write!(f, "n ({n}) must not be less than l ({l})")
+            }
+            ParamsError::KLessThanNPlusL { k, n, l } => {
+                write!(f, "k ({k}) must not be less than n + l ({n} + {l})")
+            }
+            ParamsError::DNotPowerOfTwo { d } => write!(f, "d ({d}) must be a power of two"),
+            ParamsError::ModulusNotCongruent { modulus, d } => {
+                write!(
+                    f,
+                    "modulus 2q + 1 ({modulus}) must be {} mod {}",
+                    2 * d + 1,
+                    4 * d
+                )
+            }
+            ParamsError::DataTooLong { capacity, got } => {
+                write!(f, "data too long: capacity {capacity} bytes, got {got}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParamsError {}
+
+/// The error returned by the commitment scheme.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommitError {
+    /// No randomness satisfying the commitment constraint was sampled in the maximum number of attempts.
+    RejectionLimitExceeded { attempts: usize },
+}
+
+impl Display for CommitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitError::RejectionLimitExceeded { attempts } => {
+                write!(f, "rejection limit exceeded after {attempts} attempts")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CommitError {}
+
+/// The error returned when decoding a malformed binary encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ends before all the fields are decoded.
+    Truncated,
+    /// The input has bytes left after all the fields are decoded.
+    TrailingBytes { len: usize },
+    /// The dimension (rows, columns) does not match the one defined in the `Params` struct.
+    DimensionMismatch {
+        expected: (usize, usize),
+        got: (usize, usize),
+    },
+    /// A coefficient is not in the range `[0, q')` of the modulus `q' = 2q + 1`, or does not fit into the
+    /// bytes per coefficient of [Params::decode_to_bytes](crate::Params::decode_to_bytes).
+    CoefficientOutOfRange,
+    /// The decoded bytes do not end with the padding `0x80` followed by zero bytes.
+    InvalidPadding,
+    /// The input is not a valid encoding of the value, e.g. by `bincode` (see the feature `bincode`).
+    Malformed,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated => write!(f, "unexpected end of input"),
+            DecodeError::TrailingBytes { len } => write!(f, "{len} trailing bytes"),
+            DecodeError::DimensionMismatch { expected, got } => write!(
+                f,
+                "dimension mismatch: expected {}x{}, got {}x{}",
+                expected.0, expected.1, got.0, got.1
+            ),
+            DecodeError::CoefficientOutOfRange => write!(f, "coefficient out of range"),
+            DecodeError::InvalidPadding => write!(f, "invalid padding"),
+            DecodeError::Malformed => write!(f, "malformed encoding"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// The error returned by the checked matrix operations (e.g. `Mat::try_dot`), enabled by the feature
+/// `checked`.
+#[cfg(feature = "checked")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MatError {
+    /// The dimensions (rows, columns) of the operands are not compatible with the operation.
+    DimMismatch {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+}
+
+#[cfg(feature = "checked")]
+impl Display for MatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatError::DimMismatch { lhs, rhs } => write!(
+                f,
+                "dimension mismatch: {}x{} and {}x{}",
+                lhs.0, lhs.1, rhs.0, rhs.1
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "checked")]
+impl core::error::Error for MatError {}
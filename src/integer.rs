@@ -0,0 +1,127 @@
+//! The bound on the coefficient types of the polynomials in the protocols.
+
+use num::{FromPrimitive, One, ToPrimitive, Zero};
+use rand::distr::uniform::SampleUniform;
+
+use crate::mat::MaybeSendSync;
+
+/// Integer types which can be used as the coefficients of the polynomials, e.g. `i64`, `i128` or
+/// `num::BigInt`. It gathers the bounds required by the commitment scheme and the provers/verifiers, and
+/// is implemented by all types satisfying them, so a custom (e.g. bignum) type only has to satisfy this one
+/// bound.
+///
+/// The arithmetic is done on references, so the protocols additionally require
+/// `for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>` (and `Neg<Output = I>` for some
+/// of the proofs). It cannot be part of this trait because bounds on `&Self` are not implied by a
+/// supertrait.
+///
+/// ## Example
+/// A newtype over `i64`:
+///
+/// ```
+/// use core::ops::{Add, Mul};
+///
+/// use num::{FromPrimitive, One, ToPrimitive, Zero};
+/// use rand::{
+///     distr::uniform::{Error, SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+///     Rng,
+/// };
+/// use ring_zk::RingInteger;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct MyInt(i64);
+///
+/// impl Add for MyInt {
+///     type Output = MyInt;
+///     fn add(self, rhs: MyInt) -> MyInt {
+///         MyInt(self.0 + rhs.0)
+///     }
+/// }
+///
+/// impl Mul for MyInt {
+///     type Output = MyInt;
+///     fn mul(self, rhs: MyInt) -> MyInt {
+///         MyInt(self.0 * rhs.0)
+///     }
+/// }
+///
+/// impl Zero for MyInt {
+///     fn zero() -> Self {
+///         MyInt(0)
+///     }
+///     fn is_zero(&self) -> bool {
+///         self.0 == 0
+///     }
+/// }
+///
+/// impl One for MyInt {
+///     fn one() -> Self {
+///         MyInt(1)
+///     }
+/// }
+///
+/// impl FromPrimitive for MyInt {
+///     fn from_i64(n: i64) -> Option<Self> {
+///         Some(MyInt(n))
+///     }
+///     fn from_u64(n: u64) -> Option<Self> {
+///         i64::from_u64(n).map(MyInt)
+///     }
+/// }
+///
+/// impl ToPrimitive for MyInt {
+///     fn to_i64(&self) -> Option<i64> {
+///         Some(self.0)
+///     }
+///     fn to_u64(&self) -> Option<u64> {
+///         self.0.to_u64()
+///     }
+/// }
+///
+/// struct UniformMyInt(UniformInt<i64>);
+///
+/// impl UniformSampler for UniformMyInt {
+///     type X = MyInt;
+///     fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+///     where
+///         B1: SampleBorrow<Self::X> + Sized,
+///         B2: SampleBorrow<Self::X> + Sized,
+///     {
+///         UniformInt::<i64>::new(low.borrow().0, high.borrow().0).map(UniformMyInt)
+///     }
+///     fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+///     where
+///         B1: SampleBorrow<Self::X> + Sized,
+///         B2: SampleBorrow<Self::X> + Sized,
+///     {
+///         UniformInt::<i64>::new_inclusive(low.borrow().0, high.borrow().0).map(UniformMyInt)
+///     }
+///     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+///         MyInt(self.0.sample(rng))
+///     }
+/// }
+///
+/// impl SampleUniform for MyInt {
+///     type Sampler = UniformMyInt;
+/// }
+///
+/// fn assert_ring_integer<I: RingInteger>() {}
+/// assert_ring_integer::<MyInt>();
+/// ```
+pub trait RingInteger:
+    Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform + MaybeSendSync
+{
+}
+
+impl<T> RingInteger for T where
+    T: Clone
+        + PartialOrd
+        + Ord
+        + One
+        + Zero
+        + FromPrimitive
+        + ToPrimitive
+        + SampleUniform
+        + MaybeSendSync
+{
+}
@@ -0,0 +1,257 @@
+//! An adapter exposing the commitment scheme as a "polynomial commitment"-style interface, for using the
+//! commitments in a generic IOP (Interactive Oracle Proof) framework.
+//!
+//! The commitment in this library is a commitment to the coefficients of a polynomial `p` in `R_q`. The
+//! evaluation `p(z)` at a public point `z` in `Z_q` is a linear function of the coefficients, and it is
+//! the constant coefficient of `g * p` in `R_q`, where `g = 1 - z * X^(N-1) - z^2 * X^(N-2) - ..` (i.e.
+//! `g = sum z^i * X^(-i)`). So the evaluation proof is built on the Proof of Linear Relation between the
+//! commitments to `p` and `p' = g * p`, made non-interactive by the Fiat-Shamir transform.
+//!
+//! ## Safety
+//! The evaluation proof contains the opening of the commitment to `p' = g * p`, from which the verifier reads
+//! the evaluation. As `g` is usually invertible, **the proof reveals `p`**. The adapter therefore provides
+//! binding evaluations but not hiding ones, and is intended for experimenting with IOP frameworks only.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use poly_ring_xnp1::zq::ZqI64;
+//! use ring_zk::{iop::PolynomialCommitmentScheme, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let pcs = PolynomialCommitmentScheme::new(ck, params.clone());
+//!
+//! // p(X) = 1 + 2X + 3X^2
+//! let p = params.prepare_scalar::<N>(vec![1, 2, 3]);
+//! let (opening, commitment) = pcs.commit(rng, p);
+//!
+//! let point = ZqI64::from(2);
+//! let (eval, proof) = pcs.open(rng, &opening, &commitment, point.clone());
+//! assert_eq!(eval.clone(), ZqI64::from(17));
+//! assert!(pcs.verify(&commitment, point, eval, proof));
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::{One, Zero};
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    params::Params,
+    prove::linear::{
+        LinearProofCommitment, LinearProofProver, LinearProofResponse, LinearProofVerifier,
+    },
+    transcript::Transcript,
+};
+
+/// The domain separation label for deriving the challenge in the evaluation proof.
+const EVALUATION_LABEL: &[u8] = b"ring-zk/iop-evaluation";
+
+/// The polynomial commitment scheme over the commitment key. It commits to a single polynomial, so the
+/// message length `l` defined in the `Params` struct must be 1.
+pub struct PolynomialCommitmentScheme<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+}
+
+impl<I, const N: usize> PolynomialCommitmentScheme<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self { params, ck }
+    }
+
+    /// Commit to the polynomial `p`. It returns the opening and the commitment.
+    ///
+    /// ## Panics
+    /// Panics if the length of `l` defined in the `Params` struct is not 1.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        p: Polynomial<I, N>,
+    ) -> (Opening<I, N>, Commitment<I, N>) {
+        self.ck.commit(rng, vec![p], &self.params)
+    }
+
+    /// Evaluate the committed polynomial at the `point`. It returns the evaluation and the proof that
+    /// the evaluation is correct w.r.t. the commitment.
+    ///
+    /// ## Panics
+    /// Panics if the length of `l` defined in the `Params` struct is not 1.
+    pub fn open(
+        &self,
+        rng: &mut impl RngExt,
+        opening: &Opening<I, N>,
+        commitment: &Commitment<I, N>,
+        point: I,
+    ) -> (I, EvaluationProof<I, N>) {
+        let g = evaluation_scalar::<I, N>(&point);
+        let gx = opening
+            .x
+            .iter()
+            .cloned()
+            .map(|xi| xi.mul(g.clone()))
+            .collect::<Vec<_>>(); // g * p
+        let eval = constant_coefficient(&gx[0]);
+        let (opening_p, cp) = self.ck.commit(rng, gx, &self.params);
+
+        let prover = LinearProofProver::new(self.ck.clone(), self.params.clone());
+        let (response_ctx, linear_commitment) = prover.commit_with_openings(
+            rng,
+            g,
+            (opening.clone(), commitment.clone()),
+            (opening_p.clone(), cp),
+        );
+        let verifier = LinearProofVerifier::new(self.ck.clone(), self.params.clone());
+        let d = self.challenge(&linear_commitment);
+        let (_, challenge) = verifier.challenge_with(linear_commitment.clone(), d);
+        let response = prover.create_response(response_ctx, challenge);
+        (
+            eval,
+            EvaluationProof {
+                commitment: linear_commitment,
+                opening_p,
+                response,
+            },
+        )
+    }
+
+    /// Verify that `eval` is the evaluation of the committed polynomial at the `point`. It returns `true`
+    /// if the proof is valid, otherwise `false`.
+    pub fn verify(
+        &self,
+        commitment: &Commitment<I, N>,
+        point: I,
+        eval: I,
+        proof: EvaluationProof<I, N>,
+    ) -> bool {
+        let EvaluationProof {
+            commitment: linear_commitment,
+            opening_p,
+            response,
+        } = proof;
+        if &linear_commitment.c != commitment
+            || linear_commitment.g != evaluation_scalar::<I, N>(&point)
+        {
+            return false;
+        }
+        // the evaluation is the constant coefficient of p' = g * p
+        if !linear_commitment
+            .cp
+            .verify(&opening_p, &self.ck, &self.params)
+            || opening_p.x.len() != 1
+            || constant_coefficient(&opening_p.x[0]) != eval
+        {
+            return false;
+        }
+        let verifier = LinearProofVerifier::new(self.ck.clone(), self.params.clone());
+        let d = self.challenge(&linear_commitment);
+        let (verification_ctx, _) = verifier.challenge_with(linear_commitment, d);
+        verifier.verify(response, verification_ctx)
+    }
+
+    /// Derive the challenge of the linear proof by the Fiat-Shamir transform.
+    fn challenge(&self, commitment: &LinearProofCommitment<I, N>) -> Polynomial<I, N> {
+        let mut transcript = Transcript::new(EVALUATION_LABEL);
+        transcript.append_message(&commitment.statement_bytes(&self.params));
+        transcript.challenge(self.params.kappa)
+    }
+}
+
+/// The proof of evaluating a committed polynomial at a point, created by [PolynomialCommitmentScheme::open].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluationProof<I, const N: usize>
+where
+    I: Zero,
+{
+    commitment: LinearProofCommitment<I, N>,
+    opening_p: Opening<I, N>,
+    response: LinearProofResponse<I, N>,
+}
+
+#[cfg(feature = "bincode")]
+impl<I, const N: usize> EvaluationProof<I, N>
+where
+    I: Zero + Serialize + serde::de::DeserializeOwned,
+{
+    /// Encode the proof by `bincode`. It is more compact than a text format such as JSON, as the coefficients
+    /// are encoded as fixed-size integers.
+    pub fn to_bincode(&self) -> Vec<u8> {
+        crate::encoding::to_bincode(self)
+    }
+
+    /// Decode the proof from the encoding returned by [EvaluationProof::to_bincode]. The decoded proof is not
+    /// checked; it is verified by [PolynomialCommitmentScheme::verify].
+    ///
+    /// ## Errors
+    /// Returns [DecodeError::Truncated](crate::DecodeError::Truncated) if the input ends early, or
+    /// [DecodeError::Malformed](crate::DecodeError::Malformed) if it is not an encoding of the proof or has
+    /// trailing bytes.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, crate::DecodeError> {
+        crate::encoding::from_bincode(bytes)
+    }
+
+    /// The size in bytes of the proof encoded by [EvaluationProof::to_bincode], e.g. for comparing the cost of sending
+    /// the proofs for a given `N`. The proof is not encoded for computing the size.
+    pub fn proof_size_bytes(&self) -> usize {
+        crate::encoding::bincode_size(self)
+    }
+}
+
+/// Returns `g = sum z^i * X^(-i)` for i = 0, 1, .., N-1, s.t. the constant coefficient of `g * p` is `p(z)`.
+/// In `R_q`, `X^(-i) = -X^(N-i)` for i > 0.
+fn evaluation_scalar<I, const N: usize>(point: &I) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    let mut coeffs = vec![I::zero(); N];
+    coeffs[0] = I::one();
+    let mut z_i = I::one();
+    for i in 1..N {
+        z_i = &z_i * point;
+        coeffs[N - i] = -&z_i;
+    }
+    Polynomial::from_coeffs(coeffs)
+}
+
+fn constant_coefficient<I, const N: usize>(p: &Polynomial<I, N>) -> I
+where
+    I: Clone + Zero,
+{
+    p.iter().next().cloned().unwrap_or_else(I::zero)
+}
+
+#[cfg(test)]
+mod tests {
+    use poly_ring_xnp1::zq::ZqI64;
+
+    use super::*;
+
+    const N: usize = 16;
+    type I = ZqI64<3515337053>;
+
+    #[test]
+    fn test_evaluation_scalar() {
+        let p = Polynomial::<I, N>::new(vec![I::from(3), I::from(-1), I::from(0), I::from(5)]);
+        let point = I::from(7);
+        // p(7) = 3 - 7 + 5 * 343
+        let gp = evaluation_scalar::<I, N>(&point) * p;
+        assert_eq!(constant_coefficient(&gp), I::from(3 - 7 + 5 * 343));
+    }
+}
@@ -1,24 +1,90 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-pub(crate) mod challenge_space;
+extern crate alloc;
+
+pub mod challenge_space;
 pub(crate) mod commit;
-pub use commit::{Commitment, CommitmentKey, Opening};
+pub use commit::{
+    Commitment, CommitmentBuilder, CommitmentKey, NormDiagnostics, Opening, SeededCommitmentKey,
+    DEFAULT_MAX_COMMIT_ATTEMPTS,
+};
+pub(crate) mod crt;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "bincode")]
+pub(crate) mod encoding;
+pub mod error;
+#[cfg(feature = "checked")]
+pub use error::MatError;
+pub use error::{CommitError, DecodeError, ParamsError, RingZkError, VerifyError};
+pub(crate) mod integer;
+pub use integer::RingInteger;
+pub mod iop;
 pub(crate) mod mat;
+pub use mat::{Mat, MaybeSendSync};
+#[cfg(feature = "ntt")]
+pub(crate) mod ntt;
 pub mod params;
-pub use params::Params;
+pub use params::{NormKind, Params, ParamsBuilder, ParamsDisplay, RetryConfig, SecurityEstimate};
 pub(crate) mod polynomial;
 pub mod prove;
+pub mod sampler;
+pub use sampler::{DiscreteGaussianSampler, GaussianSampler};
+#[cfg(feature = "os-rng")]
+pub mod rng;
+pub mod transcript;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 pub use prove::{
+    binary::{
+        BinaryProofChallenge, BinaryProofCommitment, BinaryProofProver, BinaryProofResponse,
+        BinaryProofResponseContext, BinaryProofVerificationContext, BinaryProofVerifier,
+    },
+    equality::{
+        EqualityProofChallenge, EqualityProofCommitment, EqualityProofProver,
+        EqualityProofResponse, EqualityProofResponseContext, EqualityProofVerificationContext,
+        EqualityProofVerifier,
+    },
     linear::{
-        LinearProofChallenge, LinearProofCommitment, LinearProofProver, LinearProofResponse,
-        LinearProofResponseContext, LinearProofVerificationContext, LinearProofVerifier,
+        prove_linear, verify_linear, LinearProof, LinearProofChallenge, LinearProofCommitment,
+        LinearProofProver, LinearProofResponse, LinearProofResponseContext,
+        LinearProofVerificationContext, LinearProofVerifier,
+    },
+    matrix_linear::{
+        MatrixLinearProofChallenge, MatrixLinearProofCommitment, MatrixLinearProofProver,
+        MatrixLinearProofResponse, MatrixLinearProofResponseContext,
+        MatrixLinearProofVerificationContext, MatrixLinearProofVerifier,
+    },
+    offset::{
+        OffsetProofChallenge, OffsetProofCommitment, OffsetProofProver, OffsetProofResponse,
+        OffsetProofResponseContext, OffsetProofVerificationContext, OffsetProofVerifier,
     },
     open::{
-        OpenProofChallenge, OpenProofCommitment, OpenProofProver, OpenProofResponse,
-        OpenProofResponseContext, OpenProofVerificationContext, OpenProofVerifier,
+        prove_open, verify_open, OpenProof, OpenProofChallenge, OpenProofCommitment,
+        OpenProofProver, OpenProofResponse, OpenProofResponseContext, OpenProofVerificationContext,
+        OpenProofVerifier, RepeatedOpenProof, RepeatedOpenProofChallenge,
+        RepeatedOpenProofCommitment, RepeatedOpenProofResponse, RepeatedOpenProofResponseContext,
+        RepeatedOpenProofVerificationContext,
+    },
+    product::{
+        ProductProofChallenge, ProductProofCommitment, ProductProofProver, ProductProofResponse,
+        ProductProofResponseContext, ProductProofVerificationContext, ProductProofVerifier,
+    },
+    range::{
+        RangeProofChallenge, RangeProofCommitment, RangeProofProver, RangeProofResponse,
+        RangeProofResponseContext, RangeProofVerificationContext, RangeProofVerifier,
+    },
+    set_membership::{
+        SetMembershipProofChallenge, SetMembershipProofCommitment, SetMembershipProofProver,
+        SetMembershipProofResponse, SetMembershipProofResponseContext,
+        SetMembershipProofVerificationContext, SetMembershipProofVerifier,
     },
     sum::{
-        SumProofChallenge, SumProofCommitment, SumProofProver, SumProofResponse,
-        SumProofResponseContext, SumProofVerificationContext, SumProofVerifier,
+        MixedSumProofCommitment, MixedSumProofResponse, MixedSumProofResponseContext,
+        MixedSumProofVerificationContext, PublicSumProofCommitment, PublicSumProofResponse,
+        PublicSumProofResponseContext, PublicSumProofVerificationContext, SumProofChallenge,
+        SumProofCommitment, SumProofProver, SumProofResponse, SumProofResponseContext,
+        SumProofVerificationContext, SumProofVerifier,
     },
 };
@@ -1,15 +1,58 @@
 //! Matrix operations over polynomial rings Z\[x]/(x^n+1).
 
-use std::ops::{Add, Mul, Neg, Sub};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
 
-use num::{One, Zero};
+use num::{FromPrimitive, One, ToPrimitive, Zero};
 use poly_ring_xnp1::Polynomial;
 use rand::distr::uniform::SampleUniform;
 use serde::{Deserialize, Serialize};
 
-/// A matrix over polynomial rings Z\[x]/(x^n+1).
+#[cfg(feature = "checked")]
+use crate::error::MatError;
+
+#[cfg(all(test, feature = "std"))]
+thread_local! {
+    /// Number of polynomial multiplications performed in the matrix operations of the current thread.
+    /// It is used for testing the cost estimation in `Params`.
+    pub(crate) static POLYNOMIAL_MUL_COUNT: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// Add `count` to the number of polynomial multiplications of the current thread. It only counts in tests
+/// with the `std` feature.
+fn count_polynomial_muls(_count: usize) {
+    #[cfg(all(test, feature = "std"))]
+    POLYNOMIAL_MUL_COUNT.with(|c| c.set(c.get() + _count as u64));
+}
+
+/// Marker for the coefficient types which can be shared across threads. With the `parallel` feature,
+/// the rows of the products of the polynomial matrices are computed in parallel, so the coefficients must
+/// be `Send + Sync`. Without the feature, it is implemented by all types.
+pub trait MaybeSendSync: thread_safety::ThreadSafe {}
+impl<T: thread_safety::ThreadSafe> MaybeSendSync for T {}
+
+/// The supertrait of [MaybeSendSync], which is `Send + Sync` only with the `parallel` feature.
+mod thread_safety {
+    #[cfg(feature = "parallel")]
+    pub trait ThreadSafe: Send + Sync {}
+    #[cfg(feature = "parallel")]
+    impl<T: Send + Sync> ThreadSafe for T {}
+
+    #[cfg(not(feature = "parallel"))]
+    pub trait ThreadSafe {}
+    #[cfg(not(feature = "parallel"))]
+    impl<T> ThreadSafe for T {}
+}
+
+/// A matrix over polynomial rings Z\[x]/(x^n+1). The commitment key, the commitment and the randomness of
+/// the commitment scheme are matrices of polynomials.
+///
+/// The matrix supports two kinds of multiplication by polynomials besides the matrix product ([Mat::dot]):
+/// - [Mat::scalar_mul] multiplies every polynomial of the matrix by the same polynomial, i.e. the
+///   polynomial is a scalar of the module over the ring.
+/// - [Mat::hadamard] multiplies the polynomials of two matrices of the same dimension elementwise.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub(crate) struct Mat<T, const N: usize>
+pub struct Mat<T, const N: usize>
 where
     T: Zero,
 {
@@ -43,12 +86,47 @@ where
     }
 
     /// Create a matrix (m x 1) from a vector of polynomials.
-    pub(crate) fn from_vec(polynomials: Vec<Polynomial<T, N>>) -> Self {
+    pub fn from_vec(polynomials: Vec<Polynomial<T, N>>) -> Self {
         Mat {
             polynomials: polynomials.into_iter().map(|p| vec![p]).collect(),
         }
     }
 
+    /// Create a matrix (m x n) from a grid of integers, where `grid[i][j]` is the coefficients (from the
+    /// constant term) of the polynomial at the row `i` and the column `j`. It is the matrix counterpart of
+    /// [Params::prepare_value](crate::Params::prepare_value).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Mat;
+    ///
+    /// let m = Mat::<i64, 4>::from_coeffs_grid(vec![
+    ///     vec![vec![1, 2], vec![3]],
+    ///     vec![vec![0], vec![4, 5, 6, 7]],
+    /// ]);
+    /// assert_eq!(m.dim(), (2, 2));
+    /// ```
+    ///
+    /// ## Panics
+    /// Panics if the rows do not have the same length, any polynomial has more than `N` coefficients, or
+    /// the constant `N` is not a power of two.
+    pub fn from_coeffs_grid(grid: Vec<Vec<Vec<T>>>) -> Self {
+        if let Some(first) = grid.first() {
+            let n = first.len();
+            assert!(
+                grid.iter().all(|row| row.len() == n),
+                "All rows must have the same length"
+            );
+        }
+        Mat {
+            polynomials: grid
+                .into_iter()
+                .map(|row| row.into_iter().map(Polynomial::new).collect())
+                .collect(),
+        }
+    }
+
     /// Convert the matrix (m x 1) to a vector of polynomials.
     ///
     /// ## Panics
@@ -76,7 +154,7 @@ where
     }
 
     /// Dimension of the matrix.
-    pub(crate) fn dim(&self) -> (usize, usize) {
+    pub fn dim(&self) -> (usize, usize) {
         let m = self.polynomials.len();
         let n = if self.polynomials.is_empty() {
             0
@@ -86,38 +164,104 @@ where
         (m, n)
     }
 
+    /// Iterate over the polynomials of the matrix in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &Polynomial<T, N>> {
+        self.polynomials.iter().flatten()
+    }
+
+    /// Compare the matrices in constant time w.r.t. the coefficients of their polynomials. The dimensions are
+    /// compared in variable time, as they are public.
+    #[cfg(feature = "ct")]
+    pub(crate) fn ct_eq(&self, other: &Mat<T, N>) -> subtle::Choice
+    where
+        T: ToPrimitive,
+    {
+        if self.dim() != other.dim() {
+            return subtle::Choice::from(0);
+        }
+        self.iter()
+            .zip(other.iter())
+            .fold(subtle::Choice::from(1), |acc, (a, b)| {
+                acc & crate::polynomial::ct_eq_polynomial(a, b)
+            })
+    }
+
+    /// Transpose of the matrix, i.e. the `m x n` matrix becomes `n x m`. A matrix without rows or columns
+    /// has the dimension (0, 0) after transpose, since a matrix of zero rows has no columns by [Mat::dim].
+    #[allow(unused)]
+    pub(crate) fn transpose(&self) -> Mat<T, N>
+    where
+        T: Clone,
+    {
+        let (m, n) = self.dim();
+        let polynomials = (0..n)
+            .map(|j| (0..m).map(|i| self.polynomials[i][j].clone()).collect())
+            .collect();
+        Mat { polynomials }
+    }
+
     /// Dot product of two matrices.
     ///
     /// ## Panics
-    /// Panics if the number of columns of the first matrix is not equal to
-    /// the number of rows of the second matrix.
-    #[allow(clippy::needless_range_loop)]
+    /// Panics if the number of columns of the first matrix is not equal to the number of rows of the
+    /// second matrix. Use [Mat::try_dot] (feature `checked`) for the matrices of unknown dimensions.
+    ///
+    /// With the `parallel` feature, the rows of the product are computed in parallel.
     pub fn dot(&self, other: &Mat<T, N>) -> Mat<T, N>
     where
-        T: Clone + One,
+        T: Clone + One + FromPrimitive + ToPrimitive + MaybeSendSync,
         for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
     {
         // mxn * nxp = mxp
         let (m, n) = self.dim();
         let (n2, p) = other.dim();
-        assert_eq!(n, n2);
+        assert_eq!(
+            n, n2,
+            "Matrix dimensions are not compatible for the dot product"
+        );
 
-        let mut polynomials = vec![vec![Polynomial::<T, N>::zero(); p]; m];
-        for i in 0..m {
-            for j in 0..p {
-                for k in 0..n {
-                    polynomials[i][j] = polynomials[i][j].clone()
-                        + self.polynomials[i][k].clone() * other.polynomials[k][j].clone();
-                }
+        count_polynomial_muls(m * n * p);
+
+        #[cfg(feature = "parallel")]
+        let polynomials = {
+            use rayon::prelude::*;
+            self.polynomials
+                .par_iter()
+                .map(|row| other.row_dot(row))
+                .collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let polynomials = self
+            .polynomials
+            .iter()
+            .map(|row| other.row_dot(row))
+            .collect();
+        Mat { polynomials }
+    }
+
+    /// The product of the row vector (1 x n) and this matrix (n x p), which is a row of [Mat::dot].
+    #[allow(clippy::needless_range_loop)]
+    fn row_dot(&self, row: &[Polynomial<T, N>]) -> Vec<Polynomial<T, N>>
+    where
+        T: Clone + One + FromPrimitive + ToPrimitive,
+        for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    {
+        let (n, p) = self.dim();
+        let mut polynomials = vec![Polynomial::<T, N>::zero(); p];
+        for j in 0..p {
+            for k in 0..n {
+                polynomials[j] =
+                    polynomials[j].clone() + polynomial_mul(&row[k], &self.polynomials[k][j]);
             }
         }
-        Mat { polynomials }
+        polynomials
     }
 
     /// Add two matrices.
     ///
     /// ## Panics
-    /// Panics if the dimensions of the two matrices are not equal.
+    /// Panics if the dimensions of the two matrices are not equal. Use [Mat::try_add] (feature `checked`)
+    /// for the matrices of unknown dimensions.
     #[allow(clippy::needless_range_loop)]
     pub(crate) fn add(&self, other: &Mat<T, N>) -> Mat<T, N>
     where
@@ -126,8 +270,11 @@ where
     {
         let (m, n) = self.dim();
         let (m2, n2) = other.dim();
-        assert_eq!(m, m2);
-        assert_eq!(n, n2);
+        assert_eq!(
+            (m, n),
+            (m2, n2),
+            "Matrix dimensions are not equal for the addition"
+        );
 
         let mut polynomials = vec![vec![Polynomial::<T, N>::zero(); n]; m];
         for i in 0..m {
@@ -142,17 +289,20 @@ where
     /// Subtract two matrices.
     ///
     /// ## Panics
-    /// Panics if the dimensions of the two matrices are not equal
+    /// Panics if the dimensions of the two matrices are not equal.
     #[allow(clippy::needless_range_loop)]
-    pub fn sub(&self, other: &Mat<T, N>) -> Mat<T, N>
+    pub(crate) fn sub(&self, other: &Mat<T, N>) -> Mat<T, N>
     where
         T: Clone + One,
         for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Neg<Output = T> + Sub<Output = T>,
     {
         let (m, n) = self.dim();
         let (m2, n2) = other.dim();
-        assert_eq!(m, m2);
-        assert_eq!(n, n2);
+        assert_eq!(
+            (m, n),
+            (m2, n2),
+            "Matrix dimensions are not equal for the subtraction"
+        );
 
         let mut polynomials = vec![vec![Polynomial::<T, N>::zero(); n]; m];
         for i in 0..m {
@@ -164,12 +314,87 @@ where
         Mat { polynomials }
     }
 
-    /// Compoentwise multiplication of a matrix and a polynomial.
-    pub(crate) fn componentwise_mul(&self, element: &Polynomial<T, N>) -> Mat<T, N>
+    /// Negate the matrix, i.e. negate every polynomial in the matrix.
+    pub(crate) fn neg(&self) -> Mat<T, N>
+    where
+        T: Clone,
+        for<'a> &'a T: Neg<Output = T>,
+    {
+        let polynomials = self
+            .polynomials
+            .iter()
+            .map(|row| row.iter().map(|p| -p.clone()).collect())
+            .collect();
+        Mat { polynomials }
+    }
+
+    /// Multiplication of the matrix by the integer scalar `w`, i.e. every coefficient of every polynomial
+    /// is multiplied by `w`. The dimensions are preserved. It is much cheaper than [Mat::scalar_mul], which
+    /// multiplies polynomials, and is useful for weighted combinations of matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use poly_ring_xnp1::Polynomial;
+    /// use ring_zk::Mat;
+    ///
+    /// let a = Polynomial::<i64, 4>::new(vec![1, 2]);
+    /// let b = Polynomial::<i64, 4>::new(vec![3]);
+    /// let m = Mat::from_vec(vec![a, b]); // 2 x 1
+    ///
+    /// assert_eq!(
+    ///     m.scale(&3),
+    ///     Mat::from_vec(vec![
+    ///         Polynomial::new(vec![3, 6]),
+    ///         Polynomial::new(vec![9])
+    ///     ])
+    /// );
+    /// ```
+    pub fn scale(&self, w: &T) -> Mat<T, N>
+    where
+        T: Clone + One,
+        for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    {
+        let polynomials = self
+            .polynomials
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|p| Polynomial::new(p.iter().map(|c| c * w).collect()))
+                    .collect()
+            })
+            .collect();
+        Mat { polynomials }
+    }
+
+    /// Multiplication of the matrix by a scalar polynomial, i.e. every polynomial of the matrix is
+    /// multiplied by `element` in the ring. The dimension is unchanged. See [Mat::hadamard] for the
+    /// elementwise product of two matrices.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use poly_ring_xnp1::Polynomial;
+    /// use ring_zk::Mat;
+    ///
+    /// let a = Polynomial::<i64, 4>::new(vec![1, 2]);
+    /// let b = Polynomial::<i64, 4>::new(vec![3]);
+    /// let m = Mat::from_vec(vec![a.clone(), b.clone()]); // 2 x 1
+    ///
+    /// let x = Polynomial::<i64, 4>::new(vec![0, 1]); // the polynomial x
+    /// assert_eq!(
+    ///     m.scalar_mul(&x),
+    ///     Mat::from_vec(vec![a * x.clone(), b * x])
+    /// );
+    /// ```
+    pub fn scalar_mul(&self, element: &Polynomial<T, N>) -> Mat<T, N>
     where
         T: Clone + One,
         for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
     {
+        let (m, n) = self.dim();
+        count_polynomial_muls(m * n);
+
         let mut polynomials = self.polynomials.clone();
         polynomials
             .iter_mut()
@@ -177,19 +402,69 @@ where
         Mat { polynomials }
     }
 
+    /// Elementwise (Hadamard) product of two matrices, i.e. the polynomial at (i, j) is the product of
+    /// the polynomials at (i, j) of the two matrices.
+    ///
+    /// It differs from [Mat::scalar_mul], which multiplies all the polynomials by the same polynomial.
+    ///
+    /// ## Panics
+    /// Panics if the dimensions of the two matrices are not equal.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use poly_ring_xnp1::Polynomial;
+    /// use ring_zk::Mat;
+    ///
+    /// let a = Mat::from_vec(vec![Polynomial::<i64, 4>::new(vec![1, 2]), Polynomial::new(vec![3])]);
+    /// let b = Mat::from_vec(vec![Polynomial::<i64, 4>::new(vec![0, 1]), Polynomial::new(vec![2])]);
+    /// assert_eq!(
+    ///     a.hadamard(&b),
+    ///     Mat::from_vec(vec![Polynomial::new(vec![0, 1, 2]), Polynomial::new(vec![6])])
+    /// );
+    /// ```
+    pub fn hadamard(&self, other: &Mat<T, N>) -> Mat<T, N>
+    where
+        T: Clone + One,
+        for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    {
+        let (m, n) = self.dim();
+        assert_eq!(
+            (m, n),
+            other.dim(),
+            "Matrix dimensions are not equal for the Hadamard product"
+        );
+
+        count_polynomial_muls(m * n);
+
+        let polynomials = self
+            .polynomials
+            .iter()
+            .zip(other.polynomials.iter())
+            .map(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .map(|(p, q)| p.clone() * q.clone())
+                    .collect()
+            })
+            .collect();
+        Mat { polynomials }
+    }
+
     /// Extend the matrix by adding rows.
     /// Original dimensions: m x n;
     /// New dimensions: (m + m') x n
     ///
     /// ## Panics
-    /// Panics if the number of columns of the two matrices are not equal.
+    /// Panics if the numbers of columns of the two matrices are not equal. Use [Mat::try_extend_rows]
+    /// (feature `checked`) for the matrices of unknown dimensions.
     pub fn extend_rows(&mut self, other: Mat<T, N>)
     where
         T: Clone,
     {
         let (_, n) = self.dim();
         let (_, n2) = other.dim();
-        assert_eq!(n, n2);
+        assert_eq!(n, n2, "Matrix numbers of columns are not equal");
 
         self.polynomials.extend(other.polynomials);
     }
@@ -217,14 +492,15 @@ where
     /// New dimensions: m x (n + n')
     ///
     /// ## Panics
-    /// Panics if the number of rows of the two matrices are not equal.
+    /// Panics if the numbers of rows of the two matrices are not equal. Use [Mat::try_extend_cols] (feature
+    /// `checked`) for the matrices of unknown dimensions.
     pub fn extend_cols(&mut self, other: Mat<T, N>)
     where
         T: Clone,
     {
         let (m, _) = self.dim();
         let (m2, _) = other.dim();
-        assert_eq!(m, m2);
+        assert_eq!(m, m2, "Matrix numbers of rows are not equal");
 
         self.polynomials
             .iter_mut()
@@ -233,12 +509,143 @@ where
     }
 }
 
+/// The checked variants of the matrix operations, which return [MatError] instead of panicking on the
+/// wrong dimensions. The proofs use the panicking operations, whose dimensions are fixed by the `Params`
+/// struct.
+#[cfg(feature = "checked")]
+impl<T, const N: usize> Mat<T, N>
+where
+    T: Zero,
+{
+    /// Dot product of two matrices, as [Mat::dot] does.
+    ///
+    /// ## Errors
+    /// Returns [MatError::DimMismatch] if the number of columns of the first matrix is not equal to
+    /// the number of rows of the second matrix.
+    pub fn try_dot(&self, other: &Mat<T, N>) -> Result<Mat<T, N>, MatError>
+    where
+        T: Clone + One + FromPrimitive + ToPrimitive + MaybeSendSync,
+        for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    {
+        let (lhs, rhs) = (self.dim(), other.dim());
+        if lhs.1 != rhs.0 {
+            return Err(MatError::DimMismatch { lhs, rhs });
+        }
+        Ok(self.dot(other))
+    }
+
+    /// Add two matrices.
+    ///
+    /// ## Errors
+    /// Returns [MatError::DimMismatch] if the dimensions of the two matrices are not equal.
+    pub fn try_add(&self, other: &Mat<T, N>) -> Result<Mat<T, N>, MatError>
+    where
+        T: Clone + One,
+        for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+    {
+        let (lhs, rhs) = (self.dim(), other.dim());
+        if lhs != rhs {
+            return Err(MatError::DimMismatch { lhs, rhs });
+        }
+        Ok(self.add(other))
+    }
+
+    /// Extend the matrix by adding rows, as [Mat::extend_rows] does. The matrix is unchanged on error.
+    ///
+    /// ## Errors
+    /// Returns [MatError::DimMismatch] if the numbers of columns of the two matrices are not equal.
+    pub fn try_extend_rows(&mut self, other: Mat<T, N>) -> Result<(), MatError>
+    where
+        T: Clone,
+    {
+        let (lhs, rhs) = (self.dim(), other.dim());
+        if lhs.1 != rhs.1 {
+            return Err(MatError::DimMismatch { lhs, rhs });
+        }
+        self.extend_rows(other);
+        Ok(())
+    }
+
+    /// Extend the matrix by adding columns, as [Mat::extend_cols] does. The matrix is unchanged on error.
+    ///
+    /// ## Errors
+    /// Returns [MatError::DimMismatch] if the numbers of rows of the two matrices are not equal.
+    pub fn try_extend_cols(&mut self, other: Mat<T, N>) -> Result<(), MatError>
+    where
+        T: Clone,
+    {
+        let (lhs, rhs) = (self.dim(), other.dim());
+        if lhs.0 != rhs.0 {
+            return Err(MatError::DimMismatch { lhs, rhs });
+        }
+        self.extend_cols(other);
+        Ok(())
+    }
+}
+
+/// The product of the polynomials. With the `ntt` feature, it is computed by the NTT if the transform
+/// is applicable, otherwise by the schoolbook multiplication.
+fn polynomial_mul<T, const N: usize>(a: &Polynomial<T, N>, b: &Polynomial<T, N>) -> Polynomial<T, N>
+where
+    T: Clone + Zero + One + FromPrimitive + ToPrimitive,
+    for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    #[cfg(feature = "ntt")]
+    if let Some(c) = crate::ntt::negacyclic_mul(a, b) {
+        return c;
+    }
+    a.clone() * b.clone()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     const N: usize = 4;
 
+    #[test]
+    fn test_serde_json() {
+        let a = Mat::<i32, N>::new_with(3, 3, {
+            let mut i = 0;
+            move || {
+                i += 1;
+                Polynomial::new(vec![i, -i, 2 * i])
+            }
+        });
+        let json = serde_json::to_string(&a).unwrap();
+        let b: Mat<i32, N> = serde_json::from_str(&json).unwrap();
+        assert_eq!(b.dim(), (3, 3));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_from_coeffs_grid() {
+        let m = Mat::<i32, N>::from_coeffs_grid(vec![
+            vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]],
+            vec![vec![-1, -2, -3, -4], vec![0, 0, 0, 1]],
+        ]);
+        assert_eq!(m.dim(), (2, 2));
+        assert_eq!(m.polynomials[0][1], Polynomial::new(vec![5, 6, 7, 8]));
+        assert_eq!(m.polynomials[1][0], Polynomial::new(vec![-1, -2, -3, -4]));
+        assert_eq!(m.polynomials[1][1].deg(), 3);
+        assert_eq!(
+            m.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                m.polynomials[0][0].clone(),
+                m.polynomials[0][1].clone(),
+                m.polynomials[1][0].clone(),
+                m.polynomials[1][1].clone()
+            ]
+        );
+        assert_eq!(Mat::<i32, N>::from_coeffs_grid(vec![]).dim(), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "All rows must have the same length")]
+    fn test_from_coeffs_grid_ragged() {
+        Mat::<i32, N>::from_coeffs_grid(vec![vec![vec![1]], vec![vec![1], vec![2]]]);
+    }
+
     #[test]
     fn test_dot() {
         let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
@@ -267,6 +674,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dot_multiple_rows() {
+        // 3x2 matrix
+        let a = Mat::<i32, N>::new_with(3, 2, {
+            let mut i = 0;
+            move || {
+                i += 1;
+                Polynomial::new(vec![i, -i, 2 * i])
+            }
+        });
+        // 2x3 matrix
+        let b = Mat::<i32, N>::new_with(2, 3, {
+            let mut i = 0;
+            move || {
+                i += 1;
+                Polynomial::new(vec![3 - i, i])
+            }
+        });
+
+        let c = a.dot(&b);
+
+        assert_eq!(c.dim(), (3, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = a.polynomials[i][0].clone() * b.polynomials[0][j].clone()
+                    + a.polynomials[i][1].clone() * b.polynomials[1][j].clone();
+                assert_eq!(c.polynomials[i][j], expected);
+            }
+        }
+    }
+
     #[test]
     fn test_add() {
         let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
@@ -325,6 +763,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_neg() {
+        let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
+        let a_0_1 = Polynomial::<i32, N>::new(vec![4, 5, 6]);
+
+        // 1x2 matrix
+        let a = Mat {
+            polynomials: vec![vec![a_0_0.clone(), a_0_1.clone()]],
+        };
+
+        let c = a.neg();
+
+        assert_eq!(
+            c.polynomials,
+            vec![vec![
+                Polynomial::<i32, N>::new(vec![-1, -2, -3]),
+                Polynomial::<i32, N>::new(vec![-4, -5, -6])
+            ]]
+        );
+        assert_eq!(c.neg(), a);
+    }
+
+    #[test]
+    fn test_add_neg_equals_sub() {
+        let a = Mat::<i32, N>::new_with(2, 3, {
+            let mut i = 0;
+            move || {
+                i += 1;
+                Polynomial::new(vec![i, 2 * i, -i])
+            }
+        });
+        let b = Mat::<i32, N>::new_with(2, 3, {
+            let mut i = 0;
+            move || {
+                i += 1;
+                Polynomial::new(vec![3 - i, i])
+            }
+        });
+
+        assert_eq!(a.add(&b.neg()), a.sub(&b));
+        assert_eq!(a.add(&a.neg()), Mat::from_element(2, 3, Polynomial::zero()));
+    }
+
     #[test]
     fn test_extend_rows() {
         let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
@@ -354,6 +835,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_transpose() {
+        let a = Mat::<i32, N>::new_with(2, 3, {
+            let mut i = 0;
+            move || {
+                i += 1;
+                Polynomial::new(vec![i, -i])
+            }
+        });
+
+        let t = a.transpose();
+
+        assert_eq!(t.dim(), (3, 2));
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(t.polynomials[j][i], a.polynomials[i][j]);
+            }
+        }
+        assert_eq!(t.transpose(), a);
+
+        let empty = Mat::<i32, N> {
+            polynomials: vec![],
+        };
+        assert_eq!(empty.transpose().dim(), (0, 0));
+    }
+
     #[test]
     fn test_extend_cols() {
         let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
@@ -386,7 +893,7 @@ mod tests {
     }
 
     #[test]
-    fn test_componentwise_mul() {
+    fn test_scalar_mul() {
         let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
         let a_0_1 = Polynomial::<i32, N>::new(vec![4, 5, 6]);
 
@@ -397,7 +904,8 @@ mod tests {
 
         let b = Polynomial::<i32, N>::new(vec![1, 2, 3]);
 
-        let a = a.componentwise_mul(&b);
+        let a = a.scalar_mul(&b);
+        assert_eq!(a.dim(), (1, 2));
 
         assert_eq!(
             a.polynomials,
@@ -405,6 +913,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scale() {
+        // 2x2 matrix
+        let a = Mat {
+            polynomials: vec![
+                vec![
+                    Polynomial::<i32, N>::new(vec![1, 2, 3]),
+                    Polynomial::<i32, N>::new(vec![4, -5]),
+                ],
+                vec![
+                    Polynomial::<i32, N>::new(vec![0, 0, 6]),
+                    Polynomial::<i32, N>::zero(),
+                ],
+            ],
+        };
+
+        let scaled = a.scale(&3);
+        assert_eq!(scaled.dim(), (2, 2));
+        assert_eq!(scaled, a.add(&a).add(&a));
+        assert_eq!(a.scale(&0), Mat::from_element(2, 2, Polynomial::zero()));
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let a_0_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
+        let a_1_0 = Polynomial::<i32, N>::new(vec![4, 5, 6]);
+        let b_0_0 = Polynomial::<i32, N>::new(vec![1, 2]);
+        let b_1_0 = Polynomial::<i32, N>::new(vec![3]);
+
+        // 2x1 matrices
+        let a = Mat::from_vec(vec![a_0_0.clone(), a_1_0.clone()]);
+        let b = Mat::from_vec(vec![b_0_0.clone(), b_1_0.clone()]);
+
+        let c = a.hadamard(&b);
+
+        assert_eq!(
+            c.polynomials,
+            vec![vec![a_0_0 * b_0_0], vec![a_1_0 * b_1_0]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix dimensions are not equal")]
+    fn test_hadamard_dimension_mismatch() {
+        let p = Polynomial::<i32, N>::new(vec![1, 2, 3]);
+        let a = Mat::from_element(2, 1, p.clone());
+        let b = Mat::from_element(1, 2, p);
+        a.hadamard(&b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix dimensions are not compatible")]
+    fn test_dot_dimension_mismatch() {
+        let p = Polynomial::<i32, N>::new(vec![1, 2, 3]);
+        let a = Mat::from_element(2, 2, p.clone());
+        let z = Mat::from_element(1, 1, p);
+        a.dot(&z);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix dimensions are not equal")]
+    fn test_add_dimension_mismatch() {
+        let p = Polynomial::<i32, N>::new(vec![1, 2, 3]);
+        let a = Mat::from_element(2, 1, p.clone());
+        let b = Mat::from_element(1, 1, p);
+        a.add(&b);
+    }
+
     #[test]
     fn test_split_rows() {
         let a_0 = Polynomial::<i32, N>::new(vec![1, 2, 3]);
@@ -436,4 +1012,59 @@ mod tests {
         let deserialized_a = bincode::deserialize(&serialized_a).unwrap();
         assert_eq!(a, deserialized_a);
     }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn test_checked_dim_mismatch() {
+        let p = Polynomial::<i32, N>::new(vec![1, 2, 3]);
+        let a = Mat::from_element(2, 3, p.clone());
+        let b = Mat::from_element(2, 1, p.clone());
+        let c = Mat::from_element(3, 1, p.clone());
+
+        // dot: 2x3 * 2x1
+        assert_eq!(
+            a.try_dot(&b),
+            Err(MatError::DimMismatch {
+                lhs: (2, 3),
+                rhs: (2, 1)
+            })
+        );
+        assert_eq!(a.try_dot(&c), Ok(a.dot(&c)));
+
+        // add: 2x3 + 2x1
+        assert_eq!(
+            a.try_add(&b),
+            Err(MatError::DimMismatch {
+                lhs: (2, 3),
+                rhs: (2, 1)
+            })
+        );
+        assert_eq!(b.try_add(&b), Ok(b.add(&b)));
+
+        // extend_rows: 2x3 and 2x1
+        let mut d = a.clone();
+        assert_eq!(
+            d.try_extend_rows(b.clone()),
+            Err(MatError::DimMismatch {
+                lhs: (2, 3),
+                rhs: (2, 1)
+            })
+        );
+        assert_eq!(d, a);
+        assert_eq!(d.try_extend_rows(a.clone()), Ok(()));
+        assert_eq!(d.dim(), (4, 3));
+
+        // extend_cols: 2x3 and 3x1
+        let mut d = a.clone();
+        assert_eq!(
+            d.try_extend_cols(c.clone()),
+            Err(MatError::DimMismatch {
+                lhs: (2, 3),
+                rhs: (3, 1)
+            })
+        );
+        assert_eq!(d, a);
+        assert_eq!(d.try_extend_cols(b), Ok(()));
+        assert_eq!(d.dim(), (2, 4));
+    }
 }
@@ -0,0 +1,239 @@
+//! Multiplication in the ring Z\[x]/(x^n+1) by the Number Theoretic Transform (NTT).
+//!
+//! The modulus `q'` of the ring is chosen s.t. `q' = 5 mod 8` (Lemma 1 of the paper), so `x^n+1` splits into
+//! only two factors modulo `q'` and the negacyclic NTT does not exist over `Z_q'`. Instead, the product is
+//! computed over the integers: the coefficients are lifted to their signed representatives, multiplied by the
+//! negacyclic NTT modulo three NTT-friendly primes, and the exact result is recovered by the Chinese Remainder
+//! Theorem before being mapped back to the coefficient type. The product is therefore equal to the schoolbook
+//! product whenever the coefficient type is a quotient of the integers (e.g. `ZqI64`).
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use num::{FromPrimitive, One, ToPrimitive, Zero};
+use poly_ring_xnp1::Polynomial;
+
+/// The NTT-friendly primes `c * 2^e + 1` with the primitive root 3. All of them support the transform of
+/// length up to `2^23`.
+const PRIMES: [u64; 3] = [998244353, 167772161, 469762049];
+const PRIMITIVE_ROOT: u64 = 3;
+const MAX_LOG_LENGTH: u32 = 23;
+
+/// Returns the product of the polynomials `a` and `b` in Z\[x]/(x^n+1) computed by the NTT, or `None` if
+/// the transform is not applicable, i.e. `N` is too large, a coefficient does not fit into `i64`, the
+/// product may exceed the range recoverable by the primes, or the result cannot be converted back to `T`.
+pub(crate) fn negacyclic_mul<T, const N: usize>(
+    a: &Polynomial<T, N>,
+    b: &Polynomial<T, N>,
+) -> Option<Polynomial<T, N>>
+where
+    T: Clone + Zero + One + FromPrimitive + ToPrimitive,
+    for<'a> &'a T: Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+{
+    // x^(2N) = 1 requires a primitive 2N-th root of unity.
+    if N < 2 || !N.is_power_of_two() || N.trailing_zeros() + 1 > MAX_LOG_LENGTH {
+        return None;
+    }
+    let a = to_signed_coeffs::<T, N>(a)?;
+    let b = to_signed_coeffs::<T, N>(b)?;
+
+    // |c_i| <= N * max|a_i| * max|b_i| must be less than half of the product of the primes (~2^86).
+    let max_a = a.iter().map(|v| v.unsigned_abs()).max().unwrap_or(0) as f64;
+    let max_b = b.iter().map(|v| v.unsigned_abs()).max().unwrap_or(0) as f64;
+    if N as f64 * max_a * max_b >= 2f64.powi(85) {
+        return None;
+    }
+
+    let residues = PRIMES.map(|p| negacyclic_mul_mod(&a, &b, p));
+    let coeffs = (0..N)
+        .map(|i| from_i128::<T>(crt([residues[0][i], residues[1][i], residues[2][i]])))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Polynomial::from_coeffs(coeffs))
+}
+
+fn to_signed_coeffs<T, const N: usize>(p: &Polynomial<T, N>) -> Option<Vec<i64>>
+where
+    T: ToPrimitive,
+{
+    let mut coeffs = p.iter().map(|c| c.to_i64()).collect::<Option<Vec<_>>>()?;
+    coeffs.resize(N, 0);
+    Some(coeffs)
+}
+
+/// Converts the integer to `T` by `hi * 2^32 + lo`, so that it works for the types which only
+/// implement the conversion from `i64`.
+fn from_i128<T>(v: i128) -> Option<T>
+where
+    T: FromPrimitive,
+    for<'a> &'a T: Add<Output = T> + Mul<Output = T>,
+{
+    if let Some(v) = T::from_i128(v) {
+        return Some(v);
+    }
+    let hi = T::from_i128(v >> 32)?;
+    let lo = T::from_i128(v & 0xffff_ffff)?;
+    let base = T::from_i64(1 << 32)?;
+    Some(&(&hi * &base) + &lo)
+}
+
+/// Recovers the signed integer from its residues modulo [PRIMES] by Garner's algorithm.
+fn crt(r: [u64; 3]) -> i128 {
+    let [p0, p1, p2] = PRIMES;
+    // x = r0 + p0 * v1 + p0 * p1 * v2
+    let v1 = mul_mod(
+        (r[1] + p1 - r[0] % p1) % p1,
+        pow_mod(p0 % p1, p1 - 2, p1),
+        p1,
+    );
+    let x01 = r[0] as u128 + p0 as u128 * v1 as u128; // < p0 * p1
+    let v2 = mul_mod(
+        (r[2] + p2 - (x01 % p2 as u128) as u64) % p2,
+        pow_mod(mul_mod(p0 % p2, p1 % p2, p2), p2 - 2, p2),
+        p2,
+    );
+    let m01 = p0 as u128 * p1 as u128;
+    let x = x01 + m01 * v2 as u128;
+    let m = m01 * p2 as u128;
+    if x > m / 2 {
+        x as i128 - m as i128
+    } else {
+        x as i128
+    }
+}
+
+/// The negacyclic convolution of `a` and `b` modulo the prime `p`. The inputs are weighted by the powers
+/// of `psi`, a primitive 2N-th root of unity, so that the cyclic convolution becomes the negacyclic one.
+fn negacyclic_mul_mod(a: &[i64], b: &[i64], p: u64) -> Vec<u64> {
+    let n = a.len();
+    let psi = pow_mod(PRIMITIVE_ROOT, (p - 1) / (2 * n as u64), p);
+    let psi_inv = pow_mod(psi, p - 2, p);
+    let n_inv = pow_mod(n as u64, p - 2, p);
+
+    let weight = |v: &[i64]| {
+        let mut w = 1;
+        v.iter()
+            .map(|&c| {
+                let c = mul_mod(c.rem_euclid(p as i64) as u64, w, p);
+                w = mul_mod(w, psi, p);
+                c
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut fa = weight(a);
+    let mut fb = weight(b);
+    ntt(&mut fa, p, false);
+    ntt(&mut fb, p, false);
+    fa.iter_mut()
+        .zip(fb)
+        .for_each(|(x, y)| *x = mul_mod(*x, y, p));
+    ntt(&mut fa, p, true);
+
+    let mut w = n_inv;
+    fa.iter_mut().for_each(|x| {
+        *x = mul_mod(*x, w, p);
+        w = mul_mod(w, psi_inv, p);
+    });
+    fa
+}
+
+/// The in-place iterative (Cooley-Tukey) cyclic NTT of length a power of two. The inverse transform
+/// is not scaled by `1/n`.
+fn ntt(a: &mut [u64], p: u64, inverse: bool) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = pow_mod(PRIMITIVE_ROOT, (p - 1) / len as u64, p);
+        if inverse {
+            w_len = pow_mod(w_len, p - 2, p);
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut w = 1;
+            for (u, v) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = mul_mod(*v, w, p);
+                *v = (*u + p - t) % p;
+                *u = (*u + t) % p;
+                w = mul_mod(w, w_len, p);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    a * b % p
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, p: u64) -> u64 {
+    let mut result = 1;
+    base %= p;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use poly_ring_xnp1::zq::ZqI64;
+    use rand::RngExt;
+
+    use super::*;
+
+    type I = ZqI64<3515337053>;
+
+    fn random_polynomial<const N: usize>(rng: &mut impl RngExt) -> Polynomial<I, N> {
+        Polynomial::new(
+            (0..N)
+                .map(|_| I::from(rng.random_range(-1757668526..=1757668526i64)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_negacyclic_mul_equals_schoolbook() {
+        let rng = &mut rand::rng();
+        for _ in 0..10 {
+            let a = random_polynomial::<64>(rng);
+            let b = random_polynomial::<64>(rng);
+            assert_eq!(negacyclic_mul(&a, &b), Some(a.clone() * b.clone()));
+        }
+        let a = random_polynomial::<1024>(rng);
+        let b = random_polynomial::<1024>(rng);
+        assert_eq!(negacyclic_mul(&a, &b), Some(a.clone() * b.clone()));
+    }
+
+    #[test]
+    fn test_negacyclic_mul_small_integers() {
+        // (1 + 2x + 3x^2) * (4 + 5x^3) mod (x^4 + 1)
+        let a = Polynomial::<i64, 4>::new(vec![1, 2, 3]);
+        let b = Polynomial::<i64, 4>::new(vec![4, 0, 0, 5]);
+        assert_eq!(negacyclic_mul(&a, &b), Some(a.clone() * b.clone()));
+        // zero polynomial
+        let z = Polynomial::<i64, 4>::zero();
+        assert_eq!(negacyclic_mul(&a, &z), Some(z));
+    }
+
+    #[test]
+    fn test_negacyclic_mul_out_of_range() {
+        let a = Polynomial::<i64, 4>::new(vec![i64::MAX]);
+        assert_eq!(negacyclic_mul(&a, &a), None);
+    }
+}
@@ -1,22 +1,42 @@
 //! Defines the public parameters for the protocol.
 
-use std::ops::{Add, Mul, Sub};
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Display},
+    ops::{Add, Mul, Sub},
+};
 
-use num::{integer::Roots, BigUint, FromPrimitive, One, ToPrimitive, Zero};
-use poly_ring_xnp1::{zq::ZqI64, Polynomial};
-use rand::{distr::uniform::SampleUniform, RngExt};
+use num::{integer::Roots, BigUint, One, ToPrimitive, Zero};
+use poly_ring_xnp1::{
+    zq::{ZqI128, ZqI64},
+    Polynomial,
+};
+use rand::RngExt;
 use serde::{Deserialize, Serialize};
 
-use crate::{mat::Mat, polynomial::norm_2, CommitmentKey};
+use crate::{
+    crt::{crt_combine, crt_modulus, crt_reduce},
+    error::{DecodeError, ParamsError},
+    integer::RingInteger,
+    mat::Mat,
+    polynomial::{
+        inverse_mod, is_invertible_mod, norm_2, norm_infinity, ring_add_reduce, ring_mul_reduce,
+        within_norm_2_ct, within_norm_infinity_ct,
+    },
+    CommitmentKey,
+};
 
 /// Public parameters for the protocol.
 ///
 /// ## Safety
 /// The struct implements Default for instantiation. If you want to use a custom parameter setting,
 /// please carefully check the constraints for the parameters (see the comment-doc for each parameters).
+/// Prefer [Params::new] or [ParamsBuilder] to setting the fields directly, as they check the constraints.
+/// The default parameters are taken from the paper for a degree `N` of 1024. Use [Params::security_estimate]
+/// for a rough check of the parameters with the chosen degree `N`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Params<I> {
-    /// Prime modulus q' divided by 2, where q' = 2*d + 1 (mod 4d). Use d = 2 in this library.
+    /// Prime modulus q' divided by 2, where q' = 2*d + 1 (mod 4d) for the splitting parameter [Params::d].
     pub q: I, // The formula is defined in Lemma 1 of the paper.
     /// Norm bound for honest prover's randomness. It is the `beta` value commonly used
     /// in lattice-based cryptography, that indicates how "random" the commitment is. It
@@ -33,13 +53,107 @@ pub struct Params<I> {
     /// The maximum norm_1 of any element in Challenge Space C. It indicates the
     /// "size" of the challenge space. The value should be a small constant.
     pub kappa: usize,
+
+    /// The norm which governs the rejection sampling of the commitment randomness and the norm
+    /// bounds in the verification of the proofs. The default is the 2-norm, as in the paper.
+    #[serde(default)]
+    pub norm: NormKind,
+
+    /// The number of irreducible factors of `X^N + 1` modulo `q'`, which determines the congruence
+    /// `q' = 2d + 1 (mod 4d)` of the modulus (Lemma 1 of the paper). It must be a power of two, and the
+    /// default is 2, i.e. `q' = 5 (mod 8)`. Note that [Params::is_invertible] and the soundness arguments of
+    /// the proofs of binary value, range and product assume `d = 2`.
+    #[serde(default = "default_d")]
+    pub d: usize,
+}
+
+/// The default splitting parameter `d` (see [Params::d]).
+const DEFAULT_D: usize = 2;
+
+fn default_d() -> usize {
+    DEFAULT_D
+}
+
+/// The norm used in the constraint checks of the commitment scheme and the proofs (see [Params::norm]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NormKind {
+    /// The 2-norm, with the bounds `4 * sigma * sqrt(N)` for the commitment randomness and
+    /// `2 * sigma * sqrt(N)` for the responses in the proofs, as defined in the paper.
+    #[default]
+    Two,
+    /// The infinity norm, with the bounds `b` for the commitment randomness and `6 * sigma` for the
+    /// responses in the proofs. The probability that a coefficient of an honest response exceeds
+    /// `6 * sigma` is about `2^-29`.
+    Infinity,
 }
 
 impl<I> Params<I>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
+    I: RingInteger,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
 {
+    /// Create the parameters, checking the constraints between them. `q` is the prime modulus `q'`
+    /// divided by 2 (see the field [Params::q]). The splitting parameter is the default `d = 2`; use
+    /// [ParamsBuilder::d] for another one.
+    ///
+    /// ## Errors
+    /// - [ParamsError::KNotGreaterThanN] if `k <= n`.
+    /// - [ParamsError::NLessThanL] if `n < l`.
+    /// - [ParamsError::KLessThanNPlusL] if `k < n + l`, i.e. the commitment matrix `a2` cannot be formed.
+    /// - [ParamsError::ModulusNotCongruent] if `q' = 2q + 1` is not congruent to `2d + 1` modulo `4d`, i.e. 5
+    ///   modulo 8 (Lemma 1 of the paper).
+    pub fn new(
+        q: I,
+        b: I,
+        n: usize,
+        k: usize,
+        l: usize,
+        kappa: usize,
+    ) -> Result<Self, ParamsError> {
+        Self::new_with_d(q, b, n, k, l, kappa, DEFAULT_D)
+    }
+
+    /// Create the parameters as [Params::new] does, with the splitting parameter `d`.
+    ///
+    /// ## Errors
+    /// Returns the same errors as [Params::new], and [ParamsError::DNotPowerOfTwo] if `d` is not a power of two.
+    fn new_with_d(
+        q: I,
+        b: I,
+        n: usize,
+        k: usize,
+        l: usize,
+        kappa: usize,
+        d: usize,
+    ) -> Result<Self, ParamsError> {
+        if k <= n {
+            return Err(ParamsError::KNotGreaterThanN { k, n });
+        }
+        if n < l {
+            return Err(ParamsError::NLessThanL { n, l });
+        }
+        if k < n + l {
+            return Err(ParamsError::KLessThanNPlusL { k, n, l });
+        }
+        if !d.is_power_of_two() {
+            return Err(ParamsError::DNotPowerOfTwo { d });
+        }
+        let modulus = 2 * q.to_i128().unwrap() + 1;
+        if modulus.rem_euclid(4 * d as i128) != 2 * d as i128 + 1 {
+            return Err(ParamsError::ModulusNotCongruent { modulus, d });
+        }
+        Ok(Params {
+            q,
+            b,
+            n,
+            k,
+            l,
+            kappa,
+            norm: NormKind::default(),
+            d,
+        })
+    }
+
     /// Generate a new commitment key. The generic parameter N indicates the maximum length of the integer vector.
     /// It must be a power of two.
     ///
@@ -53,10 +167,40 @@ where
         CommitmentKey::new(rng, self)
     }
 
+    /// Generate a new commitment key as [Params::generate_commitment_key] does, with the randomness from
+    /// [crate::rng::secure_rng], i.e. a CSPRNG seeded by the operating system.
+    ///
+    /// ## Panics
+    /// Panics if the constant `N` is not a power of two, or the random number generator of the operating
+    /// system fails.
+    #[cfg(feature = "os-rng")]
+    #[inline]
+    pub fn generate_commitment_key_secure<const N: usize>(&self) -> CommitmentKey<I, N> {
+        CommitmentKey::new(&mut crate::rng::secure_rng(), self)
+    }
+
+    /// Generate the commitment key deterministically from a public 32-byte seed, e.g. for sharing a common
+    /// reference string between the prover and the verifier. The same seed and parameters always give the
+    /// same commitment key. It is the same as [CommitmentKey::from_seed].
+    ///
+    /// ## Panics
+    /// Panics if the constant `N` is not a power of two.
+    #[inline]
+    pub fn generate_commitment_key_from_seed<const N: usize>(
+        &self,
+        seed: [u8; 32],
+    ) -> CommitmentKey<I, N> {
+        CommitmentKey::from_seed(seed, self)
+    }
+
     /// Prepare the value for the commitment. The input is a matrix (of size `l` x 1) of integer vectors.
     /// The generic parameter N indicates the maximum length of the integer vector. It must be a power
     /// of two.
     ///
+    /// The `i`-th integer vector is the coefficients (from the constant term) of the `i`-th message
+    /// polynomial `x_i`, which is committed in the `i`-th row of `c2 = a2 * r + x`. For `l > 1`, the relations
+    /// in the proofs (e.g. `x' = g * x`) hold for each of the `l` message polynomials.
+    ///
     /// This method is for wrapping the input value into a polynomial which is used as primitive element
     /// in the library.
     ///
@@ -68,11 +212,232 @@ where
         &self,
         value: Vec<Vec<impl Into<I>>>,
     ) -> Vec<Polynomial<I, N>> {
-        assert!(value.len() == self.l);
-        value
+        match self.try_prepare_value(value) {
+            Ok(x) => x,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Prepare the value for the commitment as [Params::prepare_value] does, but return an error instead
+    /// of panicking, so that untrusted input can be validated.
+    ///
+    /// ## Errors
+    /// - [ParamsError::WrongMessageLength] if the `value.len()` is not equal to the message length (`l`).
+    /// - [ParamsError::NPowerOfTwo] if the constant `N` is not a power of two.
+    pub fn try_prepare_value<const N: usize>(
+        &self,
+        value: Vec<Vec<impl Into<I>>>,
+    ) -> Result<Vec<Polynomial<I, N>>, ParamsError> {
+        if value.len() != self.l {
+            return Err(ParamsError::WrongMessageLength {
+                expected: self.l,
+                got: value.len(),
+            });
+        }
+        if !N.is_power_of_two() {
+            return Err(ParamsError::NPowerOfTwo);
+        }
+        Ok(value
             .into_iter()
             .map(|v| v.into_iter().map(Into::into).collect())
             .map(Polynomial::<I, N>::from_coeffs)
+            .collect())
+    }
+
+    /// Prepare the value for the commitment from the raw bytes `data`, e.g. arbitrary application data. The
+    /// bytes are padded by a single `0x80` byte followed by zero bytes to the capacity, and packed into the
+    /// coefficients of the `l` message polynomials in chunks of [Params::bytes_per_coeff] bytes (little-endian),
+    /// so that every coefficient is in the range `[0, q]`. The message is recovered by [Params::decode_to_bytes].
+    ///
+    /// The capacity is `l * N * bytes_per_coeff - 1` bytes, e.g. 1535 bytes for the default parameters with
+    /// the degree `N` of 512.
+    ///
+    /// ## Errors
+    /// - [ParamsError::NPowerOfTwo] if the constant `N` is not a power of two.
+    /// - [ParamsError::DataTooLong] if the length of `data` exceeds the capacity.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key::<N>(rng);
+    ///
+    /// let x = params.prepare_value_from_bytes::<N>(b"hello").unwrap();
+    /// let (open, com) = ck.commit(rng, x, &params);
+    /// assert!(com.verify(&open, &ck, &params));
+    /// assert_eq!(params.decode_to_bytes(open.x()).unwrap(), b"hello");
+    /// ```
+    pub fn prepare_value_from_bytes<const N: usize>(
+        &self,
+        data: &[u8],
+    ) -> Result<Vec<Polynomial<I, N>>, ParamsError> {
+        if !N.is_power_of_two() {
+            return Err(ParamsError::NPowerOfTwo);
+        }
+        let chunk = self.bytes_per_coeff();
+        let capacity = (self.l * N * chunk).saturating_sub(1);
+        if data.len() > capacity {
+            return Err(ParamsError::DataTooLong {
+                capacity,
+                got: data.len(),
+            });
+        }
+        let mut padded = data.to_vec();
+        padded.push(0x80);
+        padded.resize(self.l * N * chunk, 0);
+
+        let coeffs = padded
+            .chunks(chunk)
+            .map(|c| {
+                let v = c
+                    .iter()
+                    .rev()
+                    .fold(0u128, |acc, byte| (acc << 8) | *byte as u128);
+                I::from_u128(v).unwrap()
+            })
+            .collect::<Vec<_>>();
+        Ok(coeffs
+            .chunks(N)
+            .map(|c| Polynomial::from_coeffs(c.to_vec()))
+            .collect())
+    }
+
+    /// Recover the bytes from the message `x` prepared by [Params::prepare_value_from_bytes], e.g. the
+    /// committed message of an opening ([crate::Opening::x]).
+    ///
+    /// ## Errors
+    /// - [DecodeError::DimensionMismatch] if the length of `x` is not equal to the message length (`l`).
+    /// - [DecodeError::CoefficientOutOfRange] if a coefficient does not fit into [Params::bytes_per_coeff]
+    ///   bytes.
+    /// - [DecodeError::InvalidPadding] if the bytes do not end with the padding `0x80` followed by zero bytes.
+    pub fn decode_to_bytes<const N: usize>(
+        &self,
+        x: &[Polynomial<I, N>],
+    ) -> Result<Vec<u8>, DecodeError> {
+        if x.len() != self.l {
+            return Err(DecodeError::DimensionMismatch {
+                expected: (self.l, 1),
+                got: (x.len(), 1),
+            });
+        }
+        let chunk = self.bytes_per_coeff();
+        let modulus = 2 * self.q.to_i128().unwrap() + 1;
+        let mut bytes = Vec::with_capacity(self.l * N * chunk);
+        for p in x {
+            let mut coeffs = p
+                .iter()
+                .map(|c| c.to_i128().unwrap().rem_euclid(modulus) as u128)
+                .collect::<Vec<_>>();
+            coeffs.resize(N, 0);
+            for c in coeffs {
+                if chunk < 16 && c >> (8 * chunk) != 0 {
+                    return Err(DecodeError::CoefficientOutOfRange);
+                }
+                bytes.extend_from_slice(&c.to_le_bytes()[..chunk]);
+            }
+        }
+
+        let len = bytes
+            .iter()
+            .rposition(|b| *b != 0)
+            .ok_or(DecodeError::InvalidPadding)?;
+        if bytes[len] != 0x80 {
+            return Err(DecodeError::InvalidPadding);
+        }
+        bytes.truncate(len);
+        Ok(bytes)
+    }
+
+    /// The number of bytes packed into a coefficient by [Params::prepare_value_from_bytes], i.e. the largest
+    /// `c` s.t. `256^c - 1 <= q`. It is 3 for the default parameters.
+    pub fn bytes_per_coeff(&self) -> usize {
+        let q = self.q.to_u128().unwrap();
+        (1..16)
+            .take_while(|c| (1u128 << (8 * c)) - 1 <= q)
+            .last()
+            .unwrap_or(0)
+    }
+
+    /// Prepare the value for the commitment as [Params::prepare_value] does, with each integer reduced into the
+    /// balanced residue range `[-q, q]` of the modulus `q' = 2q + 1` beforehand. The message is then canonical,
+    /// and the coefficients of untrusted input are bounded, whether or not the coefficient type reduces
+    /// modulo `q'` by itself.
+    ///
+    /// ## Panics
+    /// Panics if the conditions described in [Params::prepare_value] are not satisfied.
+    pub fn prepare_value_reduced<const N: usize>(
+        &self,
+        value: Vec<Vec<impl Into<i128>>>,
+    ) -> Vec<Polynomial<I, N>> {
+        let value = value
+            .into_iter()
+            .map(|v| {
+                v.into_iter()
+                    .map(|c| self.reduce(c.into()))
+                    .collect::<Vec<I>>()
+            })
+            .collect();
+        self.prepare_value(value)
+    }
+
+    /// Prepare the value for the commitment in CRT (Chinese Remainder Theorem) mode. The input is a matrix
+    /// (of size `l` x 1) of integer vectors, where each integer is given as its residues modulo the
+    /// component `moduli`, i.e. `value[i][j][m]` is the residue of the `j`-th coefficient of the `i`-th
+    /// message polynomial modulo `moduli[m]`. The residues of each coefficient are packed into a single
+    /// integer in `[0, m_1 * m_2 * ...)`, and can be recovered by [Params::recover_value_crt].
+    ///
+    /// This is only an encoding layer. The commitment itself is still over `Z_q`.
+    ///
+    /// ## Constraints
+    /// - The `moduli` must be pairwise coprime.
+    /// - The product of the `moduli` must not exceed `q`, so that every packed integer is represented
+    ///   without wrapping around modulo `q`.
+    /// - Homomorphic operations on the committed values are only meaningful per slot if the packed
+    ///   result does not exceed `q` either.
+    ///
+    /// ## Panics
+    /// Panics if the constraints are not satisfied, any residue is not less than its modulus, or
+    /// the conditions described in [Params::prepare_value] are not satisfied.
+    pub fn prepare_value_crt<const N: usize>(
+        &self,
+        value: Vec<Vec<Vec<u64>>>,
+        moduli: &[u64],
+    ) -> Vec<Polynomial<I, N>> {
+        let m = crt_modulus(moduli);
+        assert!(
+            BigUint::from(m) <= BigUint::from(self.q.to_u128().unwrap()),
+            "product of moduli exceeds q"
+        );
+        let value = value
+            .into_iter()
+            .map(|v| {
+                v.into_iter()
+                    .map(|residues| I::from_u64(crt_combine(&residues, moduli)).unwrap())
+                    .collect::<Vec<I>>()
+            })
+            .collect();
+        self.prepare_value(value)
+    }
+
+    /// Recover the residues packed by [Params::prepare_value_crt] from the message `x`. The returned
+    /// matrix has the same layout as the input of [Params::prepare_value_crt], except that the trailing
+    /// zero coefficients of the polynomials are omitted.
+    pub fn recover_value_crt<const N: usize>(
+        &self,
+        x: &[Polynomial<I, N>],
+        moduli: &[u64],
+    ) -> Vec<Vec<Vec<u64>>> {
+        x.iter()
+            .map(|p| {
+                p.iter()
+                    .map(|c| crt_reduce(c.to_i128().unwrap(), moduli))
+                    .collect()
+            })
             .collect()
     }
 
@@ -90,31 +455,525 @@ where
         Polynomial::from_coeffs(scalar.into_iter().map(Into::into).collect::<Vec<I>>())
     }
 
-    /// The standard deviation used in the zero-knowledge proof.
-    pub(crate) fn standard_deviation(&self, deg_n: usize) -> usize {
+    /// Multiply the polynomials `a` and `b` in the ring `R_q = Z_q'[X]/(X^N+1)`, where `q' = 2q + 1`, and reduce
+    /// the coefficients into the balanced residue range `[-q, q]`.
+    ///
+    /// It is the ring multiplication used by the commitment scheme and the proofs, independent of the
+    /// coefficient type, i.e. the result is the same whether `I` reduces modulo `q'` (e.g. `ZqI64`) or not
+    /// (e.g. `i64`), and can be used to define custom relations on the committed values.
+    ///
+    /// ## Panics
+    /// Panics if `q'` is not less than `2^63`.
+    pub fn ring_mul<const N: usize>(
+        &self,
+        a: &Polynomial<I, N>,
+        b: &Polynomial<I, N>,
+    ) -> Polynomial<I, N> {
+        let modulus = 2 * self.q.to_i128().unwrap() + 1;
+        assert!(modulus < 1 << 63, "modulus must be less than 2^63");
+        ring_mul_reduce(a, b, modulus)
+    }
+
+    /// Add the polynomials `a` and `b` in the ring `R_q = Z_q'[X]/(X^N+1)`, where `q' = 2q + 1`, and reduce the
+    /// coefficients into the balanced residue range `[-q, q]` (see [Params::ring_mul]).
+    pub fn ring_add<const N: usize>(
+        &self,
+        a: &Polynomial<I, N>,
+        b: &Polynomial<I, N>,
+    ) -> Polynomial<I, N> {
+        ring_add_reduce(a, b, 2 * self.q.to_i128().unwrap() + 1)
+    }
+
+    /// Returns `true` if the polynomial `p` is invertible in the ring `R_q = Z_q'[X]/(X^N+1)`, where `q' = 2q + 1`.
+    ///
+    /// As `q' = 5 (mod 8)`, `X^N + 1` splits into two irreducible factors `X^(N/2) - s` and `X^(N/2) + s` modulo
+    /// `q'`, where `s^2 = -1` (Lemma 1 of the paper). The negacyclic NTT over `Z_q'` does not exist, so instead
+    /// of checking the slots of the NTT, `p` is reduced modulo both factors, and it is invertible iff neither
+    /// residue is zero. It can be used to check the assumption of the special-soundness extractor that the
+    /// differences of the challenges (e.g. [crate::challenge_space::random_polynomial_from_challenge_set_difference])
+    /// are invertible.
+    ///
+    /// ## Panics
+    /// Panics if `q'` is not less than `2^63`, the constant `N` is not a power of two, or the splitting
+    /// parameter [Params::d] is not 2. The result is only meaningful if `q'` is a prime, as assumed by the paper.
+    pub fn is_invertible<const N: usize>(&self, p: &Polynomial<I, N>) -> bool {
+        assert_eq!(self.d, 2, "d must be 2");
+        let modulus = 2 * self.q.to_i128().unwrap() + 1;
+        assert!(modulus < 1 << 63, "modulus must be less than 2^63");
+        assert!(N.is_power_of_two(), "N must be a power of two");
+        is_invertible_mod(p, modulus)
+    }
+
+    /// Returns the inverse of `p` in the ring `R_q = Z_q'[X]/(X^N+1)`, where `q' = 2q + 1`, with the coefficients
+    /// in the balanced residue range `[-q, q]`, or `None` if `p` is not invertible (see [Params::is_invertible]).
+    /// `ring_mul(p, inverse)` is the constant polynomial `1`.
+    ///
+    /// It is used by the special-soundness extractor ([crate::OpenProofVerifier::extract]), which divides by the
+    /// difference of two challenges.
+    ///
+    /// ## Panics
+    /// Panics if `q'` is not less than `2^63`. The result is only meaningful if `q'` is a prime, as assumed by
+    /// the paper.
+    pub fn ring_inverse<const N: usize>(&self, p: &Polynomial<I, N>) -> Option<Polynomial<I, N>> {
+        let modulus = 2 * self.q.to_i128().unwrap() + 1;
+        assert!(modulus < 1 << 63, "modulus must be less than 2^63");
+        inverse_mod(p, modulus)
+    }
+
+    /// Prepare the scalar as [Params::prepare_scalar] does, with each integer reduced into the balanced residue
+    /// range `[-q, q]` (see [Params::prepare_value_reduced]).
+    ///
+    /// ## Panics
+    /// Panics if the constant `N` is not a power of two.
+    pub fn prepare_scalar_reduced<const N: usize>(
+        &self,
+        scalar: Vec<impl Into<i128>>,
+    ) -> Polynomial<I, N> {
+        self.prepare_scalar(
+            scalar
+                .into_iter()
+                .map(|c| self.reduce(c.into()))
+                .collect::<Vec<I>>(),
+        )
+    }
+
+    /// Reduce the integer into the balanced residue range `[-q, q]` of the modulus `q' = 2q + 1`.
+    fn reduce(&self, v: i128) -> I {
+        let q = self.q.to_i128().unwrap();
+        let v = v.rem_euclid(2 * q + 1);
+        I::from_i128(if v > q { v - (2 * q + 1) } else { v }).unwrap()
+    }
+
+    /// The number of polynomial multiplications in `R_q` performed by [CommitmentKey::commit], which is
+    /// the product of the `(n + l) x k` commitment key and the `k x 1` randomness. The cost of each
+    /// multiplication grows with the degree `N`.
+    pub fn estimated_commit_ops(&self) -> u64 {
+        ((self.n + self.l) * self.k) as u64
+    }
+
+    /// The number of polynomial multiplications in `R_q` performed by a full run of the proof of opening
+    /// a commitment, i.e. the prover commits (`A * r` and `A1 * y`) and creates the response (`d * r`),
+    /// and the verifier verifies the response (`A1 * z` and `c1 * d`).
+    pub fn estimated_open_proof_ops(&self) -> u64 {
+        let (n, k) = (self.n as u64, self.k as u64);
+        self.estimated_commit_ops() + 2 * n * k + k + n
+    }
+
+    /// Returns a rough estimate of the bit security of the commitment scheme with these parameters and the
+    /// degree `N`, so that users can check that their choice of `N` does not make the parameters insecure.
+    ///
+    /// The estimate only considers the binding property, i.e. the hardness of the Module-SIS problem of
+    /// finding a short `z` (the difference of two openings, of norm at most `8 * sigma * sqrt(k * N)`)
+    /// s.t. `A1 * z = 0` modulo `q' = 2q + 1`. The root Hermite factor `delta` required by lattice reduction
+    /// to find such a vector is `log2(delta) = log2(beta)^2 / (4 * n * N * log2(q'))`. The BKZ block size
+    /// achieving `delta` is converted to bits by the core-SVP model (`0.292` bits per block size).
+    ///
+    /// ## Safety
+    /// It is a heuristic, not a proof of security. It ignores the hiding property and the improvements of
+    /// the lattice attacks after the model, so it should only be used as a warning against insecure choices.
+    /// The block size is not less than 50, where the model breaks down, so that an estimate of about 15 bits
+    /// means the parameters are broken.
+    pub fn security_estimate<const N: usize>(&self) -> SecurityEstimate {
+        let log_q = (2.0 * self.q.to_f64().unwrap() + 1.0).log2();
+        let beta = 8.0 * self.sigma_exact::<N>() * ((self.k * N) as f64).sqrt();
+        if beta.log2() >= log_q {
+            // the short vector (q', 0, .., 0) is a trivial solution.
+            return SecurityEstimate {
+                root_hermite_factor: f64::INFINITY,
+                block_size: 0,
+                bits: 0.0,
+            };
+        }
+        let log_delta = beta.log2().powi(2) / (4.0 * (self.n * N) as f64 * log_q);
+        let root_hermite_factor = log_delta.exp2();
+
+        // the smallest block size b s.t. delta(b) <= root_hermite_factor, where
+        // delta(b) = ((pi * b)^(1/b) * b / (2 * pi * e))^(1 / (2 * (b - 1)))
+        let delta = |b: f64| {
+            ((core::f64::consts::PI * b).powf(1.0 / b) * b
+                / (2.0 * core::f64::consts::PI * core::f64::consts::E))
+                .powf(1.0 / (2.0 * (b - 1.0)))
+        };
+        let max_block_size = self.k * N;
+        let block_size = (50..max_block_size)
+            .find(|&b| delta(b as f64) <= root_hermite_factor)
+            .unwrap_or(max_block_size)
+            .max(50);
+        SecurityEstimate {
+            root_hermite_factor,
+            block_size,
+            bits: 0.292 * block_size as f64,
+        }
+    }
+
+    /// Returns the size of Challenge Space C (see [crate::challenge_space]) for the degree `N`, i.e. the number
+    /// of polynomials with exactly `kappa` coefficients in `{-1, 1}` and the rest 0, which is
+    /// `|C| = 2^kappa * binomial(N, kappa)`. It is 0 if `kappa > N`.
+    ///
+    /// A cheating prover who does not know an opening can answer at most one challenge of a run of the Sigma
+    /// protocols (special soundness), so the soundness error of a single run is `1 / |C|`, i.e. at least
+    /// `challenge_set_size::<N>().bits() - 1` bits of soundness.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// let params = Params::default();
+    /// // 2^36 * binomial(512, 36), i.e. about 2^220
+    /// assert_eq!(params.challenge_set_size::<512>().bits(), 221);
+    /// ```
+    pub fn challenge_set_size<const N: usize>(&self) -> BigUint {
+        binomial(N, self.kappa) << self.kappa
+    }
+
+    /// Returns the size of the set C-bar of the differences `c - c'` of distinct challenges `c, c'` in
+    /// Challenge Space C for the degree `N`. The special-soundness extractor requires the differences to be
+    /// invertible (see [Params::is_invertible]).
+    ///
+    /// A polynomial `v` with `n2` coefficients in `{-2, 2}`, `n1` coefficients in `{-1, 1}` and the rest 0 is
+    /// a difference iff `v != 0`, `n1` is even and `n2 + n1 / 2 <= kappa <= N - n1 / 2`, as both `c` and `c'`
+    /// must have `kappa` non-zero coefficients. The size is the number of such polynomials.
+    pub fn challenge_difference_set_size<const N: usize>(&self) -> BigUint {
+        let kappa = self.kappa;
+        let mut size = BigUint::zero();
+        for n2 in 0..=N.min(kappa) {
+            for n1 in (0..=N - n2).step_by(2) {
+                if n2 + n1 / 2 > kappa || kappa > N - n1 / 2 {
+                    continue;
+                }
+                size += (binomial(N, n2) * binomial(N - n2, n1)) << (n1 + n2);
+            }
+        }
+        // the zero polynomial, i.e. c = c'
+        if size > BigUint::zero() {
+            size -= BigUint::one();
+        }
+        size
+    }
+
+    /// Returns the canonical byte encoding of the parameters together with the degree `N`, which is a part of
+    /// the public statement of the proofs. The norm is encoded as a tag byte (0 for the 2-norm and 1 for the
+    /// infinity norm).
+    pub(crate) fn statement_bytes<const N: usize>(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * 16 + 6 * 8 + 1);
+        bytes.extend(self.q.to_i128().unwrap().to_le_bytes());
+        bytes.extend(self.b.to_i128().unwrap().to_le_bytes());
+        [self.n, self.k, self.l, self.kappa, self.d, N]
+            .iter()
+            .for_each(|v| bytes.extend((*v as u64).to_le_bytes()));
+        bytes.push(match self.norm {
+            NormKind::Two => 0,
+            NormKind::Infinity => 1,
+        });
+        bytes
+    }
+
+    /// The standard deviation used in the zero-knowledge proof. It is computed in `u128`, so it does not
+    /// overflow for any `k * deg_n` and `kappa` representable in `usize`.
+    ///
+    /// ## Panics
+    /// Panics if the result exceeds `u64::MAX`, i.e. `b * 11 * kappa * sqrt(k * deg_n) >= 2^64`. For example,
+    /// with `b = 1` and `k = 3`, it holds for `kappa` up to `2^20` with any `deg_n`, and for any `kappa`
+    /// up to `2^32` with `deg_n` up to `2^30`.
+    pub(crate) fn standard_deviation(&self, deg_n: usize) -> u64 {
         // The formula defined in Table 1 of the paper:
         // sigma = 11 * kappa * b * sqrt(k*deg_n)
-        self.b.to_usize().unwrap() * (11 * self.kappa) * (self.k * deg_n).sqrt()
+        let sigma = self.b.to_u128().unwrap()
+            * (11 * self.kappa as u128)
+            * (self.k as u128 * deg_n as u128).sqrt();
+        u64::try_from(sigma).expect("standard deviation exceeds u64::MAX")
+    }
+
+    /// The standard deviation used for sampling the masking polynomials in the zero-knowledge proof.
+    ///
+    /// It computes the same formula as [Params::standard_deviation] but with `f64` arithmetic, i.e.
+    /// `sqrt(k*N)` is not truncated to an integer. The two values may differ slightly (by less than
+    /// `11 * kappa * b`). The sampler uses this exact value so that its distribution follows the formula
+    /// in the paper, while an integer value (rounded up from this exact value) is kept for the norm bounds
+    /// in the constraint checks.
+    pub fn sigma_exact<const N: usize>(&self) -> f64 {
+        // sigma = 11 * kappa * b * sqrt(k*N)
+        self.b.to_f64().unwrap() * (11 * self.kappa) as f64 * ((self.k * N) as f64).sqrt()
+    }
+
+    /// The integer standard deviation for the norm bounds in the constraint checks. It is [Params::standard_deviation]
+    /// but not less than [Params::sigma_exact], so that the bounds are never tighter than the ones for the
+    /// distribution of the masking polynomials.
+    fn bound_standard_deviation<const N: usize>(&self) -> u64 {
+        self.standard_deviation(N)
+            .max(self.sigma_exact::<N>().ceil() as u64)
+    }
+
+    /// The norm bound of the commitment constraint on the randomness `r` of an opening, in the norm selected
+    /// by [Params::norm]: `4 * sigma * sqrt(N)` for the 2-norm, or `b` for the infinity norm. The integer
+    /// `sigma` is the standard deviation of the proofs for the degree `N`, computed in 128 bits.
+    pub fn commit_norm_bound<const N: usize>(&self) -> u128 {
+        match self.norm {
+            NormKind::Two => 4 * self.bound_standard_deviation::<N>() as u128 * N.sqrt() as u128,
+            NormKind::Infinity => self.b.to_u128().unwrap(),
+        }
+    }
+
+    /// The norm bound of the responses checked by the verifiers of the proofs, in the norm selected by
+    /// [Params::norm]: `2 * sigma * sqrt(N)` for the 2-norm, or `6 * sigma` for the infinity norm. The
+    /// integer `sigma` is the standard deviation of the proofs for the degree `N`, computed in 128 bits.
+    pub fn verify_norm_bound<const N: usize>(&self) -> u128 {
+        let sigma = self.bound_standard_deviation::<N>() as u128;
+        match self.norm {
+            NormKind::Two => 2 * sigma * N.sqrt() as u128,
+            NormKind::Infinity => 6 * sigma,
+        }
+    }
+
+    /// Returns a [Display] adapter which prints the parameters (as the [Display] of [Params] does) together with
+    /// the quantities derived for the degree `N`: the integer standard deviation `sigma` of the norm bounds, and
+    /// the norm bounds [Params::commit_norm_bound] and [Params::verify_norm_bound].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::Params;
+    ///
+    /// let params = Params::default();
+    /// println!("{}", params.display::<512>());
+    /// ```
+    pub fn display<const N: usize>(&self) -> ParamsDisplay<'_, I, N> {
+        ParamsDisplay { params: self }
     }
 
-    /// Check the commitment constraint. norm_2(r_i) must be less or equal to 4*sigma*sqrt(N).
-    /// It is used in the commitment scheme.
+    /// Returns an estimate of the probability that a randomness `r`, sampled uniformly from `[-b, b]^(k x N)` by
+    /// [CommitmentKey::commit], satisfies the commitment constraint, i.e. each of the `k` polynomials of `r`
+    /// has the norm at most [Params::commit_norm_bound]. The expected number of attempts in the rejection
+    /// sampling of the commitment is its reciprocal.
+    ///
+    /// For the 2-norm, the squared norm of a polynomial is a sum of `N` independent squares of uniform
+    /// integers in `[-b, b]`, so it is approximated by a Gaussian with the mean `N * b(b+1)/3` and the
+    /// variance `N * (b(b+1)(3b^2+3b-1)/15 - (b(b+1)/3)^2)` by the central limit theorem. The `k`
+    /// polynomials are independent, so the probability for one polynomial is raised to the power `k`.
+    /// For the infinity norm with the bound `b`, every `r` is accepted.
+    ///
+    /// ## Safety
+    /// It is an approximation, which is accurate for large `N` and becomes coarse in the tails, e.g. for
+    /// small `N` or when the bound is far below the mean, where the actual probability is tiny or zero.
+    /// Use it to choose the parameters, not to bound the running time exactly. The actual distribution is
+    /// the one of the sampler of the coefficient type, e.g. `ZqI64` samples from `[-b, b)`, for which the
+    /// estimate is slightly pessimistic.
+    pub fn estimated_accept_probability<const N: usize>(&self) -> f64 {
+        match self.norm {
+            NormKind::Two => norm_2_accept_probability(
+                self.b.to_f64().unwrap(),
+                N,
+                self.k,
+                self.commit_norm_bound::<N>(),
+            ),
+            NormKind::Infinity => 1.0,
+        }
+    }
+
+    /// Returns the parameters of the commitments created by [Commitment::concat](crate::Commitment::concat)
+    /// from `m` commitments, i.e. `n`, `k` and `l` multiplied by `m`. The norm bounds grow by about
+    /// `sqrt(m)` with `k`.
+    ///
+    /// ## Panics
+    /// Panics if `m` is zero.
+    pub fn concat(&self, m: usize) -> Self {
+        assert!(m > 0, "at least one block is required");
+        Params {
+            n: self.n * m,
+            k: self.k * m,
+            l: self.l * m,
+            ..self.clone()
+        }
+    }
+
+    /// Check the commitment constraint. It is used in the commitment scheme. The norm of each r_i must be
+    /// less or equal to [Params::commit_norm_bound] in the norm selected by [Params::norm].
     pub(crate) fn check_commit_constraint<const N: usize>(&self, r: &Mat<I, N>) -> bool {
-        let sigma = self.standard_deviation(N);
-        let constraint = BigUint::from(4 * sigma * N.sqrt());
+        self.check_norm(r, self.commit_norm_bound::<N>())
+    }
+
+    /// Check the constraint for verification in zk protocol. It is used in the verification step in the zk
+    /// protocol. The norm of each r_i must be less or equal to [Params::verify_norm_bound] in the norm selected
+    /// by [Params::norm].
+    pub(crate) fn check_verify_constraint<const N: usize>(&self, r: &Mat<I, N>) -> bool {
+        self.check_norm(r, self.verify_norm_bound::<N>())
+    }
+
+    /// Check that the norm of every polynomial in `r` is less or equal to `bound`, in the norm selected by
+    /// [Params::norm]. With the feature `constant-time`, it is [Params::check_norm_ct], otherwise
+    /// [Params::check_norm_fast].
+    fn check_norm<const N: usize>(&self, r: &Mat<I, N>, bound: u128) -> bool {
+        if cfg!(feature = "constant-time") {
+            self.check_norm_ct(r, bound)
+        } else {
+            self.check_norm_fast(r, bound)
+        }
+    }
+
+    /// The default path of [Params::check_norm], which stops at the first polynomial exceeding the bound.
+    fn check_norm_fast<const N: usize>(&self, r: &Mat<I, N>, bound: u128) -> bool {
+        let norm: fn(&Polynomial<I, N>) -> BigUint = match self.norm {
+            NormKind::Two => norm_2,
+            NormKind::Infinity => norm_infinity,
+        };
+        let bound = BigUint::from(bound);
         r.polynomials
             .iter()
-            .all(|r_i| r_i.iter().all(|r_ij| norm_2(r_ij) <= constraint))
+            .all(|r_i| r_i.iter().all(|r_ij| norm(r_ij) <= bound))
     }
 
-    /// Check the constraint for verification in zk protocol. norm_2(r_i) must be less or equal to 2*sigma*sqrt(N).
-    /// It is used in the verification step in the zk protocol.
-    pub(crate) fn check_verify_constraint<const N: usize>(&self, r: &Mat<I, N>) -> bool {
-        let sigma = self.standard_deviation(N);
-        let constraint = BigUint::from(2 * sigma * N.sqrt());
+    /// The maximum norm of the polynomials in `r`, in the norm selected by [Params::norm].
+    pub(crate) fn max_norm<const N: usize>(&self, r: &Mat<I, N>) -> u128 {
+        let norm: fn(&Polynomial<I, N>) -> BigUint = match self.norm {
+            NormKind::Two => norm_2,
+            NormKind::Infinity => norm_infinity,
+        };
         r.polynomials
             .iter()
-            .all(|r_i| r_i.iter().all(|r_ij| norm_2(r_ij) <= constraint))
+            .flatten()
+            .map(|r_ij| norm(r_ij).to_u128().unwrap_or(u128::MAX))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The constant-time path of [Params::check_norm]. It scans all coefficients of all polynomials in `r`
+    /// with fixed-width arithmetic, and folds the comparisons without an early exit, so that the running time
+    /// does not reveal which polynomial or coefficient exceeds the bound, nor by how much.
+    ///
+    /// The threat model is a party checking a **secret** randomness, e.g. the randomness of an opening in
+    /// [crate::Commitment::verify] or [crate::Opening::is_valid_norm], against an observer of its timing.
+    /// The responses in the proofs are public, so their checks do not need it. It is best-effort at the
+    /// source level: the conversion of the coefficients by the coefficient type, and the code generated by
+    /// the compiler, are outside the control of this crate.
+    fn check_norm_ct<const N: usize>(&self, r: &Mat<I, N>, bound: u128) -> bool {
+        let within: fn(&Polynomial<I, N>, u128) -> bool = match self.norm {
+            NormKind::Two => within_norm_2_ct,
+            NormKind::Infinity => within_norm_infinity_ct,
+        };
+        r.polynomials
+            .iter()
+            .flatten()
+            .fold(true, |acc, r_ij| acc & within(r_ij, bound))
+    }
+}
+
+/// Returns the Gaussian approximation of the probability that all `k` polynomials with `deg_n` coefficients,
+/// uniform in `[-b, b]`, have the 2-norm at most `bound` (see [Params::estimated_accept_probability]).
+fn norm_2_accept_probability(b: f64, deg_n: usize, k: usize, bound: u128) -> f64 {
+    let mean = b * (b + 1.0) / 3.0; // E[x^2]
+    let fourth = b * (b + 1.0) * (3.0 * b * b + 3.0 * b - 1.0) / 15.0; // E[x^4]
+    let (mean, variance) = (deg_n as f64 * mean, deg_n as f64 * (fourth - mean * mean));
+    // floor(sqrt(s)) <= bound iff s < (bound + 1)^2, with the continuity correction for the integer s
+    let limit = (bound as f64 + 1.0).powi(2) - 0.5;
+    let p = if variance > 0.0 {
+        standard_normal_cdf((limit - mean) / variance.sqrt())
+    } else if limit >= mean {
+        1.0
+    } else {
+        0.0
+    };
+    p.powi(k as i32)
+}
+
+/// The cumulative distribution function of the standard normal distribution, by the approximation 7.1.26 of
+/// the error function in Abramowitz and Stegun (absolute error less than `1.5e-7`).
+fn standard_normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / core::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * z);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+    if x >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// The binomial coefficient `n choose k`, which is 0 if `k > n`.
+fn binomial(n: usize, k: usize) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+    let k = k.min(n - k);
+    (0..k).fold(BigUint::one(), |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// A rough estimate of the bit security of the parameters, returned by [Params::security_estimate].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SecurityEstimate {
+    /// The root Hermite factor required to break the binding property by lattice reduction.
+    pub root_hermite_factor: f64,
+    /// The BKZ block size achieving the root Hermite factor.
+    pub block_size: usize,
+    /// The estimated bit security in the core-SVP model.
+    pub bits: f64,
+}
+
+impl SecurityEstimate {
+    /// Returns `true` if the estimated bit security is at least `bits`.
+    pub fn meets(&self, bits: f64) -> bool {
+        self.bits >= bits
+    }
+}
+
+impl<I> Display for Params<I>
+where
+    I: ToPrimitive,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "q: {}, b: {}, n: {}, k: {}, l: {}, kappa: {}, norm: {:?}, A: {}x{}",
+            self.q.to_i128().unwrap(),
+            self.b.to_i128().unwrap(),
+            self.n,
+            self.k,
+            self.l,
+            self.kappa,
+            self.norm,
+            self.n + self.l,
+            self.k
+        )
+    }
+}
+
+/// Prints the parameters with the quantities derived for the degree `N`, returned by [Params::display].
+pub struct ParamsDisplay<'a, I, const N: usize> {
+    params: &'a Params<I>,
+}
+
+impl<I, const N: usize> Display for ParamsDisplay<'_, I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params = self.params;
+        write!(
+            f,
+            "{params}, N: {N}, sigma: {}, commit bound: {}, verify bound: {}",
+            params.bound_standard_deviation::<N>(),
+            params.commit_norm_bound::<N>(),
+            params.verify_norm_bound::<N>()
+        )
+    }
+}
+
+/// Configuration of the retry loop in the prover (e.g. [OpenProofProver::prove_with_retry](crate::OpenProofProver::prove_with_retry)),
+/// which samples the masking polynomials again if the response exceeds the norm bound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// The maximum number of attempts before the prover gives up.
+    pub max_attempts: usize,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        // The response exceeds the norm bound with a tiny probability for sane parameters.
+        Self { max_attempts: 16 }
     }
 }
 
@@ -133,13 +992,259 @@ impl Default for Params<ZqI64<3515337053_i64>> {
             k: 3,
             l: 1,
             kappa: 36,
+            norm: NormKind::default(),
+            d: DEFAULT_D,
+        }
+    }
+}
+
+impl Params<ZqI64<3515337053_i64>> {
+    /// A parameter setting for a higher security level (binding and hiding) than the default one, at the
+    /// cost of larger commitments and slower operations. It accepts a message of length 1 over the same
+    /// modulus as the default one, so that `q' = 3515337053 = 5 (mod 8)`.
+    ///
+    /// It is intended for the degree `N` of 1024 (see [Params::security_estimate]).
+    pub fn set_128bit_secure() -> Self {
+        Params {
+            q: ZqI64::from(3515337053_i64 / 2),
+            b: ZqI64::one(),
+            // Two rows in `a1` (instead of one) double the dimension of the Module-SIS problem for
+            // binding, and `k - n - l = 2` columns of `a2'` double the dimension of the Module-LWE
+            // problem for hiding.
+            n: 2,
+            k: 5,
+            l: 1,
+            // The same challenge space as the default, whose size is much larger than 2^128 for N = 1024.
+            kappa: 36,
+            norm: NormKind::default(),
+            d: DEFAULT_D,
+        }
+    }
+
+    /// A parameter setting for messages of length 4, i.e. four polynomials are committed in one commitment.
+    /// It is over the same modulus as the default one, so that `q' = 3515337053 = 5 (mod 8)`.
+    pub fn set_message_length_4() -> Self {
+        Params {
+            q: ZqI64::from(3515337053_i64 / 2),
+            b: ZqI64::one(),
+            // `n >= l` is required, and `k - n - l = 1` column of `a2'` is kept for hiding as in the
+            // default setting.
+            n: 4,
+            k: 9,
+            l: 4,
+            kappa: 36,
+            norm: NormKind::default(),
+            d: DEFAULT_D,
+        }
+    }
+
+    /// A parameter setting for faster and smaller proofs than the default one. It accepts a message of length 1
+    /// over the same modulus as the default one, so that `q' = 3515337053 = 5 (mod 8)`.
+    ///
+    /// It is intended for the degree `N` of at least 256, and is less conservative than the default one.
+    pub fn set_high_throughput() -> Self {
+        Params {
+            q: ZqI64::from(3515337053_i64 / 2),
+            b: ZqI64::one(),
+            // The smallest dimensions satisfying `k > n >= l` and `k >= n + l + 1`, so that the
+            // commitment key has the least (n + l) x k polynomials to multiply.
+            n: 1,
+            k: 3,
+            l: 1,
+            // The standard deviation `sigma = 11 * kappa * b * sqrt(k * N)` is linear in `kappa`, so a smaller
+            // `kappa` gives shorter responses and larger margins to the norm bounds. The challenge space
+            // `2^kappa * (N choose kappa)` is still larger than 2^128 for N >= 256.
+            kappa: 24,
+            norm: NormKind::default(),
+            d: DEFAULT_D,
+        }
+    }
+}
+
+impl Params<ZqI128<72057594037927909_i128>> {
+    /// A parameter setting over the 56-bit modulus `q' = 72057594037927909 = 5 (mod 8)` with the coefficient
+    /// type `ZqI128`, for a message space of about 56 bits and a larger margin of binding than the default one
+    /// (see [Params::security_estimate]). A `Default` implementation is not provided for this type, as it
+    /// would make `Params::default()` ambiguous without a type annotation.
+    ///
+    /// The coefficients are stored in `i128`, so that the modulus is not limited by `ZqI64`. The wider
+    /// coefficients come at a cost of performance: the multiplications of coefficients are in 128 bits,
+    /// and the commitments, proofs and their encodings are larger. The products of two polynomials with large
+    /// coefficients (e.g. in the proofs of product and mixed sum) also exceed the range of the `ntt` feature
+    /// and fall back to the schoolbook multiplication. Prefer the default parameters if 32-bit messages suffice.
+    ///
+    /// It is intended for the degree `N` of 1024, as the default one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{prove_open, verify_open, Params};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::set_56bit_modulus();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let x = params.prepare_value::<N>(vec![vec![1i64 << 50, -(1 << 50)]]);
+    ///
+    /// let (_, proof) = prove_open(rng, &ck, &params, x);
+    /// assert!(verify_open(&ck, &params, proof));
+    /// ```
+    pub fn set_56bit_modulus() -> Self {
+        Params {
+            q: ZqI128::from(72057594037927909_i128 / 2),
+            b: ZqI128::one(),
+            n: 1,
+            // `k - n - l = 2` columns of `a2'` (instead of one) compensate the hiding property, i.e. the
+            // Module-LWE problem, for the larger modulus.
+            k: 4,
+            l: 1,
+            kappa: 36,
+            norm: NormKind::default(),
+            d: DEFAULT_D,
         }
     }
 }
 
+/// The builder of [Params]. It starts from an existing parameter setting (the default one by
+/// [ParamsBuilder::default]) so that only the fields to be changed need to be set, and the constraints
+/// between the fields are checked once in [ParamsBuilder::build].
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::ParamsBuilder;
+///
+/// // the default parameters with a smaller challenge space and two rows in `a1`.
+/// let params = ParamsBuilder::default().kappa(24).n(2).k(4).build().unwrap();
+/// assert_eq!(params.kappa, 24);
+/// assert_eq!((params.n, params.k, params.l), (2, 4, 1));
+///
+/// // `k > n` is violated.
+/// assert!(ParamsBuilder::default().n(3).build().is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParamsBuilder<I> {
+    params: Params<I>,
+}
+
+impl<I> ParamsBuilder<I> {
+    /// Set the prime modulus `q'` divided by 2 (see [Params::q]).
+    pub fn q(mut self, q: I) -> Self {
+        self.params.q = q;
+        self
+    }
+
+    /// Set the norm bound for the honest prover's randomness (see [Params::b]).
+    pub fn b(mut self, b: I) -> Self {
+        self.params.b = b;
+        self
+    }
+
+    /// Set the height of the commitment matrix `a1` (see [Params::n]).
+    pub fn n(mut self, n: usize) -> Self {
+        self.params.n = n;
+        self
+    }
+
+    /// Set the width of the commitment matrices (see [Params::k]).
+    pub fn k(mut self, k: usize) -> Self {
+        self.params.k = k;
+        self
+    }
+
+    /// Set the dimension of the message space (see [Params::l]).
+    pub fn l(mut self, l: usize) -> Self {
+        self.params.l = l;
+        self
+    }
+
+    /// Set the maximum norm_1 of the elements in the challenge space (see [Params::kappa]).
+    pub fn kappa(mut self, kappa: usize) -> Self {
+        self.params.kappa = kappa;
+        self
+    }
+
+    /// Set the norm of the constraint checks (see [Params::norm]).
+    pub fn norm(mut self, norm: NormKind) -> Self {
+        self.params.norm = norm;
+        self
+    }
+
+    /// Set the number of irreducible factors of `X^N + 1` modulo `q'` (see [Params::d]).
+    pub fn d(mut self, d: usize) -> Self {
+        self.params.d = d;
+        self
+    }
+}
+
+impl<I> ParamsBuilder<I>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Build the parameters, checking the constraints between them.
+    ///
+    /// ## Errors
+    /// Returns the same errors as [Params::new].
+    pub fn build(self) -> Result<Params<I>, ParamsError> {
+        let Params {
+            q,
+            b,
+            n,
+            k,
+            l,
+            kappa,
+            norm,
+            d,
+        } = self.params;
+        Ok(Params {
+            norm,
+            ..Params::new_with_d(q, b, n, k, l, kappa, d)?
+        })
+    }
+}
+
+impl<I> From<Params<I>> for ParamsBuilder<I> {
+    fn from(params: Params<I>) -> Self {
+        ParamsBuilder { params }
+    }
+}
+
+impl Default for ParamsBuilder<ZqI64<3515337053_i64>> {
+    /// The builder starting from [Params::default].
+    fn default() -> Self {
+        Params::default().into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::{string::ToString, vec};
+
     use super::*;
+    use crate::{
+        challenge_space::random_polynomial_from_challenge_set_difference,
+        polynomial::{pow_mod, random_polynomial_within},
+    };
+
+    #[test]
+    fn test_try_prepare_value() {
+        let params = Params::default();
+        assert!(params.try_prepare_value::<4>(vec![vec![1, 2, 3]]).is_ok());
+        assert_eq!(
+            params.try_prepare_value::<4>(vec![vec![1], vec![2]]),
+            Err(ParamsError::WrongMessageLength {
+                expected: 1,
+                got: 2
+            })
+        );
+        assert_eq!(
+            params.try_prepare_value::<6>(vec![vec![1, 2, 3]]),
+            Err(ParamsError::NPowerOfTwo)
+        );
+    }
 
     #[test]
     fn test_standard_deviation() {
@@ -149,6 +1254,70 @@ mod tests {
         assert_eq!(sigma, 21780);
     }
 
+    #[test]
+    fn test_standard_deviation_large() {
+        let params = Params {
+            kappa: 1 << 20,
+            ..Params::default()
+        };
+        // k * deg_n overflows usize.
+        let deg_n = 1 << 62;
+        let sqrt = (3u128 << 62).sqrt();
+        assert_eq!(
+            params.standard_deviation(deg_n) as u128,
+            11 * (1 << 20) * sqrt
+        );
+
+        // 2 * sigma * sqrt(N) overflows u64.
+        const N: usize = 1 << 40;
+        let sigma = params.bound_standard_deviation::<N>() as u128;
+        assert!(2 * sigma * (1 << 20) > u64::MAX as u128);
+        let z = Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(1)])]);
+        assert!(params.check_verify_constraint(&z));
+        assert!(params.check_commit_constraint(&z));
+    }
+
+    #[test]
+    fn test_ring_mul_and_add() {
+        let params = Params::default();
+        let q = params.q.to_i64().unwrap();
+        let p = |coeffs: Vec<i64>| params.prepare_scalar::<4>(coeffs);
+
+        // 2q = q' - 1 = -1, i.e. q = -1/2 and 4 * q^2 = 1 modulo q'
+        assert_eq!(params.ring_mul(&p(vec![q]), &p(vec![2])), p(vec![-1]));
+        let sq = params.ring_mul(&p(vec![-q]), &p(vec![-q]));
+        assert_eq!(params.ring_mul(&sq, &p(vec![4])), p(vec![1]));
+        // q * X * X^3 = q * X^4 = -q
+        assert_eq!(
+            params.ring_mul(&p(vec![0, q]), &p(vec![0, 0, 0, 1])),
+            p(vec![-q])
+        );
+        // q + 1 = -q, q + q = -1
+        assert_eq!(params.ring_add(&p(vec![q]), &p(vec![1])), p(vec![-q]));
+        assert_eq!(params.ring_add(&p(vec![q, q]), &p(vec![q])), p(vec![-1, q]));
+
+        // the same as the arithmetic of the coefficient type which reduces modulo q'
+        let rng = &mut rand::rng();
+        for _ in 0..10 {
+            let a = p((0..4).map(|_| rng.random_range(-q..=q)).collect());
+            let b = p((0..4).map(|_| rng.random_range(-q..=q)).collect());
+            assert_eq!(params.ring_mul(&a, &b), a.clone() * b.clone());
+            assert_eq!(params.ring_add(&a, &b), a.clone() + b.clone());
+        }
+
+        // the coefficient type i64 does not reduce modulo q', but the ring arithmetic does
+        fn coeffs<T: ToPrimitive>(p: Polynomial<T, 4>) -> Vec<i64> {
+            p.iter().map(|c| c.to_i64().unwrap()).collect()
+        }
+        let expected = params.ring_mul(&p(vec![q, q, -q, q]), &p(vec![q, -q, q, q]));
+        let params = Params::<i64>::new(q, 1, 1, 3, 1, 36).unwrap();
+        let a = params.prepare_scalar::<4>(vec![q, q, -q, q]);
+        let b = params.prepare_scalar::<4>(vec![q, -q, q, q]);
+        assert_eq!(coeffs(params.ring_mul(&a, &b)), coeffs(expected));
+        let a = params.prepare_scalar::<4>(vec![q]);
+        assert_eq!(params.ring_add(&a, &a), params.prepare_scalar(vec![-1]));
+    }
+
     #[test]
     fn test_prepare_scalar() {
         let params = Params::default();
@@ -165,4 +1334,557 @@ mod tests {
         assert_eq!(p.len(), 1);
         assert_eq!(p[0].deg(), 3);
     }
+
+    #[test]
+    fn test_is_invertible() {
+        const N: usize = 16;
+        let params = Params::default();
+        let modulus = 2 * params.q.to_i64().unwrap() + 1;
+
+        assert!(params.is_invertible(&params.prepare_scalar::<N>(vec![1])));
+        assert!(params.is_invertible(&params.prepare_scalar::<N>(vec![0, 0, 0, -1])));
+        assert!(!params.is_invertible(&params.prepare_scalar::<N>(vec![0])));
+        assert!(!params.is_invertible(&params.prepare_scalar::<N>(vec![modulus])));
+        let rng = &mut rand::rng();
+        for _ in 0..10 {
+            let c = random_polynomial_from_challenge_set_difference::<_, N>(rng, params.kappa);
+            assert!(params.is_invertible(&c));
+        }
+
+        // X^(N/2) - s and X^(N/2) + s are zero divisors, as their product is X^N + 1 = 0
+        let s = pow_mod(2, (modulus as i128 - 1) / 4, modulus as i128) as i64;
+        let mut factor = vec![0; N / 2 + 1];
+        factor[N / 2] = 1;
+        factor[0] = -s;
+        let p1 = params.prepare_scalar::<N>(factor.clone());
+        factor[0] = s;
+        let p2 = params.prepare_scalar::<N>(factor);
+        assert!(params.ring_mul(&p1, &p2).is_zero());
+        assert!(!params.is_invertible(&p1));
+        assert!(!params.is_invertible(&p2));
+        // a non-zero multiple of a factor
+        let p3 = params.ring_mul(&p1, &params.prepare_scalar::<N>(vec![3, 1, 4, 1, 5]));
+        assert!(!p3.is_zero());
+        assert!(!params.is_invertible(&p3));
+        assert!(params.is_invertible(&params.ring_add(&p3, &params.prepare_scalar(vec![1]))));
+
+        // N = 1, i.e. the ring Z_q'
+        assert!(params.is_invertible(&params.prepare_scalar::<1>(vec![2])));
+        assert!(!params.is_invertible(&params.prepare_scalar::<1>(vec![0])));
+    }
+
+    #[test]
+    fn test_ring_inverse() {
+        const N: usize = 16;
+        let params = Params::default();
+        let one = params.prepare_scalar::<N>(vec![1]);
+
+        assert_eq!(params.ring_inverse(&one), Some(one.clone()));
+        assert_eq!(
+            params.ring_inverse(&params.prepare_scalar::<N>(vec![0])),
+            None
+        );
+        // X * (-X^(N-1)) = -X^N = 1
+        let x = params.prepare_scalar::<N>(vec![0, 1]);
+        let mut neg_x_n1 = vec![0; N];
+        neg_x_n1[N - 1] = -1;
+        assert_eq!(
+            params.ring_inverse(&x),
+            Some(params.prepare_scalar::<N>(neg_x_n1))
+        );
+
+        let rng = &mut rand::rng();
+        for _ in 0..10 {
+            let c = random_polynomial_from_challenge_set_difference::<_, N>(rng, params.kappa);
+            let c_inv = params.ring_inverse(&c).unwrap();
+            assert_eq!(params.ring_mul(&c, &c_inv), one);
+            assert_eq!(params.ring_inverse(&c_inv), Some(c));
+        }
+
+        // a zero divisor X^(N/2) - s
+        let modulus = 2 * params.q.to_i64().unwrap() + 1;
+        let s = pow_mod(2, (modulus as i128 - 1) / 4, modulus as i128) as i64;
+        let mut factor = vec![0; N / 2 + 1];
+        factor[N / 2] = 1;
+        factor[0] = -s;
+        assert_eq!(
+            params.ring_inverse(&params.prepare_scalar::<N>(factor)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_prepare_value_reduced() {
+        // the coefficient type i64 does not reduce modulo q' by itself
+        let params = Params::<i64>::new(3515337053 / 2, 1, 1, 3, 1, 36).unwrap();
+        let q = params.q as i128;
+        let value = vec![
+            q + 1,
+            -q - 1,
+            3 * (2 * q + 1) + 5,
+            i64::MAX as i128,
+            i128::MIN,
+            q,
+            -q,
+        ];
+        let x = params.prepare_value_reduced::<8>(vec![value.clone()]);
+        for (c, v) in x[0].iter().zip(value) {
+            let c = *c as i128;
+            assert!(-q <= c && c <= q);
+            assert_eq!((c - v).rem_euclid(2 * q + 1), 0);
+        }
+        assert_eq!(
+            x[0].iter().take(3).collect::<Vec<_>>(),
+            [&(-q as i64), &(q as i64), &5]
+        );
+
+        let g = params.prepare_scalar_reduced::<8>(vec![2 * q, -2 * q, 1]);
+        assert_eq!(g, params.prepare_scalar(vec![-1, 1, 1]));
+        assert_eq!(
+            params.prepare_value_reduced::<8>(vec![vec![1, 2, 3]]),
+            params.prepare_value::<8>(vec![vec![1, 2, 3]])
+        );
+    }
+
+    #[test]
+    fn test_prepare_value_crt() {
+        let params = Params::default();
+        let moduli = [7, 11];
+        // two values packed in each coefficient: (3 mod 7, 5 mod 11), (6 mod 7, 10 mod 11)
+        let value = vec![vec![vec![3, 5], vec![6, 10]]];
+        let x = params.prepare_value_crt::<4>(value.clone(), &moduli);
+        assert_eq!(x.len(), 1);
+        assert_eq!(params.recover_value_crt(&x, &moduli), value);
+    }
+
+    #[test]
+    fn test_sigma_exact() {
+        let params = Params::default();
+        let sigma_exact = params.sigma_exact::<1000>();
+        let sigma = params.standard_deviation(1000) as f64;
+        // sqrt(3 * 1000) = 54.77.. is truncated to 54 in the integer version.
+        assert!(sigma_exact > sigma);
+        assert!(sigma_exact - sigma < (11 * params.kappa) as f64);
+
+        // no drift if k * N is a perfect square.
+        assert_eq!(
+            params.sigma_exact::<1200>(),
+            params.standard_deviation(1200) as f64
+        );
+    }
+
+    #[test]
+    fn test_norm_bounds() {
+        const N: usize = 16;
+        let params = Params::default();
+        let sigma = params.bound_standard_deviation::<N>() as u128;
+        assert_eq!(params.commit_norm_bound::<N>(), 4 * sigma * 4);
+        assert_eq!(params.verify_norm_bound::<N>(), 2 * sigma * 4);
+
+        // a polynomial of a single coefficient has the 2-norm of the coefficient.
+        let at =
+            |v: u128| Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(v as i64)])]);
+        let bound = params.commit_norm_bound::<N>();
+        assert!(params.check_commit_constraint(&at(bound)));
+        assert!(!params.check_commit_constraint(&at(bound + 1)));
+        let bound = params.verify_norm_bound::<N>();
+        assert!(params.check_verify_constraint(&at(bound)));
+        assert!(!params.check_verify_constraint(&at(bound + 1)));
+
+        let params = Params {
+            norm: NormKind::Infinity,
+            ..Params::default()
+        };
+        assert_eq!(params.commit_norm_bound::<N>(), 1);
+        assert_eq!(params.verify_norm_bound::<N>(), 6 * sigma);
+    }
+
+    #[test]
+    fn test_check_norm_constant_time() {
+        const N: usize = 16;
+        let rng = &mut rand::rng();
+
+        for norm in [NormKind::Two, NormKind::Infinity] {
+            let params = Params {
+                norm,
+                ..Params::default()
+            };
+            for bound in [0, 1, 100, 1000, params.verify_norm_bound::<N>()] {
+                for _ in 0..100 {
+                    let c = rng.random_range(0..=2 * bound as i64 + 1);
+                    let r = Mat::<_, N>::new_with(params.k, 1, || {
+                        Polynomial::new(
+                            (0..rng.random_range(0..=N))
+                                .map(|_| ZqI64::from(rng.random_range(-c..=c)))
+                                .collect(),
+                        )
+                    });
+                    assert_eq!(
+                        params.check_norm_fast(&r, bound),
+                        params.check_norm_ct(&r, bound)
+                    );
+                }
+            }
+            // the norms at the bound and just above it
+            let r = |c: i64| Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(c); 4])]);
+            let bound = match norm {
+                NormKind::Two => 20, // 2-norm of [c; 4] is 2 * |c|
+                NormKind::Infinity => 10,
+            };
+            for c in [-11, -10, 10, 11] {
+                assert_eq!(
+                    params.check_norm_fast(&r(c), bound),
+                    params.check_norm_ct(&r(c), bound)
+                );
+            }
+            assert!(params.check_norm_ct(&r(10), bound));
+            assert!(!params.check_norm_ct(&r(11), bound));
+        }
+    }
+
+    #[test]
+    fn test_check_constraint_infinity() {
+        const N: usize = 16;
+        let mut params = Params {
+            norm: NormKind::Infinity,
+            ..Params::default()
+        };
+        let sigma = params.bound_standard_deviation::<N>() as i64;
+
+        // the coefficients of the commitment randomness are bounded by b = 1
+        let r = Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(1); N])]);
+        assert!(params.check_commit_constraint(&r));
+        let r = Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(-2)])]);
+        assert!(!params.check_commit_constraint(&r));
+
+        // a response of all coefficients 6 * sigma is accepted by the infinity norm, but not by the 2-norm.
+        let z = Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(6 * sigma); N])]);
+        assert!(params.check_verify_constraint(&z));
+        let z = Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(6 * sigma + 1)])]);
+        assert!(!params.check_verify_constraint(&z));
+        params.norm = NormKind::Two;
+        let z = Mat::<_, N>::from_vec(vec![Polynomial::new(vec![ZqI64::from(6 * sigma); N])]);
+        assert!(!params.check_verify_constraint(&z));
+    }
+
+    #[test]
+    fn test_new() {
+        let q = ZqI64::<3515337053>::from(3515337053_i64 / 2);
+        let b = ZqI64::one();
+        let params = Params::new(q.clone(), b.clone(), 1, 3, 1, 36).unwrap();
+        assert_eq!(
+            params.statement_bytes::<16>(),
+            Params::default().statement_bytes::<16>()
+        );
+
+        assert_eq!(
+            Params::new(q.clone(), b.clone(), 3, 3, 1, 36).unwrap_err(),
+            ParamsError::KNotGreaterThanN { k: 3, n: 3 }
+        );
+        assert_eq!(
+            Params::new(q.clone(), b.clone(), 1, 4, 2, 36).unwrap_err(),
+            ParamsError::NLessThanL { n: 1, l: 2 }
+        );
+        assert_eq!(
+            Params::new(q.clone(), b.clone(), 2, 3, 2, 36).unwrap_err(),
+            ParamsError::KLessThanNPlusL { k: 3, n: 2, l: 2 }
+        );
+        assert_eq!(
+            Params::new(ZqI64::from(1000), b, 1, 3, 1, 36).unwrap_err(),
+            ParamsError::ModulusNotCongruent {
+                modulus: 2001,
+                d: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_prepare_value_from_bytes() {
+        let rng = &mut rand::rng();
+        let params = Params::default();
+        assert_eq!(params.bytes_per_coeff(), 3);
+
+        let data = (0..200).map(|_| rng.random::<u8>()).collect::<Vec<_>>();
+        let x = params.prepare_value_from_bytes::<128>(&data).unwrap();
+        assert_eq!(x.len(), params.l);
+        assert!(x
+            .iter()
+            .flat_map(|p| p.iter())
+            .all(|c| *c >= ZqI64::zero() && *c <= params.q));
+        assert_eq!(params.decode_to_bytes(&x).unwrap(), data);
+
+        // the committed message is recovered from the opening.
+        let ck = params.generate_commitment_key::<128>(rng);
+        let (opening, c) = ck.commit(rng, x, &params);
+        assert!(c.verify(&opening, &ck, &params));
+        assert_eq!(params.decode_to_bytes(opening.x()).unwrap(), data);
+
+        // the trailing zero bytes and the empty data are kept.
+        for data in [vec![], vec![0; 10], vec![0x80, 0]] {
+            let x = params.prepare_value_from_bytes::<4>(&data).unwrap();
+            assert_eq!(params.decode_to_bytes(&x).unwrap(), data);
+        }
+
+        // 64 * 3 - 1 = 191 bytes of capacity
+        assert_eq!(
+            params
+                .prepare_value_from_bytes::<64>(&data[..191])
+                .map(|x| params.decode_to_bytes(&x).unwrap()),
+            Ok(data[..191].to_vec())
+        );
+        assert_eq!(
+            params.prepare_value_from_bytes::<64>(&data).unwrap_err(),
+            ParamsError::DataTooLong {
+                capacity: 191,
+                got: 200
+            }
+        );
+
+        assert_eq!(
+            params.decode_to_bytes::<4>(&[Polynomial::zero()]),
+            Err(DecodeError::InvalidPadding)
+        );
+        assert_eq!(
+            params.decode_to_bytes::<4>(&[Polynomial::new(vec![ZqI64::from(1 << 24)])]),
+            Err(DecodeError::CoefficientOutOfRange)
+        );
+        assert_eq!(
+            params.decode_to_bytes::<4>(&[]),
+            Err(DecodeError::DimensionMismatch {
+                expected: (1, 1),
+                got: (0, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_challenge_set_size() {
+        fn params(kappa: usize) -> Params<ZqI64<3515337053>> {
+            Params {
+                kappa,
+                ..Params::default()
+            }
+        }
+        // 2^2 * binomial(4, 2)
+        assert_eq!(params(2).challenge_set_size::<4>(), BigUint::from(24u32));
+        assert_eq!(params(0).challenge_set_size::<4>(), BigUint::one());
+        assert_eq!(params(5).challenge_set_size::<4>(), BigUint::zero());
+        assert_eq!(
+            params(36).challenge_set_size::<512>(),
+            binomial(512, 36) * BigUint::from(1u64 << 36)
+        );
+
+        // count the challenges and their differences by enumerating {-1, 0, 1}^N
+        fn enumerate(deg_n: usize, kappa: usize) -> (usize, usize) {
+            let all = (0..3usize.pow(deg_n as u32))
+                .map(|mut i| {
+                    (0..deg_n)
+                        .map(|_| {
+                            let c = (i % 3) as i8 - 1;
+                            i /= 3;
+                            c
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .filter(|c| c.iter().filter(|c| **c != 0).count() == kappa)
+                .collect::<Vec<_>>();
+            let mut differences = alloc::collections::BTreeSet::new();
+            for c in &all {
+                for cp in &all {
+                    if c != cp {
+                        differences
+                            .insert(c.iter().zip(cp).map(|(a, b)| a - b).collect::<Vec<_>>());
+                    }
+                }
+            }
+            (all.len(), differences.len())
+        }
+        for kappa in 0..=5 {
+            let (size, difference_size) = enumerate(4, kappa);
+            assert_eq!(params(kappa).challenge_set_size::<4>(), BigUint::from(size));
+            assert_eq!(
+                params(kappa).challenge_difference_set_size::<4>(),
+                BigUint::from(difference_size)
+            );
+        }
+        for kappa in [1, 3] {
+            let (size, difference_size) = enumerate(8, kappa);
+            assert_eq!(params(kappa).challenge_set_size::<8>(), BigUint::from(size));
+            assert_eq!(
+                params(kappa).challenge_difference_set_size::<8>(),
+                BigUint::from(difference_size)
+            );
+        }
+    }
+
+    #[test]
+    fn test_params_builder() {
+        let params = ParamsBuilder::from(Params::set_high_throughput())
+            .norm(NormKind::Infinity)
+            .build()
+            .unwrap();
+        assert_eq!(params.kappa, 24);
+        assert_eq!(params.norm, NormKind::Infinity);
+
+        assert_eq!(
+            ParamsBuilder::default().l(2).build().unwrap_err(),
+            ParamsError::NLessThanL { n: 1, l: 2 }
+        );
+        assert_eq!(
+            ParamsBuilder::default()
+                .q(ZqI64::from(1000))
+                .build()
+                .unwrap_err(),
+            ParamsError::ModulusNotCongruent {
+                modulus: 2001,
+                d: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_params_builder_d() {
+        // q' = 41 = 9 (mod 16) splits X^N + 1 into four factors.
+        let params = ParamsBuilder::default()
+            .q(ZqI64::from(20))
+            .d(4)
+            .build()
+            .unwrap();
+        assert_eq!(params.d, 4);
+        assert_eq!(
+            ParamsBuilder::default()
+                .q(ZqI64::from(20))
+                .build()
+                .unwrap_err(),
+            ParamsError::ModulusNotCongruent { modulus: 41, d: 2 }
+        );
+        // the default modulus is 5 (mod 8), but not 9 (mod 16).
+        assert_eq!(
+            ParamsBuilder::default().d(4).build().unwrap_err(),
+            ParamsError::ModulusNotCongruent {
+                modulus: 3515337053,
+                d: 4
+            }
+        );
+        assert_eq!(
+            ParamsBuilder::default().d(3).build().unwrap_err(),
+            ParamsError::DNotPowerOfTwo { d: 3 }
+        );
+        assert_eq!(Params::default().d, 2);
+
+        // the parameters serialized without `d` have the default one.
+        let mut json = serde_json::to_value(Params::default()).unwrap();
+        json.as_object_mut().unwrap().remove("d");
+        let params: Params<ZqI64<3515337053>> = serde_json::from_value(json).unwrap();
+        assert_eq!(params.d, 2);
+    }
+
+    #[test]
+    fn test_estimated_accept_probability() {
+        // the bound of the default parameters is far above the norm of the randomness
+        let params = Params::default();
+        assert!(params.estimated_accept_probability::<512>() > 0.999);
+        let params = Params {
+            norm: NormKind::Infinity,
+            ..Params::default()
+        };
+        assert_eq!(params.estimated_accept_probability::<512>(), 1.0);
+
+        // the bounds around the mean 2-norm sqrt(N * b(b+1)/3) = 16 of the randomness
+        const N: usize = 64;
+        let rng = &mut rand::rng();
+        let params = Params::<i64>::new(3515337053 / 2, 3, 1, 3, 1, 36).unwrap();
+        for bound in [14, 16, 18] {
+            let estimate = norm_2_accept_probability(3.0, N, params.k, bound);
+            let samples = 2000;
+            let accepted = (0..samples)
+                .filter(|_| {
+                    let r = Mat::<_, N>::new_with(params.k, 1, || {
+                        random_polynomial_within(rng, params.b)
+                    });
+                    params.check_norm_fast(&r, bound)
+                })
+                .count();
+            let empirical = accepted as f64 / samples as f64;
+            assert!(
+                (estimate - empirical).abs() < 0.05,
+                "bound {bound}: estimate {estimate}, empirical {empirical}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let params = Params::default();
+        assert_eq!(
+            params.to_string(),
+            "q: 1757668526, b: 1, n: 1, k: 3, l: 1, kappa: 36, norm: Two, A: 2x3"
+        );
+        assert_eq!(
+            params.display::<512>().to_string(),
+            "q: 1757668526, b: 1, n: 1, k: 3, l: 1, kappa: 36, norm: Two, A: 2x3, N: 512, sigma: 15520, \
+             commit bound: 1365760, verify bound: 682880"
+        );
+    }
+
+    #[test]
+    fn test_named_parameter_sets() {
+        let rng = &mut rand::rng();
+        for params in [
+            Params::default(),
+            Params::set_128bit_secure(),
+            Params::set_high_throughput(),
+            Params::set_message_length_4(),
+        ] {
+            assert_eq!((2 * params.q.to_i64().unwrap() + 1) % 8, 5);
+            assert!(params.k > params.n && params.n >= params.l);
+            assert!(params.k > params.n + params.l);
+
+            let ck = params.generate_commitment_key::<16>(rng);
+            let x = params.prepare_value((0..params.l).map(|i| vec![1, 2, i as i64]).collect());
+            let (open, com) = ck.commit(rng, x, &params);
+            assert!(com.verify(&open, &ck, &params));
+        }
+        assert!(
+            Params::set_128bit_secure().security_estimate::<1024>().bits
+                > Params::default().security_estimate::<1024>().bits
+        );
+    }
+
+    #[test]
+    fn test_security_estimate() {
+        let params = Params::default();
+        let tiny = params.security_estimate::<16>();
+        let large = params.security_estimate::<1024>();
+        assert!(!tiny.meets(80.0));
+        assert!(large.meets(100.0));
+        assert!(tiny.root_hermite_factor > large.root_hermite_factor);
+        assert!(tiny.bits < large.bits);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_estimated_ops() {
+        use crate::{mat::POLYNOMIAL_MUL_COUNT, OpenProofProver, OpenProofVerifier};
+
+        let rng = &mut rand::rng();
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<16>(rng);
+
+        POLYNOMIAL_MUL_COUNT.with(|c| c.set(0));
+        ck.commit(rng, params.prepare_value(vec![vec![1, 2]]), &params);
+        let count = POLYNOMIAL_MUL_COUNT.with(|c| c.get());
+        assert_eq!(count, params.estimated_commit_ops());
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+        POLYNOMIAL_MUL_COUNT.with(|c| c.set(0));
+        let (response_ctx, commitment) = prover.commit(rng, params.prepare_value(vec![vec![1, 2]]));
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+        let count = POLYNOMIAL_MUL_COUNT.with(|c| c.get());
+        assert_eq!(count, params.estimated_open_proof_ops());
+    }
 }
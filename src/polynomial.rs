@@ -1,11 +1,11 @@
 //! An utilities module for polynomial operations. It provides functions for generating random polynomials and calculating norms.
 
-use std::ops::{Add, Mul, Sub};
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
 
 use num::{BigInt, BigUint, FromPrimitive, One, ToPrimitive, Zero};
 use poly_ring_xnp1::{rand::CoeffsRangeInclusive, Polynomial};
 use rand::{distr::uniform::SampleUniform, RngExt};
-use rand_distr::{Distribution, Normal};
 
 /// Returns a random polynomial with coefficients in the range `[-bound, bound]`.
 ///
@@ -24,23 +24,24 @@ where
     rng.random_range(range)
 }
 
-/// Returns a random polynomial with coefficients in the normal distribution.
-pub(crate) fn random_polynomial_in_normal_distribution<I, const N: usize>(
-    rng: &mut impl RngExt,
-    mean: f64,
-    std_dev: f64,
-) -> Polynomial<I, N>
+/// Compare the polynomials in constant time w.r.t. their coefficients, which are read by their signed
+/// representatives (as the norms do) and padded with zeros to `N` coefficients.
+#[cfg(feature = "ct")]
+pub(crate) fn ct_eq_polynomial<I, const N: usize>(
+    a: &Polynomial<I, N>,
+    b: &Polynomial<I, N>,
+) -> subtle::Choice
 where
-    I: Clone + One + Zero + FromPrimitive,
+    I: ToPrimitive,
 {
-    let normal = Normal::new(mean, std_dev).unwrap();
-    let coeffs = normal
-        .sample_iter(rng)
-        .take(N)
-        .map(|x| I::from_f64(x).unwrap())
-        .collect();
+    use subtle::ConstantTimeEq;
 
-    Polynomial::new(coeffs)
+    let coeffs = |p: &Polynomial<I, N>| {
+        let mut coeffs = p.iter().map(|c| c.to_i128().unwrap()).collect::<Vec<_>>();
+        coeffs.resize(N, 0);
+        coeffs
+    };
+    coeffs(a).as_slice().ct_eq(coeffs(b).as_slice())
 }
 
 /// Returns the 1-norm of the polynomial. It is the sum of the absolute values of the coefficients.
@@ -73,7 +74,6 @@ where
 }
 
 /// Returns the infinity-norm of the polynomial. It is the maximum absolute value of the coefficients.
-#[allow(unused)]
 #[inline]
 pub(crate) fn norm_infinity<I, const N: usize>(p: &Polynomial<I, N>) -> BigUint
 where
@@ -83,11 +83,297 @@ where
         .map(|c| c.to_i128().unwrap().unsigned_abs())
         .map(BigUint::from)
         .max()
-        .unwrap()
+        .unwrap_or_default()
+}
+
+/// Returns `true` if the polynomial is invertible in `Z_modulus[X]/(X^N+1)`, for a prime `modulus = 5 (mod 8)`.
+///
+/// By Lemma 1 of the paper, `X^N + 1 = (X^(N/2) - s) * (X^(N/2) + s)` modulo `modulus`, where `s` is a square
+/// root of `-1` and both factors are irreducible. The ring is then isomorphic to the product of the two fields
+/// by the Chinese Remainder Theorem, and `p = p_lo + X^(N/2) * p_hi` is invertible iff both of its residues
+/// `p_lo + s * p_hi` and `p_lo - s * p_hi` are non-zero. For `N = 1`, the ring is `Z_modulus` itself.
+///
+/// ## Safety
+/// **modulus** must be a prime less than `2^63` and congruent to 5 modulo 8, and `N` must be a power of two.
+pub(crate) fn is_invertible_mod<I, const N: usize>(p: &Polynomial<I, N>, modulus: i128) -> bool
+where
+    I: ToPrimitive,
+{
+    let mut coeffs = p
+        .iter()
+        .map(|c| c.to_i128().unwrap().rem_euclid(modulus))
+        .collect::<Vec<_>>();
+    coeffs.resize(N, 0);
+    if N == 1 {
+        return coeffs[0] != 0;
+    }
+    // 2 is a quadratic non-residue for modulus = 5 (mod 8), so s = 2^((modulus - 1) / 4) satisfies s^2 = -1.
+    let s = pow_mod(2, (modulus - 1) / 4, modulus);
+    let (lo, hi) = coeffs.split_at(N / 2);
+    let residue = |s: i128| {
+        lo.iter()
+            .zip(hi)
+            .any(|(lo, hi)| (lo + s * hi % modulus) % modulus != 0)
+    };
+    residue(s) && residue(modulus - s)
+}
+
+/// Returns the inverse of the polynomial in `Z_modulus[X]/(X^N+1)`, with the coefficients in the balanced residue
+/// range `[-modulus/2, modulus/2]`, or `None` if the polynomial is not invertible.
+///
+/// The inverse is found by the extended Euclidean algorithm on `p` and `X^N + 1` over the field `Z_modulus`:
+/// `p` is invertible iff their greatest common divisor is a non-zero constant `g`, and then `s * p = g` for
+/// the Bezout coefficient `s`, i.e. `s / g` is the inverse. Unlike [is_invertible_mod], it does not depend on
+/// the factorization of `X^N + 1`.
+///
+/// ## Safety
+/// **modulus** must be a prime less than `2^63`.
+pub(crate) fn inverse_mod<I, const N: usize>(
+    p: &Polynomial<I, N>,
+    modulus: i128,
+) -> Option<Polynomial<I, N>>
+where
+    I: Clone + One + Zero + FromPrimitive + ToPrimitive,
+{
+    // the polynomials are the vectors of residues in [0, modulus), without trailing zeros
+    fn trim(mut v: Vec<i128>) -> Vec<i128> {
+        while v.last() == Some(&0) {
+            v.pop();
+        }
+        v
+    }
+    let inv = |c: i128| pow_mod(c, modulus - 2, modulus);
+
+    // X^N + 1
+    let mut r0 = alloc::vec![0i128; N + 1];
+    r0[0] = 1;
+    r0[N] = 1;
+    let mut r1 = trim(
+        p.iter()
+            .map(|c| c.to_i128().unwrap().rem_euclid(modulus))
+            .collect(),
+    );
+    // s0 * p = r0 and s1 * p = r1 modulo X^N + 1
+    let (mut s0, mut s1) = (Vec::new(), alloc::vec![1i128]);
+    while !r1.is_empty() {
+        // r0 = quotient * r1 + remainder, with the remainder left in r0
+        let lead_inv = inv(*r1.last().unwrap());
+        let mut quotient = alloc::vec![0i128; (r0.len() + 1).saturating_sub(r1.len())];
+        while r0.len() >= r1.len() {
+            let shift = r0.len() - r1.len();
+            let c = r0.last().unwrap() * lead_inv % modulus;
+            quotient[shift] = c;
+            r1.iter().enumerate().for_each(|(i, r)| {
+                r0[i + shift] = (r0[i + shift] - c * r % modulus).rem_euclid(modulus);
+            });
+            r0 = trim(r0);
+        }
+        // s0 - quotient * s1
+        let mut s = alloc::vec![0i128; s0.len().max(quotient.len() + s1.len())];
+        s[..s0.len()].copy_from_slice(&s0);
+        quotient.iter().enumerate().for_each(|(i, q)| {
+            s1.iter().enumerate().for_each(|(j, s1_j)| {
+                s[i + j] = (s[i + j] - q * s1_j % modulus).rem_euclid(modulus);
+            });
+        });
+        core::mem::swap(&mut r0, &mut r1);
+        s0 = core::mem::replace(&mut s1, trim(s));
+    }
+
+    // r0 is the greatest common divisor, and deg(s0) < N
+    if r0.len() != 1 {
+        return None;
+    }
+    let g_inv = inv(r0[0]);
+    Some(centered_reduce(
+        &Polynomial::new(
+            s0.into_iter()
+                .map(|c| I::from_i128(c * g_inv % modulus).unwrap())
+                .collect(),
+        ),
+        modulus,
+    ))
+}
+
+pub(crate) fn pow_mod(mut base: i128, mut exp: i128, modulus: i128) -> i128 {
+    let mut result = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Returns `true` if the 2-norm of the polynomial (rounded down as [norm_2]) is less or equal to `bound`, without
+/// an early exit. All coefficients are scanned, and the sum of squares is accumulated in `u128` with
+/// saturation. `floor(sqrt(s)) <= bound` iff `s < (bound + 1)^2`, which is exact unless both sides saturate,
+/// i.e. the 2-norm is at least `2^64`, in which case it returns `false`.
+pub(crate) fn within_norm_2_ct<I, const N: usize>(p: &Polynomial<I, N>, bound: u128) -> bool
+where
+    I: ToPrimitive,
+{
+    let sum = coefficients_ct(p)
+        .map(|c| c.saturating_mul(c))
+        .fold(0u128, u128::saturating_add);
+    let limit = bound
+        .saturating_add(1)
+        .saturating_mul(bound.saturating_add(1));
+    sum < limit
+}
+
+/// Returns `true` if the infinity-norm of the polynomial is less or equal to `bound`, without an early exit.
+/// All coefficients are scanned, and the comparisons are folded by a bitwise and.
+pub(crate) fn within_norm_infinity_ct<I, const N: usize>(p: &Polynomial<I, N>, bound: u128) -> bool
+where
+    I: ToPrimitive,
+{
+    coefficients_ct(p).fold(true, |acc, c| acc & (c <= bound))
+}
+
+/// Returns the absolute values of the coefficients of the polynomial, up to its degree. The number of
+/// iterations depends on the degree only, which is public for the randomness and the responses in practice
+/// (all `N` coefficients are non-zero with overwhelming probability).
+fn coefficients_ct<I, const N: usize>(p: &Polynomial<I, N>) -> impl Iterator<Item = u128> + '_
+where
+    I: ToPrimitive,
+{
+    p.iter().map(|c| c.to_i128().unwrap().unsigned_abs())
+}
+
+/// Returns the image of the polynomial under the automorphism `X -> X^(-1)` of `R_q = Z_q[X]/(X^N+1)`, i.e.
+/// `p(X^(-1))`. Since `X^(-1) = -X^(N-1)`, the term `p_i * X^i` is mapped to `-p_i * X^(N-i)` for `0 < i < N`.
+///
+/// The automorphism fixes the constant polynomials. For `d = 2` (see `Params::d`), it swaps the two
+/// factors `X^(N/2) - s` and `X^(N/2) + s` of `X^N + 1` modulo `q'`, where `s^2 = -1 (mod q')`.
+pub(crate) fn conjugate<I, const N: usize>(p: &Polynomial<I, N>) -> Polynomial<I, N>
+where
+    I: Clone + Zero,
+    for<'a> &'a I: Sub<Output = I>,
+{
+    let zero = I::zero();
+    let mut coeffs = alloc::vec![zero.clone(); N];
+    p.iter().enumerate().for_each(|(i, c)| match i {
+        0 => coeffs[0] = c.clone(),
+        _ => coeffs[N - i] = &zero - c,
+    });
+    Polynomial::new(coeffs)
+}
+
+/// Returns the polynomial with coefficients reduced into the centered range `[-(modulus-1)/2, (modulus-1)/2]`.
+/// The residues of the coefficients modulo `modulus` are unchanged.
+///
+/// ## Safety
+/// **modulus** must be positive and odd.
+pub(crate) fn centered_reduce<I, const N: usize>(
+    p: &Polynomial<I, N>,
+    modulus: i128,
+) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero + FromPrimitive + ToPrimitive,
+{
+    let half = modulus / 2;
+    let coeffs = p
+        .iter()
+        .map(|c| {
+            let c = c.to_i128().unwrap().rem_euclid(modulus);
+            let c = if c > half { c - modulus } else { c };
+            I::from_i128(c).unwrap()
+        })
+        .collect();
+    Polynomial::new(coeffs)
+}
+
+/// Returns the product of the polynomials in `Z_modulus[X]/(X^N+1)`, with the coefficients in the balanced
+/// residue range `[-modulus/2, modulus/2]`. The product is accumulated in `i128` and reduced at every step,
+/// so it does not depend on the overflow behavior of the coefficient type.
+///
+/// ## Safety
+/// **modulus** must be positive, odd and less than `2^63`.
+pub(crate) fn ring_mul_reduce<I, const N: usize>(
+    a: &Polynomial<I, N>,
+    b: &Polynomial<I, N>,
+    modulus: i128,
+) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero + FromPrimitive + ToPrimitive,
+{
+    let residues = |p: &Polynomial<I, N>| {
+        p.iter()
+            .map(|c| c.to_i128().unwrap().rem_euclid(modulus))
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (residues(a), residues(b));
+    let mut coeffs = alloc::vec![0i128; N];
+    for (i, a_i) in a.iter().enumerate() {
+        for (j, b_j) in b.iter().enumerate() {
+            let c = a_i * b_j % modulus;
+            // X^N = -1
+            let (k, c) = if i + j < N {
+                (i + j, c)
+            } else {
+                (i + j - N, -c)
+            };
+            coeffs[k] = (coeffs[k] + c) % modulus;
+        }
+    }
+    centered_reduce(
+        &Polynomial::new(
+            coeffs
+                .into_iter()
+                .map(|c| I::from_i128(c).unwrap())
+                .collect(),
+        ),
+        modulus,
+    )
+}
+
+/// Returns the sum of the polynomials in `Z_modulus[X]/(X^N+1)`, with the coefficients in the balanced
+/// residue range `[-modulus/2, modulus/2]`.
+///
+/// ## Safety
+/// **modulus** must be positive, odd and less than `2^126`.
+pub(crate) fn ring_add_reduce<I, const N: usize>(
+    a: &Polynomial<I, N>,
+    b: &Polynomial<I, N>,
+    modulus: i128,
+) -> Polynomial<I, N>
+where
+    I: Clone + One + Zero + FromPrimitive + ToPrimitive,
+{
+    let coeff = |p: &Polynomial<I, N>, i: usize| {
+        p.iter()
+            .nth(i)
+            .map_or(0, |c| c.to_i128().unwrap().rem_euclid(modulus))
+    };
+    let coeffs = (0..N)
+        .map(|i| I::from_i128((coeff(a, i) + coeff(b, i)) % modulus).unwrap())
+        .collect();
+    centered_reduce(&Polynomial::new(coeffs), modulus)
+}
+
+/// Returns the canonical byte encoding of the polynomial. It is the number of coefficients (8 bytes),
+/// followed by the coefficients (16 bytes each), all in little-endian form.
+pub(crate) fn polynomial_to_bytes<I, const N: usize>(p: &Polynomial<I, N>) -> Vec<u8>
+where
+    I: ToPrimitive,
+{
+    let coeffs = p.iter().collect::<Vec<_>>();
+    let mut bytes = Vec::with_capacity(8 + 16 * coeffs.len());
+    bytes.extend((coeffs.len() as u64).to_le_bytes());
+    coeffs
+        .into_iter()
+        .for_each(|c| bytes.extend(c.to_i128().unwrap().to_le_bytes()));
+    bytes
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+
     use super::*;
 
     const N: usize = 4;
@@ -102,6 +388,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conjugate() {
+        let p = Polynomial::<i32, N>::new(vec![1, 2, 3, 4]);
+        // X^(-1) = -X^3, X^(-2) = -X^2, X^(-3) = -X
+        assert_eq!(
+            conjugate(&p),
+            Polynomial::<i32, N>::new(vec![1, -4, -3, -2])
+        );
+        assert_eq!(conjugate(&conjugate(&p)), p);
+        // p(X^(-1)) * q(X^(-1)) = (p * q)(X^(-1))
+        let q = Polynomial::<i32, N>::new(vec![0, 1, -1]);
+        assert_eq!(
+            conjugate(&p) * conjugate(&q),
+            conjugate(&(p.clone() * q.clone()))
+        );
+        assert_eq!(
+            conjugate(&Polynomial::<i32, N>::new(vec![5])),
+            Polynomial::new(vec![5])
+        );
+    }
+
     #[test]
     fn test_norm_1() {
         let p = Polynomial::<i32, N>::new(vec![1, -2, 3, -4]);
@@ -121,13 +428,20 @@ mod tests {
     }
 
     #[test]
-    fn test_random_polynomial_in_normal_distribution() {
-        let mut rng = rand::rng();
-        let mean = 0.0;
-        let std_dev = 10.0;
-        let p = random_polynomial_in_normal_distribution::<i64, N>(&mut rng, mean, std_dev);
-        p.iter().for_each(|c| {
-            assert!(c.abs() <= 3 * std_dev as i64); // 99.7% of the data
-        });
+    fn test_centered_reduce() {
+        let p = Polynomial::<i64, N>::new(vec![7, -7, 3, 12]);
+        let reduced = centered_reduce(&p, 7);
+        assert_eq!(reduced, Polynomial::<i64, N>::new(vec![0, 0, 3, -2]));
+        assert_eq!(centered_reduce(&reduced, 7), reduced);
+    }
+
+    #[test]
+    fn test_polynomial_to_bytes() {
+        let p = Polynomial::<i32, N>::new(vec![1, -2]);
+        let bytes = polynomial_to_bytes(&p);
+        assert_eq!(bytes.len(), 8 + 2 * 16);
+        assert_eq!(bytes[..8], 2u64.to_le_bytes());
+        assert_eq!(bytes[8..24], 1i128.to_le_bytes());
+        assert_eq!(bytes[24..], (-2i128).to_le_bytes());
     }
 }
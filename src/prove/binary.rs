@@ -0,0 +1,263 @@
+//! Implementation of Proof of Binary Value.
+//!
+//! It is **not** defined in the paper. It proves the knowledge of the opening of a commitment to `x` such that
+//! every coefficient of `x` is 0 or 1 (for each of the `l` polynomials of `x`), i.e. `x` is a committed bit
+//! vector of length `N`, without revealing it. It is the relation `x o (x - 1) = 0` on the coefficient
+//! vectors, where `o` is the coefficient-wise product, e.g. for the selections in a voting application.
+//!
+//! The coefficient-wise relation is not a relation in the ring `R_q = Z_q[X]/(X^N+1)`, e.g. `X * (X - 1) =
+//! X^2 - X` is not zero. The prover therefore commits to the coefficients `b_0, b_1, .., b_(N-1)` of `x` as
+//! constant polynomials, and proves that
+//! - `x = X^0 * b_0 + X^1 * b_1 + .. + X^(N-1) * b_(N-1)`, which is linear in the committed values, and
+//! - each `b_i` is a constant 0 or 1, by the relations `b_i * (b_i - 1) = 0` and `sigma(b_i) = b_i` where
+//!   `sigma` is the automorphism `X -> X^(-1)` of `R_q`.
+//!
+//! It is the proof of range ([crate::prove::range]) with the weights `X^i` in place of `2^i`, and the proofs
+//! share the implementation. See the proof of range for how the relations on each bit are proven.
+//!
+//! This modules contains struct [BinaryProofProver] and [BinaryProofVerifier] for proving and verifying
+//! opening of the commitment ([BinaryProofCommitment]) to a binary value `x`.
+//! The prover and verifier will exchange messages [BinaryProofChallenge] and [BinaryProofResponse] to
+//! complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [BinaryProofResponseContext] which is created and used by prover in the
+//! protocol. The verifier generates the challenge and verifies the response by using the context
+//! [BinaryProofVerificationContext].
+//!
+//! ## Constraints
+//! - The proof contains the commitments to the `N` coefficients and to the "garbage" of each of them, so its
+//!   size and the cost of proving and verifying grow linearly in `N` (`2N + 1` commitments and responses).
+//!   A small `N` is preferred, e.g. committing a long bit vector in several slots (`l > 1`) of a smaller `N`.
+//! - The splitting parameter `d` defined in the `Params` struct must be 2, otherwise the verifier rejects.
+//! - The soundness relies on the differences of the challenges being invertible in `R_q`, and the challenges
+//!   are drawn from a subset of Challenge Space C as in the proof of range.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{BinaryProofProver, BinaryProofVerifier, Params};
+//!
+//! const N: usize = 16;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 0, 1, 1, 0, 1]]);
+//! let c = ck.commit(rng, x, &params);
+//!
+//! let prover = BinaryProofProver::new(ck.clone(), params.clone());
+//! let verifier = BinaryProofVerifier::new(ck.clone(), params.clone());
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving that the committed value is binary.
+//! let (response_ctx, commitment) = prover.commit(rng, c).unwrap();
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::{One, Zero};
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+
+use crate::{
+    commit::{Commitment, CommitmentKey, Opening},
+    error::RingZkError,
+    integer::RingInteger,
+    params::Params,
+    prove::bits,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of binary value. It is used to prove that the prover knows the
+/// opening of the commitment to `x` such that every coefficient of `x` is 0 or 1.
+pub struct BinaryProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> BinaryProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> BinaryProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> BinaryProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create commitments to the coefficients of `x` for proving that each of them is 0 or 1, given the
+    /// commitment `c` to `x` and its opening.
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the openings
+    /// of commitments to `x` and its coefficients.
+    ///
+    /// ## Errors
+    /// Returns [RingZkError::InvalidWitness] if any coefficient of `x` is not 0 or 1.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        (opening, c): (Opening<I, N>, Commitment<I, N>),
+    ) -> Result<
+        (
+            BinaryProofResponseContext<I, N>,
+            BinaryProofCommitment<I, N>,
+        ),
+        RingZkError,
+    > {
+        if !opening
+            .x
+            .iter()
+            .flat_map(|x_j| x_j.iter())
+            .all(|c| c.is_zero() || c.is_one())
+        {
+            return Err(RingZkError::InvalidWitness);
+        }
+
+        // b_i = i-th coefficient of x, for each slot
+        let bs = (0..N)
+            .map(|i| {
+                opening
+                    .x
+                    .iter()
+                    .map(|x_j| {
+                        Polynomial::from_coeffs(vec![x_j
+                            .iter()
+                            .nth(i)
+                            .cloned()
+                            .unwrap_or_else(I::zero)])
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Ok(bits::commit(
+            rng,
+            &self.ck,
+            &self.params,
+            &self.sampler,
+            (opening, c),
+            bs,
+            &monomials(),
+        ))
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: BinaryProofResponseContext<I, N>,
+        challenge: BinaryProofChallenge<I, N>,
+    ) -> BinaryProofResponse<I, N> {
+        bits::create_response(context, challenge)
+    }
+}
+
+/// The verifier for the proof of binary value. It is used to verify that the prover knows the
+/// opening of the commitment to `x` such that every coefficient of `x` is 0 or 1.
+pub struct BinaryProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+}
+
+impl<I, const N: usize> BinaryProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        BinaryProofVerifier { params, ck }
+    }
+
+    /// Generate the challenge for the prover, given the commitments that says the prover knows its
+    /// opening to the commitment to `x` such that every coefficient of `x` is 0 or 1.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: BinaryProofCommitment<I, N>,
+    ) -> (
+        BinaryProofVerificationContext<I, N>,
+        BinaryProofChallenge<I, N>,
+    ) {
+        bits::generate_challenge(rng, &self.params, commitment)
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: BinaryProofResponse<I, N>,
+        context: BinaryProofVerificationContext<I, N>,
+    ) -> bool {
+        bits::verify(&self.ck, &self.params, &monomials(), response, context)
+    }
+}
+
+/// Returns the monomials `X^0, X^1, .., X^(N-1)`, which are the weights of the coefficients.
+fn monomials<I, const N: usize>() -> Vec<Polynomial<I, N>>
+where
+    I: Clone + One + Zero,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    (0..N)
+        .map(|i| {
+            let mut coeffs = vec![I::zero(); i + 1];
+            coeffs[i] = I::one();
+            Polynomial::from_coeffs(coeffs)
+        })
+        .collect()
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of binary value. It contains the openings of commitments
+/// to `x` and its coefficients.
+pub type BinaryProofResponseContext<I, const N: usize> = bits::BitsProofResponseContext<I, N>;
+
+/// Contains the commitments to the binary value `x` and its coefficients, used in the proof of binary value.
+/// The commitments to the coefficients (field `cbs`) start from the constant term.
+pub type BinaryProofCommitment<I, const N: usize> = bits::BitsProofCommitment<I, N>;
+
+/// Contains the context for the verification phase of the proof of binary value.
+/// It is used to verify the response from the prover.
+pub type BinaryProofVerificationContext<I, const N: usize> =
+    bits::BitsProofVerificationContext<I, N>;
+
+/// The challenge created by the verifier in the protocol of proof of binary value.
+pub type BinaryProofChallenge<I, const N: usize> = bits::BitsProofChallenge<I, N>;
+
+/// The response from the prover to the verifier in the protocol of proof of binary value.
+pub type BinaryProofResponse<I, const N: usize> = bits::BitsProofResponse<I, N>;
@@ -0,0 +1,416 @@
+//! The proof of the bits of a committed value, shared by the proof of range ([crate::prove::range]) and
+//! the proof of binary value ([crate::prove::binary]).
+//!
+//! The prover commits to the bits `b_0, b_1, .., b_(B-1)` (each a vector of `l` polynomials) of the
+//! committed value `x`, and proves that
+//! - `x = w_0 * b_0 + w_1 * b_1 + .. + w_(B-1) * b_(B-1)` for the public weights `w_i`, which is linear in the
+//!   committed values,
+//! - `b_i * (b_i - 1) = 0` for each bit, which is quadratic in the committed values, and
+//! - `sigma(b_i) = b_i` for each bit, where `sigma` is the automorphism `X -> X^(-1)` of `R_q`.
+//!
+//! The last two relations hold iff every polynomial of `b_i` is a constant 0 or 1 (see [crate::prove::range]
+//! for the details). The weights are `2^i` in the proof of range and `X^i` in the proof of binary value.
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::Zero;
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge_space::random_invariant_polynomial_from_challenge_set,
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    polynomial::conjugate,
+    sampler::GaussianSampler,
+};
+
+/// Create the commitments to the bits `bs` of `x`, given the commitment `c` to `x` and its opening. It does
+/// not check that `x` is the sum of the bits multiplied by the `weights`, or that the bits are 0 or 1.
+pub(crate) fn commit<I, const N: usize, S>(
+    rng: &mut impl RngExt,
+    ck: &CommitmentKey<I, N>,
+    params: &Params<I>,
+    sampler: &S,
+    (opening, c): (Opening<I, N>, Commitment<I, N>),
+    bs: Vec<Vec<Polynomial<I, N>>>,
+    weights: &[Polynomial<I, N>],
+) -> (BitsProofResponseContext<I, N>, BitsProofCommitment<I, N>)
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    let bits = bs.len();
+    let b_mats = bs.iter().cloned().map(Mat::from_vec).collect::<Vec<_>>();
+    let (openings_b, cbs) = bs
+        .into_iter()
+        .map(|b| ck.commit(rng, b, params))
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    // masks for x and b_i
+    let y = mask(rng, params, sampler);
+    let ybs = (0..bits)
+        .map(|_| mask(rng, params, sampler))
+        .collect::<Vec<_>>();
+
+    // a = A2 * y for each mask
+    let a = ck.a2.dot(&y);
+    let a_bs = ybs.iter().map(|y| ck.a2.dot(y)).collect::<Vec<_>>();
+
+    // u = a - sum of w_i * a_b_i
+    let u = a_bs
+        .iter()
+        .zip(weights)
+        .fold(a, |acc, (a_b, w)| acc.sub(&a_b.scalar_mul(w)));
+
+    // garbage g_i = a_b_i - 2 * a_b_i * b_i, which is the coefficient of d in f_b_i * (f_b_i + d)
+    let two = Polynomial::from_coeffs(vec![I::from_u8(2).unwrap()]);
+    let (openings_g, cgs) = a_bs
+        .iter()
+        .zip(b_mats.iter())
+        .map(|(a_b, b)| {
+            let g = a_b.sub(&a_b.hadamard(b).scalar_mul(&two));
+            ck.commit(rng, g.one_d_mat_to_vec(), params)
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let ygs = (0..bits)
+        .map(|_| mask(rng, params, sampler))
+        .collect::<Vec<_>>();
+
+    // v_i = a_b_i * a_b_i + A2 * yg_i
+    let vs = a_bs
+        .iter()
+        .zip(ygs.iter())
+        .map(|(a_b, yg)| a_b.hadamard(a_b).add(&ck.a2.dot(yg)))
+        .collect();
+
+    // w_i = a_b_i - sigma(a_b_i)
+    let ws = a_bs
+        .iter()
+        .map(|a_b| a_b.sub(&conjugate_mat(a_b)))
+        .collect();
+
+    // t = A1 * y for each mask
+    let t = ck.a1.dot(&y).one_d_mat_to_vec();
+    let tbs = ybs
+        .iter()
+        .map(|y| ck.a1.dot(y).one_d_mat_to_vec())
+        .collect();
+    let tgs = ygs
+        .iter()
+        .map(|y| ck.a1.dot(y).one_d_mat_to_vec())
+        .collect();
+
+    (
+        BitsProofResponseContext {
+            opening,
+            openings_b,
+            openings_g,
+            y,
+            ybs,
+            ygs,
+        },
+        BitsProofCommitment {
+            c,
+            cbs,
+            cgs,
+            t,
+            tbs,
+            tgs,
+            u,
+            vs,
+            ws,
+        },
+    )
+}
+
+/// Create the response for the challenge received from the verifier.
+pub(crate) fn create_response<I, const N: usize>(
+    context: BitsProofResponseContext<I, N>,
+    challenge: BitsProofChallenge<I, N>,
+) -> BitsProofResponse<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    // z = y + d * r for each mask
+    let respond = |ys: &[Mat<I, N>], openings: &[Opening<I, N>]| {
+        ys.iter()
+            .zip(openings.iter())
+            .map(|(y, opening)| y.add(&opening.r.scalar_mul(&challenge.d)))
+            .collect()
+    };
+    BitsProofResponse {
+        z: context.y.add(&context.opening.r.scalar_mul(&challenge.d)),
+        zbs: respond(&context.ybs, &context.openings_b),
+        zgs: respond(&context.ygs, &context.openings_g),
+    }
+}
+
+/// Generate the challenge, which is fixed by the automorphism `X -> X^(-1)`.
+pub(crate) fn generate_challenge<I, const N: usize>(
+    rng: &mut impl RngExt,
+    params: &Params<I>,
+    commitment: BitsProofCommitment<I, N>,
+) -> (BitsProofVerificationContext<I, N>, BitsProofChallenge<I, N>)
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    let d = random_invariant_polynomial_from_challenge_set(rng, params.kappa);
+    (
+        BitsProofVerificationContext {
+            commitment,
+            d: d.clone(),
+        },
+        BitsProofChallenge { d },
+    )
+}
+
+/// Verify the response, for the bits of `x` multiplied by the `weights`. It returns `true` if the response
+/// is valid, otherwise `false`.
+pub(crate) fn verify<I, const N: usize>(
+    ck: &CommitmentKey<I, N>,
+    params: &Params<I>,
+    weights: &[Polynomial<I, N>],
+    response: BitsProofResponse<I, N>,
+    context: BitsProofVerificationContext<I, N>,
+) -> bool
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    let BitsProofVerificationContext { commitment, d } = context;
+    let Params { n, k, l, .. } = *params;
+    let bits = weights.len();
+    // check lengths
+    if params.d != 2
+        || commitment.cbs.len() != bits
+        || commitment.cgs.len() != bits
+        || commitment.tbs.len() != bits
+        || commitment.tgs.len() != bits
+        || commitment.vs.len() != bits
+        || commitment.ws.len() != bits
+        || response.zbs.len() != bits
+        || response.zgs.len() != bits
+        || commitment.u.dim() != (l, 1)
+        || commitment.vs.iter().any(|v| v.dim() != (l, 1))
+        || commitment.ws.iter().any(|w| w.dim() != (l, 1))
+    {
+        return false;
+    }
+
+    let zs = core::iter::once(&response.z)
+        .chain(response.zbs.iter())
+        .chain(response.zgs.iter())
+        .collect::<Vec<_>>();
+    if !zs
+        .iter()
+        .all(|z| z.dim() == (k, 1) && params.check_verify_constraint(z))
+    {
+        return false;
+    }
+    let cs = core::iter::once(&commitment.c)
+        .chain(commitment.cbs.iter())
+        .chain(commitment.cgs.iter())
+        .collect::<Vec<_>>();
+    let ts = core::iter::once(&commitment.t)
+        .chain(commitment.tbs.iter())
+        .chain(commitment.tgs.iter())
+        .collect::<Vec<_>>();
+    if cs.iter().any(|c| c.c.dim() != (n + l, 1)) || ts.iter().any(|t| t.len() != n) {
+        return false;
+    }
+    let cs = cs.into_iter().map(|c| c.c1_c2(params)).collect::<Vec<_>>();
+
+    // A1 * z = t + c1 * d for each commitment
+    if !zs.iter().zip(cs.iter()).zip(ts).all(|((z, (c1, _)), t)| {
+        ck.a1.dot(z) == Mat::<I, N>::from_vec(t.clone()).add(&c1.scalar_mul(&d))
+    }) {
+        return false;
+    }
+
+    // f = A2 * z - c2 * d for each commitment
+    let fs = zs
+        .iter()
+        .zip(cs.iter())
+        .map(|(z, (_, c2))| ck.a2.dot(z).sub(&c2.scalar_mul(&d)))
+        .collect::<Vec<_>>();
+    let (f, fs) = fs.split_first().unwrap();
+    let (f_bs, f_gs) = fs.split_at(bits);
+
+    // f - sum of w_i * f_b_i = u
+    let lhs = f_bs
+        .iter()
+        .zip(weights)
+        .fold(f.clone(), |acc, (f_b, w)| acc.sub(&f_b.scalar_mul(w)));
+    if lhs != commitment.u {
+        return false;
+    }
+
+    // f_b_i * (f_b_i + d) + f_g_i = v_i
+    let ds = Mat::<I, N>::from_element(l, 1, d);
+    if !f_bs
+        .iter()
+        .zip(f_gs.iter())
+        .zip(commitment.vs.iter())
+        .all(|((f_b, f_g), v)| &f_b.hadamard(&f_b.add(&ds)).add(f_g) == v)
+    {
+        return false;
+    }
+
+    // f_b_i - sigma(f_b_i) = w_i
+    f_bs.iter()
+        .zip(commitment.ws.iter())
+        .all(|(f_b, w)| &f_b.sub(&conjugate_mat(f_b)) == w)
+}
+
+/// Sample a masking polynomial vector y <- N^k_sigma.
+fn mask<I, const N: usize, S>(rng: &mut impl RngExt, params: &Params<I>, sampler: &S) -> Mat<I, N>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    Mat::<I, N>::new_with(params.k, 1, || {
+        sampler.sample_polynomial::<I, N>(rng, params.sigma_exact::<N>())
+    })
+}
+
+/// Returns the column vector of the images of the polynomials under the automorphism `X -> X^(-1)`.
+fn conjugate_mat<I, const N: usize>(m: &Mat<I, N>) -> Mat<I, N>
+where
+    I: Clone + Zero,
+    for<'a> &'a I: Sub<Output = I>,
+{
+    Mat::from_vec(m.iter().map(conjugate).collect())
+}
+
+/// The context created by the prover in the commitment phase. It contains the openings of the commitments
+/// to `x` and its bits.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BitsProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment to `x`.
+    pub opening: Opening<I, N>,
+    /// The openings of the commitments to the bits of `x`.
+    pub openings_b: Vec<Opening<I, N>>,
+    openings_g: Vec<Opening<I, N>>, // openings of the garbage commitments
+    y: Mat<I, N>,                   // k x 1 matrix
+    ybs: Vec<Mat<I, N>>,            // vector of k x 1 matrices
+    ygs: Vec<Mat<I, N>>,            // vector of k x 1 matrices
+}
+
+/// Contains the commitments to the value `x` and its bits.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BitsProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x`.
+    pub c: Commitment<I, N>,
+    /// Commitments to the bits of `x`, in the order of their weights (e.g. starting from the least
+    /// significant bit in the proof of range).
+    pub cbs: Vec<Commitment<I, N>>,
+    cgs: Vec<Commitment<I, N>>,      // commitments to the garbage
+    t: Vec<Polynomial<I, N>>,        // n x 1 matrix
+    tbs: Vec<Vec<Polynomial<I, N>>>, // vector of n x 1 matrices
+    tgs: Vec<Vec<Polynomial<I, N>>>, // vector of n x 1 matrices
+    u: Mat<I, N>,                    // l x 1 matrix
+    vs: Vec<Mat<I, N>>,              // vector of l x 1 matrices
+    ws: Vec<Mat<I, N>>,              // vector of l x 1 matrices
+}
+
+/// Contains the context for the verification phase. It is used to verify the response from the prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BitsProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    commitment: BitsProofCommitment<I, N>,
+    d: Polynomial<I, N>,
+}
+
+/// The challenge created by the verifier.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BitsProofChallenge<I, const N: usize>
+where
+    I: Zero,
+{
+    d: Polynomial<I, N>,
+}
+
+/// The response from the prover to the verifier.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BitsProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    z: Mat<I, N>,        // k x 1 matrix
+    zbs: Vec<Mat<I, N>>, // vector of k x 1 matrices
+    zgs: Vec<Mat<I, N>>, // vector of k x 1 matrices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polynomial::pow_mod, sampler::DiscreteGaussianSampler};
+    use num::{One, ToPrimitive};
+
+    const N: usize = 16;
+
+    /// Returns the idempotent `e = 1/2 + (2 * s)^(-1) * X^(N/2)` of the Chinese Remainder Theorem modulo `q'`,
+    /// where `s^2 = -1 (mod q')`. It is 1 modulo one factor of `X^N + 1` and 0 modulo the other.
+    fn idempotent<I: RingInteger>(modulus: i128) -> Polynomial<I, N> {
+        // 2 is a quadratic non-residue for modulus = 5 (mod 8)
+        let s = pow_mod(2, (modulus - 1) / 4, modulus);
+        let inverse = |a: i128| pow_mod(a, modulus - 2, modulus);
+        let mut coeffs = vec![I::zero(); N];
+        coeffs[0] = I::from_i128(inverse(2)).unwrap();
+        coeffs[N / 2] = I::from_i128(inverse(2 * s % modulus)).unwrap();
+        Polynomial::new(coeffs)
+    }
+
+    #[test]
+    fn test_idempotent_bit() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let modulus = 2 * params.q.to_i128().unwrap() + 1;
+        let ck = params.generate_commitment_key::<N>(rng);
+
+        // e * (e - 1) = 0, but e is not a constant: sigma(e) = 1 - e
+        let e = idempotent(modulus);
+        let one = Polynomial::one();
+        assert!((e.clone() * (e.clone() - one.clone())).is_zero());
+        assert_eq!(conjugate(&e), one.clone() - e.clone());
+
+        // x = w_0 * e + w_1 * 0, with the bits satisfying b_i * (b_i - 1) = 0, for the weights of the
+        // proofs of range and binary value
+        let x = Polynomial::<_, N>::from_coeffs(vec![Zero::zero(), One::one()]);
+        let two = one.clone() + one.clone();
+        for weights in [vec![one.clone(), two], vec![one.clone(), x]] {
+            let (opening, c) = ck.commit(rng, vec![e.clone()], &params);
+            let bs = vec![vec![e.clone()], vec![Polynomial::zero()]];
+            let (response_ctx, commitment) = commit(
+                rng,
+                &ck,
+                &params,
+                &DiscreteGaussianSampler,
+                (opening, c),
+                bs,
+                &weights,
+            );
+            let (verification_ctx, challenge) = generate_challenge(rng, &params, commitment);
+            let response = create_response(response_ctx, challenge);
+            assert!(!verify(&ck, &params, &weights, response, verification_ctx));
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Implementation of Proof of Equality.
+//!
+//! It is **not** defined in the paper. It proves the knowledge of openings of commitments `c_a` and `c_b`
+//! to the same value `x`, without revealing `x`. It is the Proof of Linear Relation (section 4.4 of the
+//! paper) with `g = 1`, where the relation on the second part of the commitments becomes
+//! `c2_a - c2_b = a2 * (r_a - r_b)`.
+//!
+//! This modules contains struct [EqualityProofProver] and [EqualityProofVerifier] for proving and verifying
+//! opening of commitments ([EqualityProofCommitment]) to `x_a` and `x_b` such that `x_a = x_b`.
+//! The prover and verifier will exchange messages [EqualityProofChallenge] and [EqualityProofResponse] to
+//! complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [EqualityProofResponseContext] which is created and used by prover in the
+//! protocol. The verifier generates the challenge and verifies the response by using the context
+//! [EqualityProofVerificationContext].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{Params, EqualityProofProver, EqualityProofVerifier};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//!
+//! // two independently produced commitments to the same value.
+//! let (opening_a, c_a) = ck.commit(rng, x.clone(), &params);
+//! let (opening_b, c_b) = ck.commit(rng, x, &params);
+//!
+//! let prover = EqualityProofProver::new(ck.clone(), params.clone());
+//! let verifier = EqualityProofVerifier::new(ck.clone(), params.clone());
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving the equality of the committed values.
+//! let (response_ctx, commitment) = prover.commit(rng, (opening_a, c_a), (opening_b, c_b));
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::Zero;
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge_space::random_polynomial_from_challenge_set,
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of equality. It is used to prove that the prover knows the
+/// openings of commitments to `x_a` and `x_b` such that `x_a = x_b`.
+pub struct EqualityProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> EqualityProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> EqualityProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> EqualityProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create the commitment for proving that the commitments `c_a` and `c_b` open to the same value,
+    /// given their openings.
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the openings
+    /// of commitments `c_a` and `c_b`.
+    ///
+    /// ## Panics
+    /// Panics if the openings do not contain the same value.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        (opening_a, c_a): (Opening<I, N>, Commitment<I, N>),
+        (opening_b, c_b): (Opening<I, N>, Commitment<I, N>),
+    ) -> (
+        EqualityProofResponseContext<I, N>,
+        EqualityProofCommitment<I, N>,
+    ) {
+        assert!(opening_a.x == opening_b.x);
+
+        // ya <- N^k_sigma
+        let ya = Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        });
+
+        // yb <- N^k_sigma
+        let yb = Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        });
+
+        // ta = A1 * ya
+        let ta = self.ck.a1.dot(&ya).one_d_mat_to_vec();
+
+        // tb = A1 * yb
+        let tb = self.ck.a1.dot(&yb).one_d_mat_to_vec();
+
+        // u = A2 * ya - A2 * yb
+        let u = self.ck.a2.dot(&ya).sub(&self.ck.a2.dot(&yb));
+
+        (
+            EqualityProofResponseContext {
+                opening_a,
+                opening_b,
+                ya,
+                yb,
+            },
+            EqualityProofCommitment {
+                c_a,
+                c_b,
+                ta,
+                tb,
+                u,
+            },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: EqualityProofResponseContext<I, N>,
+        challenge: EqualityProofChallenge<I, N>,
+    ) -> EqualityProofResponse<I, N> {
+        // za = ya + d * ra
+        let za = context
+            .ya
+            .add(&context.opening_a.r.scalar_mul(&challenge.d));
+        // zb = yb + d * rb
+        let zb = context
+            .yb
+            .add(&context.opening_b.r.scalar_mul(&challenge.d));
+        EqualityProofResponse { za, zb }
+    }
+}
+
+/// The verifier for the proof of equality. It is used to verify that the prover knows the
+/// openings of commitments to `x_a` and `x_b` such that `x_a = x_b`.
+pub struct EqualityProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+}
+
+impl<I, const N: usize> EqualityProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        EqualityProofVerifier { params, ck }
+    }
+
+    /// Generate the challenge for the prover, given the commitments that says the prover knows its
+    /// openings to the commitments to values `x_a` and `x_b` such that `x_a = x_b`.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: EqualityProofCommitment<I, N>,
+    ) -> (
+        EqualityProofVerificationContext<I, N>,
+        EqualityProofChallenge<I, N>,
+    ) {
+        let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        let (c1a, c2a) = commitment.c_a.c1_c2(&self.params);
+        let (c1b, c2b) = commitment.c_b.c1_c2(&self.params);
+        (
+            EqualityProofVerificationContext {
+                c1a,
+                c2a,
+                c1b,
+                c2b,
+                ta: commitment.ta,
+                tb: commitment.tb,
+                u: commitment.u,
+                d: d.clone(),
+            },
+            EqualityProofChallenge { d },
+        )
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: EqualityProofResponse<I, N>,
+        context: EqualityProofVerificationContext<I, N>,
+    ) -> bool {
+        if !self.params.check_verify_constraint(&response.za) {
+            return false;
+        }
+        if !self.params.check_verify_constraint(&response.zb) {
+            return false;
+        }
+        // A1 * za = ta + c1a * d
+        let lhs = self.ck.a1.dot(&response.za);
+        let rhs = Mat::<I, N>::from_vec(context.ta).add(&context.c1a.scalar_mul(&context.d));
+        if lhs != rhs {
+            return false;
+        }
+        // A1 * zb = tb + c1b * d
+        let lhs = self.ck.a1.dot(&response.zb);
+        let rhs = Mat::<I, N>::from_vec(context.tb).add(&context.c1b.scalar_mul(&context.d));
+        if lhs != rhs {
+            return false;
+        }
+        // A2 * za - A2 * zb = (c2a - c2b) * d + u
+        let lhs = self
+            .ck
+            .a2
+            .dot(&response.za)
+            .sub(&self.ck.a2.dot(&response.zb));
+        let rhs = context
+            .c2a
+            .sub(&context.c2b)
+            .scalar_mul(&context.d)
+            .add(&context.u);
+        lhs == rhs
+    }
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of equality. It contains the openings of commitments
+/// to `x_a` and `x_b` such that `x_a = x_b`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EqualityProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment `c_a`.
+    pub opening_a: Opening<I, N>,
+    /// The opening of the commitment `c_b`.
+    pub opening_b: Opening<I, N>,
+    ya: Mat<I, N>, // k x 1 matrix
+    yb: Mat<I, N>, // k x 1 matrix
+}
+
+impl<I, const N: usize> EqualityProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `ya` (`k x 1` matrix) for the commitment to `x_a`, s.t. the
+    /// response is `z_a = y_a + d * r_a`. It must be kept secret as the opening.
+    pub fn ya(&self) -> &Mat<I, N> {
+        &self.ya
+    }
+
+    /// The masking polynomials `yb` (`k x 1` matrix) for the commitment to `x_b`, s.t. the
+    /// response is `z_b = y_b + d * r_b`. It must be kept secret as the opening.
+    pub fn yb(&self) -> &Mat<I, N> {
+        &self.yb
+    }
+}
+
+/// Contains the commitments to the values `x_a` and `x_b` such that `x_a = x_b`, used in
+/// the proof of equality.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EqualityProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x_a` s.t. `x_a = x_b`.
+    pub c_a: Commitment<I, N>,
+    /// Commitment to value `x_b` s.t. `x_a = x_b`.
+    pub c_b: Commitment<I, N>,
+    ta: Vec<Polynomial<I, N>>, // n x 1 matrix
+    tb: Vec<Polynomial<I, N>>, // n x 1 matrix
+    u: Mat<I, N>,              // l x 1 matrix
+}
+
+/// Contains the context for the verification phase of the proof of equality.
+/// It is used to verify the response from the prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EqualityProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    c1a: Mat<I, N>, // n x 1 matrix
+    c2a: Mat<I, N>, // l x 1 matrix
+
+    c1b: Mat<I, N>, // n x 1 matrix
+    c2b: Mat<I, N>, // l x 1 matrix
+
+    ta: Vec<Polynomial<I, N>>, // n x 1 matrix
+    tb: Vec<Polynomial<I, N>>, // n x 1 matrix
+    u: Mat<I, N>,              // l x 1 matrix
+    d: Polynomial<I, N>,
+}
+
+/// The challenge created by the verifier in the protocol of proof of equality.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EqualityProofChallenge<I, const N: usize>
+where
+    I: Zero,
+{
+    d: Polynomial<I, N>,
+}
+
+/// The response from the prover to the verifier in the protocol of proof of equality.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EqualityProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    za: Mat<I, N>, // k x 1 matrix
+    zb: Mat<I, N>, // k x 1 matrix
+}
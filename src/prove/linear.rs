@@ -1,7 +1,8 @@
 //! Implementation of Proof of Linear Relation, defined in section 4.4 of the paper.
 //!
 //! This modules contains struct [LinearProofProver] and [LinearProofVerifier] for proving and verifying
-//! opening of commitments ([LinearProofCommitment]) to `x'` and `x` such that `x' = g * x` for scalar `g`.
+//! opening of commitments ([LinearProofCommitment]) to `x'` and `x` such that `x' = g * x` for scalar `g`,
+//! or the affine relation `x' = g * x + h` for a public constant polynomial `h` ([LinearProofProver::commit_affine]).
 //! The prover and verifier will exchange messages [LinearProofChallenge] and [LinearProofResponse] to
 //! complete the 3-phase Sigma Protocol.
 //! The opening is encapsulated in [LinearProofResponseContext] which is created and used by prover in the
@@ -36,47 +37,150 @@
 //! // - Verifier verifies the response.
 //! assert!(verifier.verify(response, verification_ctx));
 //! ```
+//!
+//! ## Non-interactive Proof
+//!
+//! As the proof of opening, the protocol can be made non-interactive by the Fiat-Shamir transform, where
+//! the challenge is derived by hashing the commitment [LinearProofCommitment]. The functions [prove_linear]
+//! and [verify_linear] run all the phases in one call.
+//!
+//! ```rust
+//! use ring_zk::{prove_linear, verify_linear, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//! let g = params.prepare_scalar::<N>(vec![5, 6]);
+//!
+//! let proof = prove_linear(rng, &ck, &params, g, x);
+//! assert!(verify_linear(&ck, &params, proof));
+//! ```
+//!
+//! ## Opening and Linear Relation in One Run
+//!
+//! The response `z = y + d * r` satisfies the equation `A1 * z = t + c1 * d` of the proof of opening the
+//! commitment to `x` ([crate::prove::open]), and it is checked by [LinearProofVerifier::verify] together with
+//! the linear relation under the same challenge `d`. So one run of the protocol proves both the opening of `x`
+//! and `x' = g * x`, without a second run of the proof of opening. The challenge is generated only after the
+//! prover has sent `t`, `t'` and `u` in [LinearProofCommitment] (and the Fiat-Shamir challenge is derived by
+//! hashing all of them), so sharing it does not affect the soundness.
+//!
+//! The parts of the proof of opening can be taken by [LinearProofVerificationContext::open_proof_context] and
+//! [LinearProofResponse::open_proof_response], e.g. to hand them to the code expecting the proof of opening.
+//!
+//! ```rust
+//! use ring_zk::{LinearProofProver, LinearProofVerifier, OpenProofVerifier, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//! let g = params.prepare_scalar::<N>(vec![5, 6]);
+//!
+//! let prover = LinearProofProver::new(ck.clone(), params.clone());
+//! let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+//!
+//! let (response_ctx, commitment) = prover.commit(rng, g, x);
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! let response = prover.create_response(response_ctx, challenge);
+//!
+//! // the same response proves the opening of the commitment to `x` under the shared challenge.
+//! let open_verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+//! assert!(open_verifier.verify(
+//!     response.open_proof_response(),
+//!     verification_ctx.open_proof_context()
+//! ));
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+//!
+//! ## Combining Relations on the Same `x`
+//!
+//! The relations `x'_a = g_a * x` and `x'_b = g_b * x` on the same committed `x` can be proven in one run as
+//! `x'_a + x'_b = (g_a + g_b) * x`. The prover starts the second relation by [LinearProofProver::commit_with_shared_x],
+//! the verifier combines the commitments by [LinearProofCommitment::combine], and the prover combines the
+//! responses to the single challenge by [LinearProofResponse::combine].
 
-use std::ops::{Add, Mul, Neg, Sub};
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
 
-use num::{FromPrimitive, One, ToPrimitive, Zero};
+use num::{One, Zero};
 use poly_ring_xnp1::Polynomial;
-use rand::RngExt;
-use rand_distr::uniform::SampleUniform;
+use rand::{rngs::ChaCha20Rng, RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     challenge_space::random_polynomial_from_challenge_set,
     commit::{Commitment, CommitmentKey, Opening},
+    error::VerifyError,
+    integer::RingInteger,
     mat::Mat,
     params::Params,
-    polynomial::random_polynomial_in_normal_distribution,
+    polynomial::polynomial_to_bytes,
+    prove::open::{OpenProofResponse, OpenProofVerificationContext},
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+    transcript::{mat_to_bytes, polynomials_to_bytes, Transcript},
 };
 
+/// The domain separation label for deriving the challenge in the non-interactive proof.
+const FIAT_SHAMIR_LABEL: &[u8] = b"ring-zk/linear-proof";
+
 /// The prover for the proof of linear relation. It is used to prove that the prover knows the
 /// openings of commitments to `x'` and `x` such that `x' = g * x` for scalar `g`.
-pub struct LinearProofProver<I, const N: usize>
+pub struct LinearProofProver<I, const N: usize, S = DiscreteGaussianSampler>
 where
     I: Zero,
 {
     params: Params<I>,
     ck: CommitmentKey<I, N>,
+    sampler: S,
 }
 
 impl<I, const N: usize> LinearProofProver<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
-    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+    I: Zero,
 {
     pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
-        Self { params, ck }
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
     }
+}
 
+impl<I, const N: usize, S> LinearProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> LinearProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
     /// Create commitments to `x'` and `x` such that `x' = g * x` for scalar `g`.
     /// It returns the response context and the commitment. The response context is used to create
     /// the response in a later phase of the protocol. Note that the context includes the openings
     /// of commitments to `x'` and `x`.
     ///
+    /// ## Safety
+    /// `rng` must be a cryptographically secure random number generator (e.g. `rand::rng()`), as the
+    /// randomness of the commitments and the masking polynomials hide the committed values. See [crate::rng].
+    ///
     /// ## Panics
     /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
     pub fn commit(
@@ -95,23 +199,142 @@ where
             .collect::<Vec<_>>(); // g * x
         let (opening_p, cp) = self.ck.commit(rng, gx, &self.params);
         let (opening, c) = self.ck.commit(rng, x, &self.params);
+        self.commit_with_openings(rng, g, (opening, c), (opening_p, cp))
+    }
+
+    /// Create commitments to `x'` and `x` such that `x' = g * x` as [LinearProofProver::commit] does, with the
+    /// randomness derived from the 32-byte `seed`.
+    ///
+    /// The randomness is drawn from [ChaCha20Rng] seeded by `seed`, so the same seed and inputs always produce
+    /// the same commitments, including `t`, `tp` and `u`,. It is intended for reproducible test vectors, e.g. golden-file tests of the
+    /// transcript. The seed must be secret and never reused for other statements, as the masking polynomials
+    /// derived from it hide the opening in the response.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit_with_seed(
+        &self,
+        seed: [u8; 32],
+        g: Polynomial<I, N>,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (
+        LinearProofResponseContext<I, N>,
+        LinearProofCommitment<I, N>,
+    ) {
+        self.commit(&mut ChaCha20Rng::from_seed(seed), g, x)
+    }
+
+    /// Create commitments to `x'` and `x` such that `x' = g * x` as [LinearProofProver::commit] does, with the
+    /// randomness from [crate::rng::secure_rng], i.e. a CSPRNG seeded by the operating system.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct, or the
+    /// random number generator of the operating system fails.
+    #[cfg(feature = "os-rng")]
+    pub fn commit_secure(
+        &self,
+        g: Polynomial<I, N>,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (
+        LinearProofResponseContext<I, N>,
+        LinearProofCommitment<I, N>,
+    ) {
+        self.commit(&mut crate::rng::secure_rng(), g, x)
+    }
+
+    /// Create commitments to `x'` and `x` such that `x' = g * x + h` for scalar `g` and a public constant
+    /// polynomial `h`, which is added to every polynomial of `g * x`. It is the same as [LinearProofProver::commit]
+    /// except for the offset `h`, which is carried in the commitment and checked by the verifier.
+    ///
+    /// The offset only shifts the committed value `x'`, so the randomness of the commitments and the responses,
+    /// and hence their norm bounds, are the same as those of the linear relation. Note that `g * x + h` is
+    /// computed in the ring, i.e. its coefficients wrap around the modulus `q'` instead of growing.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{Params, LinearProofProver, LinearProofVerifier};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    /// let g = params.prepare_scalar::<N>(vec![5, 6]);
+    /// let h = params.prepare_scalar::<N>(vec![7, 8, 9]);
+    ///
+    /// let prover = LinearProofProver::new(ck.clone(), params.clone());
+    /// let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    ///
+    /// let (response_ctx, commitment) = prover.commit_affine(rng, g, h, x);
+    /// let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    /// let response = prover.create_response(response_ctx, challenge);
+    /// assert!(verifier.verify(response, verification_ctx));
+    /// ```
+    pub fn commit_affine(
+        &self,
+        rng: &mut impl RngExt,
+        g: Polynomial<I, N>,
+        h: Polynomial<I, N>,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (
+        LinearProofResponseContext<I, N>,
+        LinearProofCommitment<I, N>,
+    ) {
+        let gxh = x
+            .iter()
+            .cloned()
+            .map(|xi| xi.mul(g.clone()) + h.clone())
+            .collect::<Vec<_>>(); // g * x + h
+        let (opening_p, cp) = self.ck.commit(rng, gxh, &self.params);
+        let (opening, c) = self.ck.commit(rng, x, &self.params);
+        let (context, commitment) =
+            self.commit_with_openings(rng, g, (opening, c), (opening_p, cp));
+        (context, LinearProofCommitment { h, ..commitment })
+    }
 
+    /// Create the commitment for the proof with the existing commitments `c` to `x` and `c'` to `x' = g * x`,
+    /// together with their openings.
+    pub(crate) fn commit_with_openings(
+        &self,
+        rng: &mut impl RngExt,
+        g: Polynomial<I, N>,
+        (opening, c): (Opening<I, N>, Commitment<I, N>),
+        (opening_p, cp): (Opening<I, N>, Commitment<I, N>),
+    ) -> (
+        LinearProofResponseContext<I, N>,
+        LinearProofCommitment<I, N>,
+    ) {
         // y <- N^k_sigma
         let y = Mat::<I, N>::new_with(self.params.k, 1, || {
-            random_polynomial_in_normal_distribution::<I, N>(
-                rng,
-                I::zero().to_f64().unwrap(),
-                self.params.standard_deviation(N) as f64,
-            )
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
         });
+        self.commit_with_masking(rng, g, (opening, c), (opening_p, cp), y)
+    }
 
+    /// Create the commitment for the proof as [LinearProofProver::commit_with_openings] does, with the given
+    /// masking polynomials `y` for the commitment to `x`.
+    fn commit_with_masking(
+        &self,
+        rng: &mut impl RngExt,
+        g: Polynomial<I, N>,
+        (opening, c): (Opening<I, N>, Commitment<I, N>),
+        (opening_p, cp): (Opening<I, N>, Commitment<I, N>),
+        y: Mat<I, N>,
+    ) -> (
+        LinearProofResponseContext<I, N>,
+        LinearProofCommitment<I, N>,
+    ) {
         // yp <- N^k_sigma
         let yp = Mat::<I, N>::new_with(self.params.k, 1, || {
-            random_polynomial_in_normal_distribution::<I, N>(
-                rng,
-                I::zero().to_f64().unwrap(),
-                self.params.standard_deviation(N) as f64,
-            )
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
         });
 
         // t = A1 * y
@@ -121,12 +344,7 @@ where
         let tp = self.ck.a1.dot(&yp).one_d_mat_to_vec();
 
         // u = g * A2 * y - A2 * yp
-        let u = self
-            .ck
-            .a2
-            .dot(&y)
-            .componentwise_mul(&g)
-            .sub(&self.ck.a2.dot(&yp));
+        let u = self.ck.a2.dot(&y).scalar_mul(&g).sub(&self.ck.a2.dot(&yp));
 
         (
             LinearProofResponseContext {
@@ -135,7 +353,84 @@ where
                 y,
                 yp,
             },
-            LinearProofCommitment { c, cp, g, t, tp, u },
+            LinearProofCommitment {
+                c,
+                cp,
+                g,
+                h: Polynomial::zero(),
+                t,
+                tp,
+                u,
+            },
+        )
+    }
+
+    /// Create commitments for another relation `x'' = g * x` on the same `x` as the protocol started by
+    /// [LinearProofProver::commit] (which returned `context` and `commitment`), so that the two can be combined
+    /// into the relation `x' + x'' = (g_a + g_b) * x` by [LinearProofCommitment::combine]. The commitment `c` to
+    /// `x` and the masking polynomials `y` are taken from `context`, and only `x''` is committed to anew.
+    ///
+    /// As `y` is shared, the two protocols must be answered with the same challenge, i.e. the one for the
+    /// combined commitment. Answering them with different challenges `d_a` and `d_b` reveals
+    /// `(d_a - d_b) * r` from the responses `z`.
+    ///
+    /// ## Panics
+    /// Panics if `context` is not created together with `commitment`.
+    pub fn commit_with_shared_x(
+        &self,
+        rng: &mut impl RngExt,
+        g: Polynomial<I, N>,
+        context: &LinearProofResponseContext<I, N>,
+        commitment: &LinearProofCommitment<I, N>,
+    ) -> (
+        LinearProofResponseContext<I, N>,
+        LinearProofCommitment<I, N>,
+    ) {
+        assert!(
+            self.ck.a1.dot(&context.y).one_d_mat_to_vec() == commitment.t,
+            "the context does not belong to the commitment"
+        );
+        let gx = context
+            .opening
+            .x
+            .iter()
+            .cloned()
+            .map(|xi| xi.mul(g.clone()))
+            .collect::<Vec<_>>(); // g * x
+        let (opening_p, cp) = self.ck.commit(rng, gx, &self.params);
+        self.commit_with_masking(
+            rng,
+            g,
+            (context.opening.clone(), commitment.c.clone()),
+            (opening_p, cp),
+            context.y.clone(),
+        )
+    }
+
+    /// Create a non-interactive proof of the linear relation `x' = g * x` by the Fiat-Shamir transform.
+    /// The challenge is derived by hashing the commitment, so it is deterministic given the commitment.
+    /// It returns the openings of the commitments to `x` and `x'`, and the proof.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn prove_non_interactive(
+        &self,
+        rng: &mut impl RngExt,
+        g: Polynomial<I, N>,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (Opening<I, N>, Opening<I, N>, LinearProof<I, N>) {
+        let (context, commitment) = self.commit(rng, g, x);
+        let d = commitment.fiat_shamir_challenge(&self.params);
+        let (opening, opening_p) = (context.opening.clone(), context.opening_p.clone());
+        let response = self.create_response(context, LinearProofChallenge { d: d.clone() });
+        (
+            opening,
+            opening_p,
+            LinearProof {
+                commitment,
+                d,
+                response,
+            },
         )
     }
 
@@ -147,13 +442,11 @@ where
         challenge: LinearProofChallenge<I, N>,
     ) -> LinearProofResponse<I, N> {
         // z = y + d * r
-        let z = context
-            .y
-            .add(&context.opening.r.componentwise_mul(&challenge.d));
+        let z = context.y.add(&context.opening.r.scalar_mul(&challenge.d));
         // zp = yp + d * rp
         let zp = context
             .yp
-            .add(&context.opening_p.r.componentwise_mul(&challenge.d));
+            .add(&context.opening_p.r.scalar_mul(&challenge.d));
         LinearProofResponse { z, zp }
     }
 }
@@ -170,7 +463,7 @@ where
 
 impl<I, const N: usize> LinearProofVerifier<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
+    I: RingInteger,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
 {
     pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
@@ -190,6 +483,19 @@ where
         LinearProofChallenge<I, N>,
     ) {
         let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        self.challenge_with(commitment, d)
+    }
+
+    /// Create the verification context and the challenge for the given challenge polynomial `d`, e.g.
+    /// the one derived by the Fiat-Shamir transform.
+    pub(crate) fn challenge_with(
+        &self,
+        commitment: LinearProofCommitment<I, N>,
+        d: Polynomial<I, N>,
+    ) -> (
+        LinearProofVerificationContext<I, N>,
+        LinearProofChallenge<I, N>,
+    ) {
         let (c1, c2) = commitment.c.c1_c2(&self.params);
         let (c1p, c2p) = commitment.cp.c1_c2(&self.params);
         (
@@ -199,6 +505,7 @@ where
                 c1p,
                 c2p,
                 g: commitment.g,
+                h: commitment.h,
                 t: commitment.t,
                 tp: commitment.tp,
                 u: commitment.u,
@@ -215,41 +522,140 @@ where
         response: LinearProofResponse<I, N>,
         context: LinearProofVerificationContext<I, N>,
     ) -> bool {
+        self.verify_detailed(response, context).is_ok()
+    }
+
+    /// Verify the response from the prover as [LinearProofVerifier::verify] does, but tell the reason
+    /// if the response is invalid.
+    ///
+    /// ## Errors
+    /// - [VerifyError::NormConstraintViolated] if a response exceeds the norm bound.
+    /// - [VerifyError::FirstEquationMismatch] if `A1 * z = t + c1 * d` or `A1 * z' = t' + c1' * d` does not hold.
+    /// - [VerifyError::SecondEquationMismatch] if `g * A2 * z - A2 * z' = (g * c2 - c2' + h) * d + u` does not hold,
+    ///   where `h` is the offset of the affine relation (zero for the linear relation).
+    pub fn verify_detailed(
+        &self,
+        response: LinearProofResponse<I, N>,
+        context: LinearProofVerificationContext<I, N>,
+    ) -> Result<(), VerifyError> {
         if !self.params.check_verify_constraint(&response.z) {
-            return false;
+            return Err(VerifyError::NormConstraintViolated);
         }
         if !self.params.check_verify_constraint(&response.zp) {
-            return false;
+            return Err(VerifyError::NormConstraintViolated);
         }
         // A1 * z = t + c1 * d
         let lhs = self.ck.a1.dot(&response.z);
-        let rhs = Mat::<I, N>::from_vec(context.t).add(&context.c1.componentwise_mul(&context.d));
+        let rhs = Mat::<I, N>::from_vec(context.t).add(&context.c1.scalar_mul(&context.d));
         if lhs != rhs {
-            return false;
+            return Err(VerifyError::FirstEquationMismatch);
         }
         // A1 * zp = tp + c1p * d
         let lhs = self.ck.a1.dot(&response.zp);
-        let rhs = Mat::<I, N>::from_vec(context.tp).add(&context.c1p.componentwise_mul(&context.d));
+        let rhs = Mat::<I, N>::from_vec(context.tp).add(&context.c1p.scalar_mul(&context.d));
         if lhs != rhs {
-            return false;
+            return Err(VerifyError::FirstEquationMismatch);
         }
-        // g * A2 * z - A2 * zp = (g * c2 - c2p) * d + u
+        // g * A2 * z - A2 * zp = (g * c2 - c2p + h) * d + u
         let lhs = self
             .ck
             .a2
             .dot(&response.z)
-            .componentwise_mul(&context.g)
+            .scalar_mul(&context.g)
             .sub(&self.ck.a2.dot(&response.zp));
         let rhs = context
             .c2
-            .componentwise_mul(&context.g)
+            .scalar_mul(&context.g)
             .sub(&context.c2p)
-            .componentwise_mul(&context.d)
+            .add(&Mat::from_element(self.params.l, 1, context.h))
+            .scalar_mul(&context.d)
             .add(&context.u);
-        lhs == rhs
+        if lhs != rhs {
+            return Err(VerifyError::SecondEquationMismatch);
+        }
+        Ok(())
+    }
+
+    /// Verify the non-interactive proof created by [LinearProofProver::prove_non_interactive]. The challenge
+    /// is derived from the commitment in the proof in the same way as the prover does, and must be equal
+    /// to the challenge embedded in the proof. It returns `true` if the proof is valid, otherwise `false`.
+    pub fn verify_non_interactive(&self, proof: LinearProof<I, N>) -> bool {
+        let LinearProof {
+            commitment,
+            d,
+            response,
+        } = proof;
+        if commitment.fiat_shamir_challenge(&self.params) != d {
+            return false;
+        }
+        let (context, _) = self.challenge_with(commitment, d);
+        self.verify(response, context)
+    }
+
+    /// Verify the openings of the commitments to `x` and `x'`, together with the response from the prover.
+    /// It returns `true` only if
+    /// - the commitment is the one from which the context was created,
+    /// - `opening` and `opening_p` are valid openings of the commitments to `x` and `x'` respectively, and
+    /// - the response is valid as [LinearProofVerifier::verify] checks.
+    pub fn verify_full(
+        &self,
+        commitment: &LinearProofCommitment<I, N>,
+        response: LinearProofResponse<I, N>,
+        context: LinearProofVerificationContext<I, N>,
+        opening: &Opening<I, N>,
+        opening_p: &Opening<I, N>,
+    ) -> bool {
+        if commitment.c.c1_c2(&self.params) != (context.c1.clone(), context.c2.clone())
+            || commitment.cp.c1_c2(&self.params) != (context.c1p.clone(), context.c2p.clone())
+        {
+            return false;
+        }
+        if !commitment.c.verify(opening, &self.ck, &self.params)
+            || !commitment.cp.verify(opening_p, &self.ck, &self.params)
+        {
+            return false;
+        }
+        self.verify(response, context)
     }
 }
 
+/// Commit to `x` and `x' = g * x`, and create the non-interactive proof of the linear relation, as
+/// [LinearProofProver::prove_non_interactive] does. It is a convenience for running all the phases of the
+/// protocol in a single trusted call; use [LinearProofProver] and [LinearProofVerifier] for the interactive
+/// protocol.
+///
+/// ## Panics
+/// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+pub fn prove_linear<I, const N: usize>(
+    rng: &mut impl RngExt,
+    ck: &CommitmentKey<I, N>,
+    params: &Params<I>,
+    g: Polynomial<I, N>,
+    x: Vec<Polynomial<I, N>>,
+) -> LinearProof<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    let (_, _, proof) =
+        LinearProofProver::new(ck.clone(), params.clone()).prove_non_interactive(rng, g, x);
+    proof
+}
+
+/// Verify the non-interactive proof created by [prove_linear], as [LinearProofVerifier::verify_non_interactive]
+/// does. It returns `true` if the proof is valid, otherwise `false`.
+pub fn verify_linear<I, const N: usize>(
+    ck: &CommitmentKey<I, N>,
+    params: &Params<I>,
+    proof: LinearProof<I, N>,
+) -> bool
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    LinearProofVerifier::new(ck.clone(), params.clone()).verify_non_interactive(proof)
+}
+
 /// The response created by the prover upon receiving the challenge from the verifier
 /// in the protocol of proof of linear relation. It contains the openings of commitments
 /// to `x'` and `x` such that `x' = g * x` for scalar `g`.
@@ -266,6 +672,23 @@ where
     yp: Mat<I, N>, // k x 1 matrix
 }
 
+impl<I, const N: usize> LinearProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `y` (`k x 1` matrix) for the commitment to `x`, s.t. the
+    /// response is `z = y + d * r`. It must be kept secret as the opening.
+    pub fn y(&self) -> &Mat<I, N> {
+        &self.y
+    }
+
+    /// The masking polynomials `yp` (`k x 1` matrix) for the commitment to `x'`, s.t. the
+    /// response is `z' = y' + d * r'`. It must be kept secret as the opening.
+    pub fn yp(&self) -> &Mat<I, N> {
+        &self.yp
+    }
+}
+
 /// Contains the commitments to the values `x'` and `x` such that `x' = g * x`, used in
 /// the proof of linear relation.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -279,11 +702,162 @@ where
     pub cp: Commitment<I, N>,
     /// The scalar `g` in the relation `x' = g * x`.
     pub g: Polynomial<I, N>,
+    /// The public offset `h` in the affine relation `x' = g * x + h`. It is zero for the linear relation.
+    pub h: Polynomial<I, N>,
     t: Vec<Polynomial<I, N>>,  // n x 1 matrix
     tp: Vec<Polynomial<I, N>>, // n x 1 matrix
     u: Mat<I, N>,              // l x 1 matrix
 }
 
+impl<I, const N: usize> LinearProofCommitment<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Returns the canonical byte encoding of the public statement, i.e. the parameters, the commitments
+    /// `c` and `c'`, the scalar `g`, the offset `h`, `t`, `t'` and `u`, which are the bytes to be absorbed by a Fiat-Shamir
+    /// transcript for deriving the challenge. The crate absorbs them into a [Transcript] labeled
+    /// `b"ring-zk/linear-proof"` (see [LinearProofProver::prove_non_interactive]).
+    pub fn statement_bytes(&self, params: &Params<I>) -> Vec<u8> {
+        let mut bytes = params.statement_bytes::<N>();
+        bytes.extend(mat_to_bytes(&self.c.c));
+        bytes.extend(mat_to_bytes(&self.cp.c));
+        bytes.extend(polynomial_to_bytes(&self.g));
+        bytes.extend(polynomial_to_bytes(&self.h));
+        bytes.extend(polynomials_to_bytes(&self.t));
+        bytes.extend(polynomials_to_bytes(&self.tp));
+        bytes.extend(mat_to_bytes(&self.u));
+        bytes
+    }
+
+    /// Combine the commitments for the relations `x'_a = g_a * x + h_a` and `x'_b = g_b * x + h_b` on the same
+    /// `x` into the commitment for `x'_a + x'_b = (g_a + g_b) * x + (h_a + h_b)`, created by
+    /// [LinearProofProver::commit] and [LinearProofProver::commit_with_shared_x]. The commitment to `x'_a + x'_b`
+    /// is `c'_a + c'_b`, and the verifier checks the combined relation with one run of the protocol. The prover
+    /// answers the challenge with both contexts and combines the responses by [LinearProofResponse::combine].
+    ///
+    /// It returns `None` if the commitments do not share the commitment `c` to `x` and its masking commitment
+    /// `t`, in which case the verifier must reject.
+    ///
+    /// The combined masking polynomials `y'_a + y'_b` have the standard deviation `sqrt(2) * sigma`, and the
+    /// randomness `r'_a + r'_b` has twice the norm bound of the commitments, so the response `z'` is about `sqrt(2)`
+    /// times larger than in a single proof. It is still within the norm bound `2 * sigma * sqrt(N)` checked by the
+    /// verifier (see [Params::verify_norm_bound]) with high probability for a large `N` (e.g. 256), but a small `N`
+    /// or combining the combined commitment again makes the responses rejected more often.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{LinearProofProver, LinearProofVerifier, Params};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    /// let g1 = params.prepare_scalar::<N>(vec![5, 6]);
+    /// let g2 = params.prepare_scalar::<N>(vec![7]);
+    ///
+    /// let prover = LinearProofProver::new(ck.clone(), params.clone());
+    /// let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    ///
+    /// let (response_ctx_a, commitment_a) = prover.commit(rng, g1, x);
+    /// let (response_ctx_b, commitment_b) =
+    ///     prover.commit_with_shared_x(rng, g2, &response_ctx_a, &commitment_a);
+    ///
+    /// let commitment = commitment_a.combine(&commitment_b).unwrap();
+    /// let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    /// let response_a = prover.create_response(response_ctx_a, challenge.clone());
+    /// let response_b = prover.create_response(response_ctx_b, challenge);
+    /// let response = response_a.combine(&response_b).unwrap();
+    /// assert!(verifier.verify(response, verification_ctx));
+    /// ```
+    pub fn combine(
+        &self,
+        other: &LinearProofCommitment<I, N>,
+    ) -> Option<LinearProofCommitment<I, N>> {
+        if self.c != other.c || self.t != other.t {
+            return None;
+        }
+        Some(LinearProofCommitment {
+            c: self.c.clone(),
+            cp: self.cp.add(&other.cp),
+            g: self.g.clone() + other.g.clone(),
+            h: self.h.clone() + other.h.clone(),
+            t: self.t.clone(),
+            tp: self
+                .tp
+                .iter()
+                .zip(&other.tp)
+                .map(|(a, b)| a.clone() + b.clone())
+                .collect(),
+            u: self.u.add(&other.u),
+        })
+    }
+
+    /// Derive the challenge in Challenge Space C by hashing the statement, which is
+    /// the Fiat-Shamir transform of the protocol.
+    fn fiat_shamir_challenge(&self, params: &Params<I>) -> Polynomial<I, N> {
+        let mut transcript = Transcript::new(FIAT_SHAMIR_LABEL);
+        transcript.append_message(&self.statement_bytes(params));
+        transcript.challenge(params.kappa)
+    }
+}
+
+/// The non-interactive proof of linear relation, created by the Fiat-Shamir transform of the protocol.
+/// It contains the commitment, the challenge derived from the commitment, and the response.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinearProof<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The commitments to `x'` and `x` with information for proving the relation `x' = g * x`.
+    pub commitment: LinearProofCommitment<I, N>,
+    d: Polynomial<I, N>,
+    response: LinearProofResponse<I, N>,
+}
+
+impl<I, const N: usize> LinearProof<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` derived by the Fiat-Shamir transform, embedded in the proof.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<I, const N: usize> LinearProof<I, N>
+where
+    I: Zero + Serialize + serde::de::DeserializeOwned,
+{
+    /// Encode the proof by `bincode`. It is more compact than a text format such as JSON, as the coefficients
+    /// are encoded as fixed-size integers.
+    pub fn to_bincode(&self) -> Vec<u8> {
+        crate::encoding::to_bincode(self)
+    }
+
+    /// Decode the proof from the encoding returned by [LinearProof::to_bincode]. The decoded proof is not
+    /// checked; it is verified by [LinearProofVerifier::verify_non_interactive].
+    ///
+    /// ## Errors
+    /// Returns [DecodeError::Truncated](crate::DecodeError::Truncated) if the input ends early, or
+    /// [DecodeError::Malformed](crate::DecodeError::Malformed) if it is not an encoding of the proof or has
+    /// trailing bytes.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, crate::DecodeError> {
+        crate::encoding::from_bincode(bytes)
+    }
+
+    /// The size in bytes of the proof encoded by [LinearProof::to_bincode], e.g. for comparing the cost of sending
+    /// the proofs for a given `N`. The proof is not encoded for computing the size.
+    pub fn proof_size_bytes(&self) -> usize {
+        crate::encoding::bincode_size(self)
+    }
+}
+
 /// Contains the context for the verification phase of the proof of linear relation.
 /// It is used to verify the response from the prover.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -298,6 +872,7 @@ where
     c2p: Mat<I, N>, // l x 1 matrix
 
     g: Polynomial<I, N>,
+    h: Polynomial<I, N>,
 
     t: Vec<Polynomial<I, N>>,  // n x 1 matrix
     tp: Vec<Polynomial<I, N>>, // n x 1 matrix
@@ -305,6 +880,28 @@ where
     d: Polynomial<I, N>,
 }
 
+impl<I, const N: usize> LinearProofVerificationContext<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` sent to the prover, from which the context was created.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
+impl<I, const N: usize> LinearProofVerificationContext<I, N>
+where
+    I: Clone + Zero,
+{
+    /// The context of the proof of opening the commitment to `x`, which shares `c1`, `t` and the challenge `d`
+    /// with this context. It is verified with [LinearProofResponse::open_proof_response] by
+    /// [OpenProofVerifier](crate::OpenProofVerifier).
+    pub fn open_proof_context(&self) -> OpenProofVerificationContext<I, N> {
+        OpenProofVerificationContext::from_parts(self.c1.clone(), self.t.clone(), self.d.clone())
+    }
+}
+
 /// The challenge created by the verifier in the protocol of proof of linear relation.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LinearProofChallenge<I, const N: usize>
@@ -314,8 +911,18 @@ where
     d: Polynomial<I, N>,
 }
 
+impl<I, const N: usize> LinearProofChallenge<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` in Challenge Space C.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
 /// The response from the prover to the verifier in the protocol of proof of linear relation.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LinearProofResponse<I, const N: usize>
 where
     I: Zero,
@@ -323,3 +930,36 @@ where
     z: Mat<I, N>,  // k x 1 matrix
     zp: Mat<I, N>, // k x 1 matrix
 }
+
+impl<I, const N: usize> LinearProofResponse<I, N>
+where
+    I: Clone + Zero,
+{
+    /// The response `z` of the proof of opening the commitment to `x`, which is part of this response. It is
+    /// verified with [LinearProofVerificationContext::open_proof_context] by
+    /// [OpenProofVerifier](crate::OpenProofVerifier).
+    pub fn open_proof_response(&self) -> OpenProofResponse<I, N> {
+        OpenProofResponse::from_z(self.z.clone())
+    }
+}
+
+impl<I, const N: usize> LinearProofResponse<I, N>
+where
+    I: Clone + One + Zero + PartialEq,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Combine the responses of the protocols on the same `x` to the same challenge, for the combined commitment
+    /// by [LinearProofCommitment::combine]. The response `z` is shared, and `z' = z'_a + z'_b`.
+    ///
+    /// It returns `None` if the responses have different `z`, i.e. they are not created from the contexts sharing
+    /// the masking polynomials `y` or not for the same challenge.
+    pub fn combine(&self, other: &LinearProofResponse<I, N>) -> Option<LinearProofResponse<I, N>> {
+        if self.z != other.z {
+            return None;
+        }
+        Some(LinearProofResponse {
+            z: self.z.clone(),
+            zp: self.zp.add(&other.zp),
+        })
+    }
+}
@@ -0,0 +1,421 @@
+//! Implementation of Proof of Matrix Linear Relation.
+//!
+//! It is **not** defined in the paper, but it is a generalization of the Proof of Linear Relation. It proves
+//! `x' = G * x` for a public `l x l` matrix `G` of polynomials applied to the committed vector `x` (of length
+//! `l`). The proof of linear relation is the case `G = g * I_l`, and a weighted sum of the slots of `x` is a
+//! row of `G`.
+//!
+//! The protocol follows the proof of linear relation with the scalar `g` replaced by the matrix product with
+//! `G`. For the commitments `c = [c1 c2]` to `x` and `c' = [c1' c2']` to `x'`, the relation
+//! `G * c2 - c2' = G * A2 * r - A2 * r'` holds if and only if `x' = G * x`, so the verifier checks
+//! `G * A2 * z - A2 * z' = (G * c2 - c2') * d + u` for the responses `z` and `z'`.
+//!
+//! This modules contains struct [MatrixLinearProofProver] and [MatrixLinearProofVerifier] for proving and
+//! verifying opening of commitments ([MatrixLinearProofCommitment]) to `x'` and `x` such that `x' = G * x`.
+//! The prover and verifier will exchange messages [MatrixLinearProofChallenge] and [MatrixLinearProofResponse]
+//! to complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [MatrixLinearProofResponseContext] which is created and used by prover in the
+//! protocol. The verifier generates the challenge and verifies the response by using the context
+//! [MatrixLinearProofVerificationContext].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use poly_ring_xnp1::zq::ZqI64;
+//! use ring_zk::{Mat, MatrixLinearProofProver, MatrixLinearProofVerifier, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params {
+//!     n: 2,
+//!     k: 5,
+//!     l: 2,
+//!     ..Params::default()
+//! };
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]);
+//! // G = [[1, 2], [3, X]]
+//! let coeffs = |v: Vec<i64>| v.into_iter().map(ZqI64::from).collect::<Vec<_>>();
+//! let g = Mat::from_coeffs_grid(vec![
+//!     vec![coeffs(vec![1]), coeffs(vec![2])],
+//!     vec![coeffs(vec![3]), coeffs(vec![0, 1])],
+//! ]);
+//!
+//! let prover = MatrixLinearProofProver::new(ck.clone(), params.clone());
+//! let verifier = MatrixLinearProofVerifier::new(ck.clone(), params.clone());
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving the linear relationship of the committed values.
+//! let (response_ctx, commitment) = prover.commit(rng, g, x);
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::Zero;
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge_space::random_polynomial_from_challenge_set,
+    commit::{Commitment, CommitmentKey, Opening},
+    error::VerifyError,
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of matrix linear relation. It is used to prove that the prover knows the
+/// openings of commitments to `x'` and `x` such that `x' = G * x` for a public `l x l` matrix `G`.
+pub struct MatrixLinearProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> MatrixLinearProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> MatrixLinearProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> MatrixLinearProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create commitments to `x'` and `x` such that `x' = G * x` for the public matrix `G` (`g`).
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the openings
+    /// of commitments to `x'` and `x`.
+    ///
+    /// ## Panics
+    /// Panics if
+    /// - the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    /// - the dimension of `g` is not `l x l`.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        g: Mat<I, N>,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (
+        MatrixLinearProofResponseContext<I, N>,
+        MatrixLinearProofCommitment<I, N>,
+    ) {
+        let l = self.params.l;
+        assert_eq!(x.len(), l);
+        assert_eq!(g.dim(), (l, l), "G must be an l x l matrix");
+
+        // x' = G * x
+        let gx = g.dot(&Mat::from_vec(x.clone())).one_d_mat_to_vec();
+        let (opening_p, cp) = self.ck.commit(rng, gx, &self.params);
+        let (opening, c) = self.ck.commit(rng, x, &self.params);
+
+        // y, yp <- N^k_sigma
+        let y = self.mask(rng);
+        let yp = self.mask(rng);
+
+        // t = A1 * y
+        let t = self.ck.a1.dot(&y).one_d_mat_to_vec();
+
+        // tp = A1 * yp
+        let tp = self.ck.a1.dot(&yp).one_d_mat_to_vec();
+
+        // u = G * A2 * y - A2 * yp
+        let u = g.dot(&self.ck.a2.dot(&y)).sub(&self.ck.a2.dot(&yp));
+
+        (
+            MatrixLinearProofResponseContext {
+                opening,
+                opening_p,
+                y,
+                yp,
+            },
+            MatrixLinearProofCommitment { c, cp, g, t, tp, u },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: MatrixLinearProofResponseContext<I, N>,
+        challenge: MatrixLinearProofChallenge<I, N>,
+    ) -> MatrixLinearProofResponse<I, N> {
+        // z = y + d * r
+        let z = context.y.add(&context.opening.r.scalar_mul(&challenge.d));
+        // zp = yp + d * rp
+        let zp = context
+            .yp
+            .add(&context.opening_p.r.scalar_mul(&challenge.d));
+        MatrixLinearProofResponse { z, zp }
+    }
+
+    /// Sample a masking polynomial vector y <- N^k_sigma.
+    fn mask(&self, rng: &mut impl RngExt) -> Mat<I, N> {
+        Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        })
+    }
+}
+
+/// The verifier for the proof of matrix linear relation. It is used to verify that the prover knows the
+/// openings of commitments to `x'` and `x` such that `x' = G * x` for a public `l x l` matrix `G`.
+pub struct MatrixLinearProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+}
+
+impl<I, const N: usize> MatrixLinearProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        MatrixLinearProofVerifier { params, ck }
+    }
+
+    /// Generate the challenge for the prover, given the commitments that says the prover knows its
+    /// openings to the commitments to values `x'` and `x` such that `x' = G * x`.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: MatrixLinearProofCommitment<I, N>,
+    ) -> (
+        MatrixLinearProofVerificationContext<I, N>,
+        MatrixLinearProofChallenge<I, N>,
+    ) {
+        let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        let (c1, c2) = commitment.c.c1_c2(&self.params);
+        let (c1p, c2p) = commitment.cp.c1_c2(&self.params);
+        (
+            MatrixLinearProofVerificationContext {
+                c1,
+                c2,
+                c1p,
+                c2p,
+                g: commitment.g,
+                t: commitment.t,
+                tp: commitment.tp,
+                u: commitment.u,
+                d: d.clone(),
+            },
+            MatrixLinearProofChallenge { d },
+        )
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: MatrixLinearProofResponse<I, N>,
+        context: MatrixLinearProofVerificationContext<I, N>,
+    ) -> bool {
+        self.verify_detailed(response, context).is_ok()
+    }
+
+    /// Verify the response from the prover as [MatrixLinearProofVerifier::verify] does, but tell the reason
+    /// if the response is invalid.
+    ///
+    /// ## Errors
+    /// - [VerifyError::NormConstraintViolated] if a response exceeds the norm bound.
+    /// - [VerifyError::LengthMismatch] if the dimension of `G` is not `l x l` (the expected and actual
+    ///   numbers of rows are reported).
+    /// - [VerifyError::FirstEquationMismatch] if `A1 * z = t + c1 * d` or `A1 * z' = t' + c1' * d` does not hold.
+    /// - [VerifyError::SecondEquationMismatch] if `G * A2 * z - A2 * z' = (G * c2 - c2') * d + u` does not hold.
+    pub fn verify_detailed(
+        &self,
+        response: MatrixLinearProofResponse<I, N>,
+        context: MatrixLinearProofVerificationContext<I, N>,
+    ) -> Result<(), VerifyError> {
+        let l = self.params.l;
+        if context.g.dim() != (l, l) {
+            return Err(VerifyError::LengthMismatch {
+                expected: l,
+                got: context.g.dim().0,
+            });
+        }
+        if !self.params.check_verify_constraint(&response.z) {
+            return Err(VerifyError::NormConstraintViolated);
+        }
+        if !self.params.check_verify_constraint(&response.zp) {
+            return Err(VerifyError::NormConstraintViolated);
+        }
+        // A1 * z = t + c1 * d
+        let lhs = self.ck.a1.dot(&response.z);
+        let rhs = Mat::<I, N>::from_vec(context.t).add(&context.c1.scalar_mul(&context.d));
+        if lhs != rhs {
+            return Err(VerifyError::FirstEquationMismatch);
+        }
+        // A1 * zp = tp + c1p * d
+        let lhs = self.ck.a1.dot(&response.zp);
+        let rhs = Mat::<I, N>::from_vec(context.tp).add(&context.c1p.scalar_mul(&context.d));
+        if lhs != rhs {
+            return Err(VerifyError::FirstEquationMismatch);
+        }
+        // G * A2 * z - A2 * zp = (G * c2 - c2p) * d + u
+        let lhs = context
+            .g
+            .dot(&self.ck.a2.dot(&response.z))
+            .sub(&self.ck.a2.dot(&response.zp));
+        let rhs = context
+            .g
+            .dot(&context.c2)
+            .sub(&context.c2p)
+            .scalar_mul(&context.d)
+            .add(&context.u);
+        if lhs != rhs {
+            return Err(VerifyError::SecondEquationMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of matrix linear relation. It contains the openings of commitments
+/// to `x'` and `x` such that `x' = G * x`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixLinearProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment to `x` s.t. `x' = G * x`.
+    pub opening: Opening<I, N>,
+    /// The opening of the commitment to `x'` s.t. `x' = G * x`.
+    pub opening_p: Opening<I, N>,
+    y: Mat<I, N>,  // k x 1 matrix
+    yp: Mat<I, N>, // k x 1 matrix
+}
+
+impl<I, const N: usize> MatrixLinearProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `y` (`k x 1` matrix) for the commitment to `x`, s.t. the
+    /// response is `z = y + d * r`. It must be kept secret as the opening.
+    pub fn y(&self) -> &Mat<I, N> {
+        &self.y
+    }
+
+    /// The masking polynomials `yp` (`k x 1` matrix) for the commitment to `x'`, s.t. the
+    /// response is `z' = y' + d * r'`. It must be kept secret as the opening.
+    pub fn yp(&self) -> &Mat<I, N> {
+        &self.yp
+    }
+}
+
+/// Contains the commitments to the values `x'` and `x` such that `x' = G * x`, used in
+/// the proof of matrix linear relation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixLinearProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x` s.t. `x' = G * x`.
+    pub c: Commitment<I, N>,
+    /// Commitment to value `x'` s.t. `x' = G * x`.
+    pub cp: Commitment<I, N>,
+    /// The public `l x l` matrix `G` in the relation `x' = G * x`.
+    pub g: Mat<I, N>,
+    t: Vec<Polynomial<I, N>>,  // n x 1 matrix
+    tp: Vec<Polynomial<I, N>>, // n x 1 matrix
+    u: Mat<I, N>,              // l x 1 matrix
+}
+
+/// Contains the context for the verification phase of the proof of matrix linear relation.
+/// It is used to verify the response from the prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixLinearProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    c1: Mat<I, N>, // n x 1 matrix
+    c2: Mat<I, N>, // l x 1 matrix
+
+    c1p: Mat<I, N>, // n x 1 matrix
+    c2p: Mat<I, N>, // l x 1 matrix
+
+    g: Mat<I, N>, // l x l matrix
+
+    t: Vec<Polynomial<I, N>>,  // n x 1 matrix
+    tp: Vec<Polynomial<I, N>>, // n x 1 matrix
+    u: Mat<I, N>,              // l x 1 matrix
+    d: Polynomial<I, N>,
+}
+
+impl<I, const N: usize> MatrixLinearProofVerificationContext<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` sent to the prover, from which the context was created.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
+/// The challenge created by the verifier in the protocol of proof of matrix linear relation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixLinearProofChallenge<I, const N: usize>
+where
+    I: Zero,
+{
+    d: Polynomial<I, N>,
+}
+
+impl<I, const N: usize> MatrixLinearProofChallenge<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` in Challenge Space C.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
+/// The response from the prover to the verifier in the protocol of proof of matrix linear relation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixLinearProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    z: Mat<I, N>,  // k x 1 matrix
+    zp: Mat<I, N>, // k x 1 matrix
+}
@@ -1,6 +1,15 @@
 //! Contains the proof system implementations for Proof of Opening a Commitment,
-//! Proof of Linear Relation, and Proof of Sum.
+//! Proof of Linear Relation, Proof of Matrix Linear Relation, Proof of Sum, Proof of Set Membership,
+//! Proof of Offset, Proof of Equality, Proof of Range, Proof of Product, and Proof of Binary Value.
 
+pub mod binary;
+mod bits;
+pub mod equality;
 pub mod linear;
+pub mod matrix_linear;
+pub mod offset;
 pub mod open;
+pub mod product;
+pub mod range;
+pub mod set_membership;
 pub mod sum;
@@ -0,0 +1,359 @@
+//! Implementation of Proof of Offset.
+//!
+//! It is **not** defined in the paper. It proves the knowledge of openings of commitments to `x'` and `x`
+//! such that `x' = x + h` for a public polynomial vector `h`, e.g. a committed counter is incremented by a
+//! known amount. It is the additive analogue of the Proof of Linear Relation (section 4.4 of the paper),
+//! where the relation on the second part of the commitments becomes `c2' - c2 - h = a2 * (r' - r)`.
+//!
+//! This modules contains struct [OffsetProofProver] and [OffsetProofVerifier] for proving and verifying
+//! opening of commitments ([OffsetProofCommitment]) to `x'` and `x` such that `x' = x + h`.
+//! The prover and verifier will exchange messages [OffsetProofChallenge] and [OffsetProofResponse] to
+//! complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [OffsetProofResponseContext] which is created and used by prover in the
+//! protocol. The verifier generates the challenge and verifies the response by using the context
+//! [OffsetProofVerificationContext].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{Params, OffsetProofProver, OffsetProofVerifier};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//! let h = params.prepare_value::<N>(vec![vec![1]]);
+//!
+//! let prover = OffsetProofProver::new(ck.clone(), params.clone());
+//! let verifier = OffsetProofVerifier::new(ck.clone(), params.clone());
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving the offset of the committed values.
+//! let (response_ctx, commitment) = prover.commit(rng, h, x);
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::Zero;
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge_space::random_polynomial_from_challenge_set,
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of offset. It is used to prove that the prover knows the
+/// openings of commitments to `x'` and `x` such that `x' = x + h` for public `h`.
+pub struct OffsetProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> OffsetProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> OffsetProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> OffsetProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create commitments to `x' = x + h` and `x`.
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the openings
+    /// of commitments to `x'` and `x`.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` or `h` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        h: Vec<Polynomial<I, N>>,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (
+        OffsetProofResponseContext<I, N>,
+        OffsetProofCommitment<I, N>,
+    ) {
+        assert_eq!(h.len(), self.params.l);
+        let xp = x
+            .iter()
+            .zip(h.iter())
+            .map(|(xi, hi)| xi.clone() + hi.clone())
+            .collect::<Vec<_>>(); // x + h
+        let (opening_p, cp) = self.ck.commit(rng, xp, &self.params);
+        let (opening, c) = self.ck.commit(rng, x, &self.params);
+
+        // y <- N^k_sigma
+        let y = Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        });
+
+        // yp <- N^k_sigma
+        let yp = Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        });
+
+        // t = A1 * y
+        let t = self.ck.a1.dot(&y).one_d_mat_to_vec();
+
+        // tp = A1 * yp
+        let tp = self.ck.a1.dot(&yp).one_d_mat_to_vec();
+
+        // u = A2 * yp - A2 * y
+        let u = self.ck.a2.dot(&yp).sub(&self.ck.a2.dot(&y));
+
+        (
+            OffsetProofResponseContext {
+                opening,
+                opening_p,
+                y,
+                yp,
+            },
+            OffsetProofCommitment { c, cp, h, t, tp, u },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: OffsetProofResponseContext<I, N>,
+        challenge: OffsetProofChallenge<I, N>,
+    ) -> OffsetProofResponse<I, N> {
+        // z = y + d * r
+        let z = context.y.add(&context.opening.r.scalar_mul(&challenge.d));
+        // zp = yp + d * rp
+        let zp = context
+            .yp
+            .add(&context.opening_p.r.scalar_mul(&challenge.d));
+        OffsetProofResponse { z, zp }
+    }
+}
+
+/// The verifier for the proof of offset. It is used to verify that the prover knows the
+/// openings of commitments to `x'` and `x` such that `x' = x + h` for public `h`.
+pub struct OffsetProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+}
+
+impl<I, const N: usize> OffsetProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        OffsetProofVerifier { params, ck }
+    }
+
+    /// Generate the challenge for the prover, given the commitments that says the prover knows its
+    /// openings to the commitments to values `x'` and `x` such that `x' = x + h` for public `h`.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: OffsetProofCommitment<I, N>,
+    ) -> (
+        OffsetProofVerificationContext<I, N>,
+        OffsetProofChallenge<I, N>,
+    ) {
+        let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        let (c1, c2) = commitment.c.c1_c2(&self.params);
+        let (c1p, c2p) = commitment.cp.c1_c2(&self.params);
+        (
+            OffsetProofVerificationContext {
+                c1,
+                c2,
+                c1p,
+                c2p,
+                h: commitment.h,
+                t: commitment.t,
+                tp: commitment.tp,
+                u: commitment.u,
+                d: d.clone(),
+            },
+            OffsetProofChallenge { d },
+        )
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: OffsetProofResponse<I, N>,
+        context: OffsetProofVerificationContext<I, N>,
+    ) -> bool {
+        if context.h.len() != self.params.l {
+            return false;
+        }
+        if !self.params.check_verify_constraint(&response.z) {
+            return false;
+        }
+        if !self.params.check_verify_constraint(&response.zp) {
+            return false;
+        }
+        // A1 * z = t + c1 * d
+        let lhs = self.ck.a1.dot(&response.z);
+        let rhs = Mat::<I, N>::from_vec(context.t).add(&context.c1.scalar_mul(&context.d));
+        if lhs != rhs {
+            return false;
+        }
+        // A1 * zp = tp + c1p * d
+        let lhs = self.ck.a1.dot(&response.zp);
+        let rhs = Mat::<I, N>::from_vec(context.tp).add(&context.c1p.scalar_mul(&context.d));
+        if lhs != rhs {
+            return false;
+        }
+        // A2 * zp - A2 * z = (c2p - c2 - h) * d + u
+        let lhs = self
+            .ck
+            .a2
+            .dot(&response.zp)
+            .sub(&self.ck.a2.dot(&response.z));
+        let rhs = context
+            .c2p
+            .sub(&context.c2)
+            .sub(&Mat::<I, N>::from_vec(context.h))
+            .scalar_mul(&context.d)
+            .add(&context.u);
+        lhs == rhs
+    }
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of offset. It contains the openings of commitments
+/// to `x'` and `x` such that `x' = x + h`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsetProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment to `x` s.t. `x' = x + h`.
+    pub opening: Opening<I, N>,
+    /// The opening of the commitment to `x'` s.t. `x' = x + h`.
+    pub opening_p: Opening<I, N>,
+    y: Mat<I, N>,  // k x 1 matrix
+    yp: Mat<I, N>, // k x 1 matrix
+}
+
+impl<I, const N: usize> OffsetProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `y` (`k x 1` matrix) for the commitment to `x`, s.t. the
+    /// response is `z = y + d * r`. It must be kept secret as the opening.
+    pub fn y(&self) -> &Mat<I, N> {
+        &self.y
+    }
+
+    /// The masking polynomials `yp` (`k x 1` matrix) for the commitment to `x'`, s.t. the
+    /// response is `z' = y' + d * r'`. It must be kept secret as the opening.
+    pub fn yp(&self) -> &Mat<I, N> {
+        &self.yp
+    }
+}
+
+/// Contains the commitments to the values `x'` and `x` such that `x' = x + h`, used in
+/// the proof of offset.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsetProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x` s.t. `x' = x + h`.
+    pub c: Commitment<I, N>,
+    /// Commitment to value `x'` s.t. `x' = x + h`.
+    pub cp: Commitment<I, N>,
+    /// The public offset `h` in the relation `x' = x + h`.
+    pub h: Vec<Polynomial<I, N>>,
+    t: Vec<Polynomial<I, N>>,  // n x 1 matrix
+    tp: Vec<Polynomial<I, N>>, // n x 1 matrix
+    u: Mat<I, N>,              // l x 1 matrix
+}
+
+/// Contains the context for the verification phase of the proof of offset.
+/// It is used to verify the response from the prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsetProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    c1: Mat<I, N>, // n x 1 matrix
+    c2: Mat<I, N>, // l x 1 matrix
+
+    c1p: Mat<I, N>, // n x 1 matrix
+    c2p: Mat<I, N>, // l x 1 matrix
+
+    h: Vec<Polynomial<I, N>>,
+
+    t: Vec<Polynomial<I, N>>,  // n x 1 matrix
+    tp: Vec<Polynomial<I, N>>, // n x 1 matrix
+    u: Mat<I, N>,              // l x 1 matrix
+    d: Polynomial<I, N>,
+}
+
+/// The challenge created by the verifier in the protocol of proof of offset.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsetProofChallenge<I, const N: usize>
+where
+    I: Zero,
+{
+    d: Polynomial<I, N>,
+}
+
+/// The response from the prover to the verifier in the protocol of proof of offset.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsetProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    z: Mat<I, N>,  // k x 1 matrix
+    zp: Mat<I, N>, // k x 1 matrix
+}
@@ -34,47 +34,170 @@
 //! // - Verifier verifies the response.
 //! assert!(verifier.verify(response, verification_ctx));
 //! ```
+//!
+//! ## Non-interactive Proof
+//!
+//! The protocol can be made non-interactive by the Fiat-Shamir transform, where the challenge is derived
+//! by hashing the commitment [OpenProofCommitment] instead of being sampled by the verifier. The prover
+//! creates a self-contained [OpenProof] which can be verified without any interaction.
+//!
+//! ```rust
+//! use ring_zk::{Params, OpenProofProver, OpenProofVerifier};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//!
+//! let prover = OpenProofProver::new(ck.clone(), params.clone());
+//! let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+//!
+//! let (opening, proof) = prover.prove_non_interactive(rng, x);
+//! assert!(proof.commitment.c.verify(&opening, &ck, &params));
+//! assert!(verifier.verify_non_interactive(proof));
+//! ```
+//!
+//! The functions [prove_open] and [verify_open] wrap the prover and verifier for one-shot use.
+//!
+//! ```rust
+//! use ring_zk::{prove_open, verify_open, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//!
+//! let (opening, proof) = prove_open(rng, &ck, &params, x);
+//! assert!(verify_open(&ck, &params, proof));
+//! ```
+//!
+//! ## Parallel Repetitions
+//!
+//! A cheating prover succeeds in a run of the protocol with probability `1 / |C|` (see
+//! [Params::challenge_set_size]), which may be too high for a small `kappa` or `N`. The protocol can be run
+//! `t` times in parallel on the same commitment by [OpenProofProver::commit_repeated], with independent
+//! masking polynomials and challenges. The verifier accepts only if all the runs pass, so the soundness
+//! error drops to `1 / |C|^t`. The messages of all the runs are bundled in single values, e.g.
+//! [RepeatedOpenProofResponse], and [OpenProofProver::prove_non_interactive_repeated] creates a single
+//! [RepeatedOpenProof].
+//!
+//! ```rust
+//! use ring_zk::{Params, OpenProofProver, OpenProofVerifier};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//!
+//! let prover = OpenProofProver::new(ck.clone(), params.clone());
+//! let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+//!
+//! let (response_ctx, commitment) = prover.commit_repeated(rng, x, 3);
+//! let (verification_ctx, challenge) = verifier.generate_challenge_repeated(rng, commitment);
+//! let response = prover.create_response_repeated(response_ctx, challenge);
+//! assert!(verifier.verify_repeated(response, verification_ctx));
+//! ```
+//!
+//! ## Rerandomized Commitments
+//!
+//! A commitment rerandomized by [Commitment::rerandomize] has an opening with the relaxation factor `f`,
+//! s.t. `f * c = A * r + f * [0 x]`. The prover proves the opening of such an existing commitment by
+//! [OpenProofProver::commit_with_opening], and the verifier must generate the challenge by
+//! [OpenProofVerifier::generate_challenge_with_randomizer] with the same `f`, which checks
+//! `A1 * z = t + f * c1 * d`. The other methods of the verifier assume `f = 1`, so they reject the proof of
+//! a rerandomized commitment. The proofs of relations (e.g. [crate::LinearProofVerifier]) always assume
+//! `f = 1`, as the commitments in a relation would need a common factor.
+//!
+//! ## Special Soundness
+//!
+//! Two accepting transcripts with the same commitment `t` and different challenges reveal an opening of the
+//! commitment, which is what makes the protocol a proof of knowledge. [OpenProofVerifier::extract] computes it
+//! from the transcripts, e.g. for testing the protocol by rewinding the prover.
 
-use std::ops::{Add, Mul, Neg, Sub};
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
 
-use num::{FromPrimitive, One, ToPrimitive, Zero};
+use num::Zero;
 use poly_ring_xnp1::Polynomial;
-use rand::RngExt;
-use rand_distr::uniform::SampleUniform;
+use rand::{rngs::ChaCha20Rng, RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     challenge_space::random_polynomial_from_challenge_set,
     commit::{Commitment, CommitmentKey, Opening},
+    error::{RingZkError, VerifyError},
+    integer::RingInteger,
     mat::Mat,
-    params::Params,
-    polynomial::random_polynomial_in_normal_distribution,
+    params::{Params, RetryConfig},
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+    transcript::{mat_to_bytes, polynomials_to_bytes, Transcript},
 };
 
+/// The domain separation label for deriving the challenge in the non-interactive proof.
+const FIAT_SHAMIR_LABEL: &[u8] = b"ring-zk/open-proof";
+
+/// The domain separation label for deriving the challenges in the non-interactive proof with repetitions.
+const FIAT_SHAMIR_LABEL_REPEATED: &[u8] = b"ring-zk/open-proof-repeated";
+
 /// The prover for the proof of linear relation. It is used to prove that the prover knows the
 /// opening of commitment to a value.
-pub struct OpenProofProver<I, const N: usize>
+pub struct OpenProofProver<I, const N: usize, S = DiscreteGaussianSampler>
 where
     I: Zero,
 {
     params: Params<I>,
     ck: CommitmentKey<I, N>,
+    sampler: S,
 }
 
 impl<I, const N: usize> OpenProofProver<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
-    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+    I: Zero,
 {
     pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
-        Self { params, ck }
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> OpenProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
     }
+}
 
+impl<I, const N: usize, S> OpenProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
     /// Create commitments to the value `x`.
     /// It returns the response context and the commitment. The response context is used to create
     /// the response in a later phase of the protocol. Note that the context includes the openings
     /// of commitments to `x`.
     ///
+    /// ## Safety
+    /// `rng` must be a cryptographically secure random number generator (e.g. `rand::rng()`), as the
+    /// randomness of the commitments and the masking polynomials hide the committed values. See [crate::rng].
+    ///
     /// ## Panics
     /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
     pub fn commit(
@@ -83,14 +206,73 @@ where
         x: Vec<Polynomial<I, N>>,
     ) -> (OpenProofResponseContext<I, N>, OpenProofCommitment<I, N>) {
         let (opening, c) = self.ck.commit(rng, x, &self.params);
+        self.mask(rng, opening, c)
+    }
+
+    /// Create commitments to the value `x` as [OpenProofProver::commit] does, with the randomness derived from
+    /// the 32-byte `seed`.
+    ///
+    /// The randomness is drawn from [ChaCha20Rng] seeded by `seed`, so the same seed and inputs always produce
+    /// the same commitment `c` and masking commitment `t`. It is intended for reproducible test vectors, e.g. golden-file tests of the
+    /// transcript. The seed must be secret and never reused for other statements, as the masking polynomials
+    /// derived from it hide the opening in the response.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit_with_seed(
+        &self,
+        seed: [u8; 32],
+        x: Vec<Polynomial<I, N>>,
+    ) -> (OpenProofResponseContext<I, N>, OpenProofCommitment<I, N>) {
+        self.commit(&mut ChaCha20Rng::from_seed(seed), x)
+    }
 
+    /// Create commitments to the value `x` as [OpenProofProver::commit] does, with the randomness from
+    /// [crate::rng::secure_rng], i.e. a CSPRNG seeded by the operating system.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct, or the
+    /// random number generator of the operating system fails.
+    #[cfg(feature = "os-rng")]
+    pub fn commit_secure(
+        &self,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (OpenProofResponseContext<I, N>, OpenProofCommitment<I, N>) {
+        self.commit(&mut crate::rng::secure_rng(), x)
+    }
+
+    /// Create the commitment for the proof of opening the existing commitment `c`, with its opening, instead
+    /// of committing to a new value. It is used for the commitments not created by the prover itself, e.g. the
+    /// ones rerandomized by [Commitment::rerandomize]. If the opening has the relaxation factor `f`, the verifier must
+    /// be given the same `f` in [OpenProofVerifier::generate_challenge_with_randomizer].
+    ///
+    /// ## Panics
+    /// Panics if the number of rows of the randomness in `opening` is not equal to `k` defined in the `Params` struct.
+    pub fn commit_with_opening(
+        &self,
+        rng: &mut impl RngExt,
+        opening: Opening<I, N>,
+        c: Commitment<I, N>,
+    ) -> (OpenProofResponseContext<I, N>, OpenProofCommitment<I, N>) {
+        assert_eq!(
+            opening.r.dim().0,
+            self.params.k,
+            "the randomness must have k rows"
+        );
+        self.mask(rng, opening, c)
+    }
+
+    /// Sample the masking polynomials `y` for the commitment `c` with its opening.
+    fn mask(
+        &self,
+        rng: &mut impl RngExt,
+        opening: Opening<I, N>,
+        c: Commitment<I, N>,
+    ) -> (OpenProofResponseContext<I, N>, OpenProofCommitment<I, N>) {
         // y <- N^k_sigma
         let y = Mat::<I, N>::new_with(self.params.k, 1, || {
-            random_polynomial_in_normal_distribution::<I, N>(
-                rng,
-                I::zero().to_f64().unwrap(),
-                self.params.standard_deviation(N) as f64,
-            )
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
         });
 
         // t = A1 * y
@@ -102,6 +284,66 @@ where
         )
     }
 
+    /// Create a non-interactive proof of opening the commitment to the value `x`, by the Fiat-Shamir transform.
+    /// The challenge is derived by hashing the commitment, so it is deterministic given the commitment.
+    /// It returns the opening of the commitment to `x` and the proof.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn prove_non_interactive(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+    ) -> (Opening<I, N>, OpenProof<I, N>) {
+        let (context, commitment) = self.commit(rng, x);
+        let d = commitment.fiat_shamir_challenge(&self.params);
+        let opening = context.opening.clone();
+        let response = self.create_response(context, OpenProofChallenge { d: d.clone() });
+        (
+            opening,
+            OpenProof {
+                commitment,
+                d,
+                response,
+            },
+        )
+    }
+
+    /// Create a non-interactive proof as [OpenProofProver::prove_non_interactive] does, but sample the
+    /// masking polynomials again if the response exceeds the norm bound checked by the verifier, so that
+    /// the returned proof is always accepted by an honest verifier. The commitment to `x` is kept among
+    /// the attempts. It returns the opening, the proof, and the number of attempts used.
+    ///
+    /// ## Errors
+    /// Returns [RingZkError::RetryExhausted] if no valid response is created in `config.max_attempts` attempts.
+    ///
+    /// ## Panics
+    /// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn prove_with_retry(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+        config: &RetryConfig,
+    ) -> Result<(Opening<I, N>, OpenProof<I, N>, usize), RingZkError> {
+        let (opening, c) = self.ck.commit(rng, x, &self.params);
+        for attempt in 1..=config.max_attempts {
+            let (context, commitment) = self.mask(rng, opening.clone(), c.clone());
+            let d = commitment.fiat_shamir_challenge(&self.params);
+            let response = self.create_response(context, OpenProofChallenge { d: d.clone() });
+            if self.params.check_verify_constraint(&response.z) {
+                let proof = OpenProof {
+                    commitment,
+                    d,
+                    response,
+                };
+                return Ok((opening, proof, attempt));
+            }
+        }
+        Err(RingZkError::RetryExhausted {
+            attempts: config.max_attempts,
+        })
+    }
+
     /// Create the response for the challenge received from the verifier. The response is created
     /// using the context that was created during the commitment phase.
     pub fn create_response(
@@ -110,10 +352,91 @@ where
         challenge: OpenProofChallenge<I, N>,
     ) -> OpenProofResponse<I, N> {
         // z = y + d * r
-        let z = context
-            .y
-            .add(&context.opening.r.componentwise_mul(&challenge.d));
-        OpenProofResponse { z }
+        let z = context.y.add(&context.opening.r.scalar_mul(&challenge.d));
+        OpenProofResponse { z, deg_n: N }
+    }
+
+    /// Create the commitment to the value `x` for `repetitions` parallel runs of the protocol, each with its
+    /// own masking polynomials `y_j` and `t_j = A1 * y_j`. The runs share the commitment `c` to `x`, and
+    /// are answered by [OpenProofProver::create_response_repeated]. See the section "Parallel Repetitions"
+    /// of the [module](crate::prove::open) for the soundness error.
+    ///
+    /// ## Panics
+    /// Panics if `repetitions` is 0, or the length of `x` is not equal to the length of `l` defined in the
+    /// `Params` struct.
+    pub fn commit_repeated(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+        repetitions: usize,
+    ) -> (
+        RepeatedOpenProofResponseContext<I, N>,
+        RepeatedOpenProofCommitment<I, N>,
+    ) {
+        assert!(repetitions > 0, "repetitions must be positive");
+        let (opening, c) = self.ck.commit(rng, x, &self.params);
+        let (ys, ts) = (0..repetitions)
+            .map(|_| {
+                let (context, commitment) = self.mask(rng, opening.clone(), c.clone());
+                (context.y, commitment.t)
+            })
+            .unzip();
+        (
+            RepeatedOpenProofResponseContext { opening, ys },
+            RepeatedOpenProofCommitment { c, ts },
+        )
+    }
+
+    /// Create the responses `z_j = y_j + d_j * r` of all the runs, for the challenges received from the
+    /// verifier in the protocol started by [OpenProofProver::commit_repeated].
+    ///
+    /// ## Panics
+    /// Panics if the number of challenges is not equal to the number of repetitions of the context.
+    pub fn create_response_repeated(
+        &self,
+        context: RepeatedOpenProofResponseContext<I, N>,
+        challenge: RepeatedOpenProofChallenge<I, N>,
+    ) -> RepeatedOpenProofResponse<I, N> {
+        assert_eq!(
+            context.ys.len(),
+            challenge.ds.len(),
+            "one challenge per repetition is required"
+        );
+        let zs = context
+            .ys
+            .iter()
+            .zip(&challenge.ds)
+            .map(|(y, d)| y.add(&context.opening.r.scalar_mul(d)))
+            .collect();
+        RepeatedOpenProofResponse { zs }
+    }
+
+    /// Create a non-interactive proof of opening the commitment to the value `x` with `repetitions` parallel
+    /// runs, by the Fiat-Shamir transform. The challenges of all the runs are squeezed from a single
+    /// transcript after absorbing the commitment and all `t_j`. It returns the opening of the commitment to
+    /// `x` and the proof.
+    ///
+    /// ## Panics
+    /// Panics in the same cases as [OpenProofProver::commit_repeated].
+    pub fn prove_non_interactive_repeated(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+        repetitions: usize,
+    ) -> (Opening<I, N>, RepeatedOpenProof<I, N>) {
+        let (context, commitment) = self.commit_repeated(rng, x, repetitions);
+        let ds = commitment.fiat_shamir_challenges(&self.params);
+        let opening = context.opening.clone();
+        let response =
+            self.create_response_repeated(context, RepeatedOpenProofChallenge { ds: ds.clone() });
+        (
+            opening,
+            RepeatedOpenProof {
+                commitment,
+                ds,
+                response,
+            },
+        )
     }
 }
 
@@ -129,7 +452,7 @@ where
 
 impl<I, const N: usize> OpenProofVerifier<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
+    I: RingInteger,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
 {
     pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
@@ -157,6 +480,69 @@ where
         )
     }
 
+    /// Generate the challenge as [OpenProofVerifier::generate_challenge] does, for the commitment whose opening
+    /// has the relaxation factor `f` ([Opening::f]), i.e. `f * c = A * r + f * [0 x]` (see [Commitment::rerandomize]). Then
+    /// `f * c1 = A1 * r`, so the response is verified by `A1 * z = t + f * c1 * d` instead. If `f` is `None`,
+    /// it is the same as [OpenProofVerifier::generate_challenge].
+    ///
+    /// The factor is not hidden by the protocol; the prover sends it along with the commitment.
+    ///
+    /// ## Panics
+    /// Panics if `f` is the zero polynomial, as any response would satisfy the equation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{OpenProofProver, OpenProofVerifier, Params};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let (open, com) = ck.commit(rng, params.prepare_value::<N>(vec![vec![1, 2, 3]]), &params);
+    /// let (com2, open2) = com.rerandomize(rng, &open, &ck, &params);
+    ///
+    /// let prover = OpenProofProver::new(ck.clone(), params.clone());
+    /// let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    ///
+    /// let f = open2.f().cloned();
+    /// let (response_ctx, commitment) = prover.commit_with_opening(rng, open2, com2);
+    /// let (verification_ctx, challenge) =
+    ///     verifier.generate_challenge_with_randomizer(rng, commitment, f.as_ref());
+    /// let response = prover.create_response(response_ctx, challenge);
+    /// assert!(verifier.verify(response, verification_ctx));
+    /// ```
+    pub fn generate_challenge_with_randomizer(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: OpenProofCommitment<I, N>,
+        f: Option<&Polynomial<I, N>>,
+    ) -> (OpenProofVerificationContext<I, N>, OpenProofChallenge<I, N>) {
+        let (mut context, challenge) = self.generate_challenge(rng, commitment);
+        if let Some(f) = f {
+            assert!(!f.is_zero(), "the relaxation factor f must be non-zero");
+            // f * c1 = A1 * r
+            context.c1 = context.c1.scalar_mul(f);
+        }
+        (context, challenge)
+    }
+
+    /// Generate the challenges for a batch of commitments, as [OpenProofVerifier::generate_challenge] does
+    /// for each of them. The returned pairs of verification context and challenge are in the same order
+    /// as the input commitments.
+    pub fn generate_challenges(
+        &self,
+        rng: &mut impl RngExt,
+        commitments: Vec<OpenProofCommitment<I, N>>,
+    ) -> Vec<(OpenProofVerificationContext<I, N>, OpenProofChallenge<I, N>)> {
+        commitments
+            .into_iter()
+            .map(|commitment| self.generate_challenge(rng, commitment))
+            .collect()
+    }
+
     /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
     /// The context was created during the challenge phase in the protocol.
     pub fn verify(
@@ -164,16 +550,386 @@ where
         response: OpenProofResponse<I, N>,
         context: OpenProofVerificationContext<I, N>,
     ) -> bool {
+        self.verify_detailed(response, context).is_ok()
+    }
+
+    /// Verify the response from the prover as [OpenProofVerifier::verify] does, but tell the reason
+    /// if the response is invalid.
+    ///
+    /// ## Errors
+    /// - [VerifyError::NormConstraintViolated] if the response exceeds the norm bound.
+    /// - [VerifyError::FirstEquationMismatch] if `A1 * z = t + c1 * d` does not hold.
+    pub fn verify_detailed(
+        &self,
+        response: OpenProofResponse<I, N>,
+        context: OpenProofVerificationContext<I, N>,
+    ) -> Result<(), VerifyError> {
+        let a1z = self.ck.a1.dot(&response.z);
+        self.verify_precomputed_detailed(&a1z, response, context)
+    }
+
+    /// Verify the response from the prover as [OpenProofVerifier::verify] does, with the product `a1z = A1 * z`
+    /// already computed by the caller (e.g. by `ck.a1().dot(response.z())`), so that the multiplication is not
+    /// repeated when the product is also used for other equations. It returns the same result as
+    /// [OpenProofVerifier::verify] if `a1z` is the product for `response`; a wrong product makes the result
+    /// meaningless, so it must be computed by the verifier itself, never taken from the prover.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{OpenProofProver, OpenProofVerifier, Params};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    ///
+    /// let prover = OpenProofProver::new(ck.clone(), params.clone());
+    /// let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    ///
+    /// let (response_ctx, commitment) = prover.commit(rng, x);
+    /// let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    /// let response = prover.create_response(response_ctx, challenge);
+    ///
+    /// let a1z = ck.a1().dot(response.z());
+    /// assert!(verifier.verify_with_precomputed(&a1z, response, verification_ctx));
+    /// ```
+    pub fn verify_with_precomputed(
+        &self,
+        a1z: &Mat<I, N>,
+        response: OpenProofResponse<I, N>,
+        context: OpenProofVerificationContext<I, N>,
+    ) -> bool {
+        self.verify_precomputed_detailed(a1z, response, context)
+            .is_ok()
+    }
+
+    fn verify_precomputed_detailed(
+        &self,
+        a1z: &Mat<I, N>,
+        response: OpenProofResponse<I, N>,
+        context: OpenProofVerificationContext<I, N>,
+    ) -> Result<(), VerifyError> {
         if !self.params.check_verify_constraint(&response.z) {
-            return false;
+            return Err(VerifyError::NormConstraintViolated);
         }
         // A1 * z = t + c1 * d
-        let lhs = self.ck.a1.dot(&response.z);
-        let rhs = Mat::<I, N>::from_vec(context.t).add(&context.c1.componentwise_mul(&context.d));
-        lhs == rhs
+        let rhs = Mat::<I, N>::from_vec(context.t).add(&context.c1.scalar_mul(&context.d));
+        if *a1z != rhs {
+            return Err(VerifyError::FirstEquationMismatch);
+        }
+        Ok(())
+    }
+
+    /// Extract the opening of the `commitment` from two accepting transcripts of the protocol on it, which share
+    /// the first message `t` but have different challenges `d` and `d'`. This is the extractor of the proof of
+    /// special soundness (section 4.4 of the paper): `A1 * (z - z') = c1 * (d - d')`, and `d - d'` is invertible
+    /// in `R_q` (see [Params::is_invertible]), so `r = (z - z') * (d - d')^-1` satisfies `A1 * r = c1`, and the
+    /// message is `x = c2 - A2 * r`. The opening has no relaxation factor `f`.
+    ///
+    /// The extracted `r` is the randomness of the commitment if the responses are created by rewinding an honest
+    /// prover (i.e. the same masking polynomials `y`). Otherwise, it may not satisfy the norm bound, and the
+    /// opening is rejected by [Commitment::verify]. It is intended for demonstrating the soundness and testing.
+    ///
+    /// It returns `None` if the challenges are equal, the transcripts do not share `t` or the part `c1` of the
+    /// `commitment`, either response is rejected by [OpenProofVerifier::verify], or `d - d'` is not invertible.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{OpenProofProver, OpenProofVerifier, Params};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    ///
+    /// let prover = OpenProofProver::new(ck.clone(), params.clone());
+    /// let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    ///
+    /// let (response_ctx, commitment) = prover.commit(rng, x.clone());
+    /// // rewind the prover to answer two challenges with the same first message
+    /// let (ctx1, challenge1) = verifier.generate_challenge(rng, commitment.clone());
+    /// let (ctx2, challenge2) = verifier.generate_challenge(rng, commitment.clone());
+    /// let response1 = prover.create_response(response_ctx.clone(), challenge1);
+    /// let response2 = prover.create_response(response_ctx, challenge2);
+    ///
+    /// let opening = verifier
+    ///     .extract(&commitment.c, &response1, &ctx1, &response2, &ctx2)
+    ///     .unwrap();
+    /// assert_eq!(opening.x(), &x[..]);
+    /// assert!(commitment.c.verify(&opening, &ck, &params));
+    /// ```
+    pub fn extract(
+        &self,
+        commitment: &Commitment<I, N>,
+        response1: &OpenProofResponse<I, N>,
+        context1: &OpenProofVerificationContext<I, N>,
+        response2: &OpenProofResponse<I, N>,
+        context2: &OpenProofVerificationContext<I, N>,
+    ) -> Option<Opening<I, N>> {
+        let (c1, c2) = commitment.c1_c2(&self.params);
+        if context1.d == context2.d
+            || context1.t != context2.t
+            || context1.c1 != c1
+            || context2.c1 != c1
+        {
+            return None;
+        }
+        if !self.verify(response1.clone(), context1.clone())
+            || !self.verify(response2.clone(), context2.clone())
+        {
+            return None;
+        }
+
+        // r = (z - z') * (d - d')^-1
+        let d_inv = self
+            .params
+            .ring_inverse(&(context1.d.clone() - context2.d.clone()))?;
+        let r = response1.z.sub(&response2.z).scalar_mul(&d_inv);
+        // x = c2 - A2 * r
+        let x = c2.sub(&self.ck.a2.dot(&r)).one_d_mat_to_vec();
+        Some(Opening { x, r, f: None })
+    }
+
+    /// Verify the non-interactive proof created by [OpenProofProver::prove_non_interactive]. The challenge
+    /// is derived from the commitment in the proof in the same way as the prover does, and must be equal
+    /// to the challenge embedded in the proof. It returns `true` if the proof is valid, otherwise `false`.
+    pub fn verify_non_interactive(&self, proof: OpenProof<I, N>) -> bool {
+        let d = proof.recompute_challenge(&self.params);
+        if d != proof.d {
+            return false;
+        }
+        let OpenProof {
+            commitment,
+            response,
+            ..
+        } = proof;
+        let (c1, _) = commitment.c.c1_c2(&self.params);
+        self.verify(
+            response,
+            OpenProofVerificationContext {
+                c1,
+                t: commitment.t,
+                d,
+            },
+        )
+    }
+
+    /// Verify the responses from the provers in a batch. It returns `true` only if all the responses are
+    /// valid, otherwise `false`.
+    ///
+    /// Instead of checking `A1 * z_i = t_i + c1_i * d_i` for each of the responses, it checks the random
+    /// linear combination `A1 * (w_1 * z_1 + w_2 * z_2 + ..) = w_1 * (t_1 + c1_1 * d_1) + ..` with fresh
+    /// random integer weights `w_i` in `[-q, q]`, so that `A1` is multiplied only once. The weights are
+    /// scalars in `Z_q`, so scaling by them is much cheaper than the multiplication of polynomials. If any
+    /// of the equations does not hold, the combined one holds with probability at most `1/q`.
+    pub fn verify_batch(
+        &self,
+        rng: &mut impl RngExt,
+        items: &[(OpenProofResponse<I, N>, OpenProofVerificationContext<I, N>)],
+    ) -> bool {
+        if !items
+            .iter()
+            .all(|(response, _)| self.params.check_verify_constraint(&response.z))
+        {
+            return false;
+        }
+        let zero = Polynomial::<I, N>::zero();
+        let mut lhs = Mat::<I, N>::from_element(self.params.k, 1, zero.clone());
+        let mut rhs = Mat::<I, N>::from_element(self.params.n, 1, zero);
+        let bound = self.params.q.clone();
+        for (response, context) in items {
+            if response.z.dim() != (self.params.k, 1)
+                || context.t.len() != self.params.n
+                || context.c1.dim() != (self.params.n, 1)
+            {
+                return false;
+            }
+            let w = rng.random_range(-&bound..=bound.clone());
+            // w * z
+            lhs = lhs.add(&response.z.scale(&w));
+            // w * (t + c1 * d)
+            let t_i =
+                Mat::<I, N>::from_vec(context.t.clone()).add(&context.c1.scalar_mul(&context.d));
+            rhs = rhs.add(&t_i.scale(&w));
+        }
+        self.ck.a1.dot(&lhs) == rhs
+    }
+
+    /// Verify the responses from the provers in parallel. It returns the result of [OpenProofVerifier::verify]
+    /// for each pair of response and context, in the same order as the input `items`. Unlike verifying
+    /// the responses in a batch, it tells which of the responses are invalid.
+    #[cfg(feature = "parallel")]
+    pub fn verify_all(
+        &self,
+        items: Vec<(OpenProofResponse<I, N>, OpenProofVerificationContext<I, N>)>,
+    ) -> Vec<bool>
+    where
+        I: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        items
+            .into_par_iter()
+            .map(|(response, context)| self.verify(response, context))
+            .collect()
+    }
+
+    /// Verify the response from the prover as [OpenProofVerifier::verify] does, after checking that
+    /// the degree embedded in the response equals the degree `N` of this verifier. It is useful when
+    /// the response is decoded from bytes, since a polynomial of smaller degree can be decoded as one
+    /// of larger degree.
+    ///
+    /// ## Errors
+    /// Returns [RingZkError::DegreeMismatch] if the degree of the response is not `N`.
+    pub fn verify_for_degree(
+        &self,
+        response: OpenProofResponse<I, N>,
+        context: OpenProofVerificationContext<I, N>,
+    ) -> Result<bool, RingZkError> {
+        if response.degree() != N {
+            return Err(RingZkError::DegreeMismatch {
+                expected: N,
+                got: response.degree(),
+            });
+        }
+        Ok(self.verify(response, context))
+    }
+
+    /// Generate an independent challenge for each run of the protocol started by
+    /// [OpenProofProver::commit_repeated]. It returns the verification context and the challenges.
+    pub fn generate_challenge_repeated(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: RepeatedOpenProofCommitment<I, N>,
+    ) -> (
+        RepeatedOpenProofVerificationContext<I, N>,
+        RepeatedOpenProofChallenge<I, N>,
+    ) {
+        let ds = (0..commitment.ts.len())
+            .map(|_| random_polynomial_from_challenge_set(rng, self.params.kappa))
+            .collect::<Vec<_>>();
+        let (c1, _) = commitment.c.c1_c2(&self.params);
+        (
+            RepeatedOpenProofVerificationContext {
+                c1,
+                ts: commitment.ts,
+                ds: ds.clone(),
+            },
+            RepeatedOpenProofChallenge { ds },
+        )
+    }
+
+    /// Verify the responses of all the runs in the protocol started by [OpenProofProver::commit_repeated].
+    /// It returns `true` only if every run is valid as [OpenProofVerifier::verify] checks, otherwise `false`.
+    pub fn verify_repeated(
+        &self,
+        response: RepeatedOpenProofResponse<I, N>,
+        context: RepeatedOpenProofVerificationContext<I, N>,
+    ) -> bool {
+        self.verify_repeated_detailed(response, context).is_ok()
+    }
+
+    /// Verify the responses as [OpenProofVerifier::verify_repeated] does, but tell the reason if they are
+    /// invalid.
+    ///
+    /// ## Errors
+    /// - [VerifyError::LengthMismatch] if the number of responses or `t_j` is not equal to the number of
+    ///   challenges.
+    /// - The errors of [OpenProofVerifier::verify_detailed] for the first invalid run.
+    pub fn verify_repeated_detailed(
+        &self,
+        response: RepeatedOpenProofResponse<I, N>,
+        context: RepeatedOpenProofVerificationContext<I, N>,
+    ) -> Result<(), VerifyError> {
+        let RepeatedOpenProofVerificationContext { c1, ts, ds } = context;
+        for got in [response.zs.len(), ts.len()] {
+            if got != ds.len() {
+                return Err(VerifyError::LengthMismatch {
+                    expected: ds.len(),
+                    got,
+                });
+            }
+        }
+        response
+            .zs
+            .into_iter()
+            .zip(ts)
+            .zip(ds)
+            .try_for_each(|((z, t), d)| {
+                self.verify_detailed(
+                    OpenProofResponse { z, deg_n: N },
+                    OpenProofVerificationContext {
+                        c1: c1.clone(),
+                        t,
+                        d,
+                    },
+                )
+            })
+    }
+
+    /// Verify the non-interactive proof created by [OpenProofProver::prove_non_interactive_repeated]. The
+    /// challenges are derived from the commitment in the proof in the same way as the prover does, and must
+    /// be equal to the challenges embedded in the proof. It returns `true` if the proof is valid, otherwise
+    /// `false`.
+    pub fn verify_non_interactive_repeated(&self, proof: RepeatedOpenProof<I, N>) -> bool {
+        let RepeatedOpenProof {
+            commitment,
+            ds,
+            response,
+        } = proof;
+        if commitment.fiat_shamir_challenges(&self.params) != ds {
+            return false;
+        }
+        let (c1, _) = commitment.c.c1_c2(&self.params);
+        let context = RepeatedOpenProofVerificationContext {
+            c1,
+            ts: commitment.ts,
+            ds,
+        };
+        self.verify_repeated(response, context)
     }
 }
 
+/// Commit to the value `x` and create the non-interactive proof of opening the commitment, as
+/// [OpenProofProver::prove_non_interactive] does. It returns the opening of the commitment to `x` and the proof.
+///
+/// ## Panics
+/// Panics if the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+pub fn prove_open<I, const N: usize>(
+    rng: &mut impl RngExt,
+    ck: &CommitmentKey<I, N>,
+    params: &Params<I>,
+    x: Vec<Polynomial<I, N>>,
+) -> (Opening<I, N>, OpenProof<I, N>)
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    OpenProofProver::new(ck.clone(), params.clone()).prove_non_interactive(rng, x)
+}
+
+/// Verify the non-interactive proof created by [prove_open], as [OpenProofVerifier::verify_non_interactive] does.
+/// It returns `true` if the proof is valid, otherwise `false`.
+pub fn verify_open<I, const N: usize>(
+    ck: &CommitmentKey<I, N>,
+    params: &Params<I>,
+    proof: OpenProof<I, N>,
+) -> bool
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    OpenProofVerifier::new(ck.clone(), params.clone()).verify_non_interactive(proof)
+}
+
 /// The response created by the prover upon receiving the challenge from the verifier
 /// in the protocol of proof of opening a commitment. It contains the opening of commitment
 /// to value `x`.
@@ -186,6 +942,17 @@ where
     y: Mat<I, N>, // k x 1 matrix
 }
 
+impl<I, const N: usize> OpenProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `y` (`k x 1` matrix) for the commitment to `x`, s.t. the
+    /// response is `z = y + d * r`. It must be kept secret as the opening.
+    pub fn y(&self) -> &Mat<I, N> {
+        &self.y
+    }
+}
+
 /// Contains the commitment to the values `x`.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OpenProofCommitment<I, const N: usize>
@@ -197,6 +964,120 @@ where
     t: Vec<Polynomial<I, N>>, // n x 1 matrix
 }
 
+impl<I, const N: usize> OpenProofCommitment<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Returns the canonical byte encoding of the public statement, i.e. the parameters, the commitment `c`
+    /// and `t`. They are the exact bytes absorbed by the Fiat-Shamir transform of this crate (see
+    /// [OpenProofProver::prove_non_interactive]), so that the challenge can also be derived by an outer
+    /// transcript. The crate absorbs them into a [Transcript] labeled `b"ring-zk/open-proof"`.
+    pub fn statement_bytes(&self, params: &Params<I>) -> Vec<u8> {
+        let mut bytes = params.statement_bytes::<N>();
+        bytes.extend(mat_to_bytes(&self.c.c));
+        bytes.extend(polynomials_to_bytes(&self.t));
+        bytes
+    }
+
+    /// Derive the challenge in Challenge Space C by hashing the statement, which is
+    /// the Fiat-Shamir transform of the protocol.
+    fn fiat_shamir_challenge(&self, params: &Params<I>) -> Polynomial<I, N> {
+        let mut transcript = Transcript::new(FIAT_SHAMIR_LABEL);
+        transcript.append_message(&self.statement_bytes(params));
+        transcript.challenge(params.kappa)
+    }
+}
+
+/// The non-interactive proof of opening a commitment, created by the Fiat-Shamir transform of the protocol.
+/// It contains the commitment, the challenge derived from the commitment, and the response.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenProof<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The commitment to value `x` with information for proving the opening.
+    pub commitment: OpenProofCommitment<I, N>,
+    d: Polynomial<I, N>,
+    response: OpenProofResponse<I, N>,
+}
+
+impl<I, const N: usize> OpenProof<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` derived by the Fiat-Shamir transform, embedded in the proof.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+
+    /// The degree `N` of the polynomial ring under which the proof was created.
+    pub fn degree(&self) -> usize {
+        self.response.degree()
+    }
+}
+
+impl<I, const N: usize> OpenProof<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Re-derive the Fiat-Shamir challenge from the commitment in the proof. A valid proof embeds exactly
+    /// this challenge, so comparing them detects a proof whose challenge was tampered independently of
+    /// the commitment. [OpenProofVerifier::verify_non_interactive] performs this check first.
+    pub fn recompute_challenge(&self, params: &Params<I>) -> Polynomial<I, N> {
+        self.commitment.fiat_shamir_challenge(params)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<I, const N: usize> OpenProof<I, N>
+where
+    I: Zero + Serialize + serde::de::DeserializeOwned,
+{
+    /// Encode the proof by `bincode`. It is more compact than a text format such as JSON, as the coefficients
+    /// are encoded as fixed-size integers.
+    pub fn to_bincode(&self) -> Vec<u8> {
+        crate::encoding::to_bincode(self)
+    }
+
+    /// Decode the proof from the encoding returned by [OpenProof::to_bincode]. The decoded proof is not
+    /// checked; it is verified by [OpenProofVerifier::verify_non_interactive].
+    ///
+    /// ## Errors
+    /// Returns [DecodeError::Truncated](crate::DecodeError::Truncated) if the input ends early, or
+    /// [DecodeError::Malformed](crate::DecodeError::Malformed) if it is not an encoding of the proof or has
+    /// trailing bytes.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, crate::DecodeError> {
+        crate::encoding::from_bincode(bytes)
+    }
+
+    /// The size in bytes of the proof encoded by [OpenProof::to_bincode], e.g. for comparing the cost of sending
+    /// the proofs for a given `N`. The proof is not encoded for computing the size.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ring_zk::{prove_open, OpenProof, Params};
+    ///
+    /// const N: usize = 512;
+    ///
+    /// let rng = &mut rand::rng();
+    ///
+    /// let params = Params::default();
+    /// let ck = params.generate_commitment_key(rng);
+    /// let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    ///
+    /// let (_, proof) = prove_open(rng, &ck, &params, x);
+    /// let bytes = proof.to_bincode();
+    /// assert_eq!(bytes.len(), proof.proof_size_bytes());
+    /// assert_eq!(OpenProof::from_bincode(&bytes), Ok(proof));
+    /// ```
+    pub fn proof_size_bytes(&self) -> usize {
+        crate::encoding::bincode_size(self)
+    }
+}
+
 /// Contains the context for the verification phase of the proof of opening a commitment.
 /// It is used to verify the response from the prover.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -209,6 +1090,22 @@ where
     d: Polynomial<I, N>,
 }
 
+impl<I, const N: usize> OpenProofVerificationContext<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` sent to the prover, from which the context was created.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+
+    /// Create the context from the part `c1` of the commitment, `t = A1 * y` and the challenge `d`, e.g. the
+    /// ones shared by another protocol which contains the equation of the proof of opening.
+    pub(crate) fn from_parts(c1: Mat<I, N>, t: Vec<Polynomial<I, N>>, d: Polynomial<I, N>) -> Self {
+        Self { c1, t, d }
+    }
+}
+
 /// The challenge created by the verifier in the protocol of proof of opening a commitment.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OpenProofChallenge<I, const N: usize>
@@ -218,6 +1115,16 @@ where
     d: Polynomial<I, N>,
 }
 
+impl<I, const N: usize> OpenProofChallenge<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` in Challenge Space C.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
 /// The response from the prover to the verifier in the protocol of proof of opening a commitment.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OpenProofResponse<I, const N: usize>
@@ -225,4 +1132,369 @@ where
     I: Zero,
 {
     z: Mat<I, N>, // k x 1 matrix
+    deg_n: usize, // the degree N under which the response is created
+}
+
+#[cfg(feature = "ct")]
+impl<I, const N: usize> OpenProofResponse<I, N>
+where
+    I: Zero + num::ToPrimitive,
+{
+    /// Compare the responses in constant time w.r.t. the coefficients of `z`, as [Commitment::ct_eq] does for
+    /// the commitments. It returns `Choice(1)` if and only if the responses are equal (`==`), which remains for
+    /// the comparisons of non-secret responses.
+    pub fn ct_eq(&self, other: &OpenProofResponse<I, N>) -> subtle::Choice {
+        subtle::Choice::from((self.deg_n == other.deg_n) as u8) & self.z.ct_eq(&other.z)
+    }
+}
+
+impl<I, const N: usize> OpenProofResponse<I, N>
+where
+    I: Zero,
+{
+    /// The degree `N` of the polynomial ring under which the response was created.
+    pub fn degree(&self) -> usize {
+        self.deg_n
+    }
+
+    /// The response `z = y + d * r` (a `k x 1` matrix).
+    pub fn z(&self) -> &Mat<I, N> {
+        &self.z
+    }
+
+    /// Create the response `z = y + d * r` under the degree `N`.
+    pub(crate) fn from_z(z: Mat<I, N>) -> Self {
+        Self { z, deg_n: N }
+    }
+}
+
+/// The response context created by [OpenProofProver::commit_repeated], for the parallel runs of the proof of
+/// opening a commitment. It contains the opening of the commitment to `x`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedOpenProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment to `x`.
+    pub opening: Opening<I, N>,
+    ys: Vec<Mat<I, N>>, // vector of k x 1 matrices, one per run
+}
+
+/// Contains the commitment to the value `x` shared by the parallel runs of the proof of opening a commitment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedOpenProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x`.
+    pub c: Commitment<I, N>,
+    ts: Vec<Vec<Polynomial<I, N>>>, // vector of n x 1 matrices, one per run
+}
+
+impl<I, const N: usize> RepeatedOpenProofCommitment<I, N>
+where
+    I: Zero,
+{
+    /// The number of parallel runs of the protocol.
+    pub fn repetitions(&self) -> usize {
+        self.ts.len()
+    }
+}
+
+impl<I, const N: usize> RepeatedOpenProofCommitment<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Returns the canonical byte encoding of the public statement, i.e. the parameters, the commitment `c`,
+    /// the number of runs and `t_j` of each run. The crate absorbs them into a [Transcript] labeled
+    /// `b"ring-zk/open-proof-repeated"` (see [OpenProofProver::prove_non_interactive_repeated]).
+    pub fn statement_bytes(&self, params: &Params<I>) -> Vec<u8> {
+        let mut bytes = params.statement_bytes::<N>();
+        bytes.extend(mat_to_bytes(&self.c.c));
+        bytes.extend((self.ts.len() as u64).to_le_bytes());
+        self.ts
+            .iter()
+            .for_each(|t| bytes.extend(polynomials_to_bytes(t)));
+        bytes
+    }
+
+    /// Derive the challenges of all the runs by hashing the statement, which is the Fiat-Shamir transform
+    /// of the protocol.
+    fn fiat_shamir_challenges(&self, params: &Params<I>) -> Vec<Polynomial<I, N>> {
+        let mut transcript = Transcript::new(FIAT_SHAMIR_LABEL_REPEATED);
+        transcript.append_message(&self.statement_bytes(params));
+        (0..self.ts.len())
+            .map(|_| transcript.challenge(params.kappa))
+            .collect()
+    }
+}
+
+/// The non-interactive proof of opening a commitment with parallel runs, created by
+/// [OpenProofProver::prove_non_interactive_repeated]. It contains the commitment, the challenges derived from
+/// the commitment, and the responses of all the runs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedOpenProof<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The commitment to value `x` with information for proving the opening in each run.
+    pub commitment: RepeatedOpenProofCommitment<I, N>,
+    ds: Vec<Polynomial<I, N>>,
+    response: RepeatedOpenProofResponse<I, N>,
+}
+
+#[cfg(feature = "bincode")]
+impl<I, const N: usize> RepeatedOpenProof<I, N>
+where
+    I: Zero + Serialize + serde::de::DeserializeOwned,
+{
+    /// Encode the proof by `bincode`. It is more compact than a text format such as JSON, as the coefficients
+    /// are encoded as fixed-size integers.
+    pub fn to_bincode(&self) -> Vec<u8> {
+        crate::encoding::to_bincode(self)
+    }
+
+    /// Decode the proof from the encoding returned by [RepeatedOpenProof::to_bincode]. The decoded proof is not
+    /// checked; it is verified by [OpenProofVerifier::verify_non_interactive_repeated].
+    ///
+    /// ## Errors
+    /// Returns [DecodeError::Truncated](crate::DecodeError::Truncated) if the input ends early, or
+    /// [DecodeError::Malformed](crate::DecodeError::Malformed) if it is not an encoding of the proof or has
+    /// trailing bytes.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, crate::DecodeError> {
+        crate::encoding::from_bincode(bytes)
+    }
+
+    /// The size in bytes of the proof encoded by [RepeatedOpenProof::to_bincode], e.g. for comparing the cost of sending
+    /// the proofs for a given `N`. The proof is not encoded for computing the size.
+    pub fn proof_size_bytes(&self) -> usize {
+        crate::encoding::bincode_size(self)
+    }
+}
+
+impl<I, const N: usize> RepeatedOpenProof<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomials `d_j` of the runs, derived by the Fiat-Shamir transform.
+    pub fn challenges(&self) -> &[Polynomial<I, N>] {
+        &self.ds
+    }
+}
+
+/// Contains the context for the verification phase of the parallel runs of the proof of opening a commitment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedOpenProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    c1: Mat<I, N>,                  // n x 1 matrix
+    ts: Vec<Vec<Polynomial<I, N>>>, // vector of n x 1 matrices
+    ds: Vec<Polynomial<I, N>>,
+}
+
+impl<I, const N: usize> RepeatedOpenProofVerificationContext<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomials `d_j` sent to the prover, one per run.
+    pub fn challenges(&self) -> &[Polynomial<I, N>] {
+        &self.ds
+    }
+}
+
+/// The challenges created by the verifier for the parallel runs of the proof of opening a commitment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedOpenProofChallenge<I, const N: usize>
+where
+    I: Zero,
+{
+    ds: Vec<Polynomial<I, N>>,
+}
+
+impl<I, const N: usize> RepeatedOpenProofChallenge<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomials `d_j` in Challenge Space C, one per run.
+    pub fn challenges(&self) -> &[Polynomial<I, N>] {
+        &self.ds
+    }
+}
+
+/// The responses from the prover to the verifier for the parallel runs of the proof of opening a commitment.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedOpenProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    zs: Vec<Mat<I, N>>, // vector of k x 1 matrices, one per run
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::params::NormKind;
+
+    const N: usize = 16;
+
+    #[test]
+    fn test_prove_open() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<N>(rng);
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+
+        let (opening, proof) = prove_open(rng, &ck, &params, x);
+        assert!(proof.commitment.c.verify(&opening, &ck, &params));
+        assert!(verify_open(&ck, &params, proof.clone()));
+
+        // the proof does not verify under another commitment key.
+        let other_ck = params.generate_commitment_key::<N>(rng);
+        assert!(!verify_open(&other_ck, &params, proof));
+    }
+
+    #[test]
+    fn test_verify_with_precomputed() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<N>(rng);
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+        let (response_ctx, commitment) = prover.commit(rng, x.clone());
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+
+        // a response to another challenge
+        let (response_ctx, commitment) = prover.commit(rng, x);
+        let (other_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let other_response = prover.create_response(response_ctx, challenge);
+
+        for (response, context) in [
+            (response.clone(), verification_ctx.clone()),
+            (other_response.clone(), other_ctx.clone()),
+            (response, other_ctx),
+            (other_response, verification_ctx),
+        ] {
+            let a1z = ck.a1().dot(response.z());
+            assert_eq!(
+                verifier.verify_with_precomputed(&a1z, response.clone(), context.clone()),
+                verifier.verify(response, context)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_batch_malformed_response() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<N>(rng);
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck, params.clone());
+
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+        let (response_ctx, commitment) = prover.commit(rng, x);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let mut response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify_batch(rng, &[(response.clone(), verification_ctx.clone())]));
+
+        // a response with fewer than k rows is rejected instead of panicking.
+        response.z.polynomials.pop();
+        assert!(!verifier.verify_batch(rng, &[(response, verification_ctx)]));
+    }
+
+    #[test]
+    fn test_response_context_masking() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<N>(rng);
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+        let (response_ctx, commitment) = prover.commit(rng, x);
+        // t = A1 * y
+        assert_eq!(
+            ck.a1.dot(response_ctx.y()),
+            Mat::from_vec(commitment.t.clone())
+        );
+
+        let (_, challenge) = verifier.generate_challenge(rng, commitment);
+        let d = challenge.challenge().clone();
+        let y = response_ctx.y().clone();
+        let r = response_ctx.opening.r.clone();
+        let response = prover.create_response(response_ctx, challenge);
+        // z = y + d * r
+        assert_eq!(response.z, y.add(&r.scalar_mul(&d)));
+    }
+
+    #[test]
+    fn test_statement_bytes_external_challenge() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<N>(rng);
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+
+        let prover = OpenProofProver::new(ck, params.clone());
+        let (_, commitment) = prover.commit(rng, x);
+
+        // derive the challenge by an outer transcript from the statement bytes.
+        let mut transcript = Transcript::new(FIAT_SHAMIR_LABEL);
+        transcript.append_message(&commitment.statement_bytes(&params));
+        let d = transcript.challenge::<_, N>(params.kappa);
+
+        assert_eq!(d, commitment.fiat_shamir_challenge(&params));
+
+        // the statement is bound to the splitting parameter and the norm.
+        let other_d = Params {
+            d: 4,
+            ..params.clone()
+        };
+        let other_norm = Params {
+            norm: NormKind::Infinity,
+            ..params.clone()
+        };
+        let bytes = commitment.statement_bytes(&params);
+        assert_ne!(bytes, commitment.statement_bytes(&other_d));
+        assert_ne!(bytes, commitment.statement_bytes(&other_norm));
+        assert_ne!(d, commitment.fiat_shamir_challenge(&other_d));
+        assert_ne!(d, commitment.fiat_shamir_challenge(&other_norm));
+    }
+
+    #[test]
+    fn test_recompute_challenge() {
+        let rng = &mut rand::rng();
+
+        let params = Params::default();
+        let ck = params.generate_commitment_key::<N>(rng);
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck, params.clone());
+        let (_, proof) = prover.prove_non_interactive(rng, x);
+        assert_eq!(proof.recompute_challenge(&params), proof.d);
+        assert!(verifier.verify_non_interactive(proof.clone()));
+
+        // swap the embedded challenge for another element in the challenge space.
+        let mut tampered = proof;
+        loop {
+            let d = random_polynomial_from_challenge_set(rng, params.kappa);
+            if d != tampered.d {
+                tampered.d = d;
+                break;
+            }
+        }
+        assert_ne!(tampered.recompute_challenge(&params), tampered.d);
+        assert!(!verifier.verify_non_interactive(tampered));
+    }
 }
@@ -0,0 +1,388 @@
+//! Implementation of Proof of Product.
+//!
+//! It is **not** defined in the paper. It proves the knowledge of openings of commitments to `x`, `x'` and
+//! `x''` such that `x'' = x * x'`, where both factors are hidden. The product is the polynomial product in
+//! the ring `R_q = Z_q[X]/(X^N+1)`, taken slot by slot if the message length `l` is larger than 1.
+//!
+//! The relation is quadratic in the committed values, and is proven by the technique of Attema, Lyubashevsky
+//! and Seiler (2020/517). For a committed value `m` with randomness `r` and masking polynomial `y`, the
+//! verifier computes `f = a2 * z - c2 * d = a2 * y - d * m`. Then `f * f' + d * f''` is a polynomial of
+//! degree 2 in the challenge `d`, where the coefficient of `d^2` is `x * x' - x''`. The prover commits to
+//! the coefficient of `d` (the "garbage") beforehand, so that the verifier can cancel it.
+//!
+//! This modules contains struct [ProductProofProver] and [ProductProofVerifier] for proving and verifying
+//! opening of commitments ([ProductProofCommitment]) to `x`, `x'` and `x''` such that `x'' = x * x'`.
+//! The prover and verifier will exchange messages [ProductProofChallenge] and [ProductProofResponse] to
+//! complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [ProductProofResponseContext] which is created and used by prover in the
+//! protocol. The verifier generates the challenge and verifies the response by using the context
+//! [ProductProofVerificationContext].
+//!
+//! ## Safety
+//! - The relation is the product of polynomials in `R_q`, not the product of the integer coefficients. E.g.
+//!   for messages prepared by [Params::prepare_value], the coefficients of `x * x'` are negacyclic
+//!   convolutions of the coefficients reduced modulo `q'`, which coincide with the integer product only if
+//!   `x` and `x'` are constant polynomials whose product does not exceed `q` in absolute value.
+//! - The soundness relies on the coefficient of `d^2` being zero, which follows from the responses to
+//!   distinct challenges only if their differences are invertible in `R_q`. As `X^N+1` splits into two
+//!   factors modulo `q'` (Lemma 1 of the paper), this holds for the challenges in the Challenge Space C,
+//!   but the soundness error is larger than the one of the linear proofs, i.e. about `2 / |C|` per run.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{Params, ProductProofProver, ProductProofVerifier};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2]]);
+//! let xp = params.prepare_value::<N>(vec![vec![3, 4]]);
+//! let xpp = params.prepare_value::<N>(vec![vec![3, 10, 8]]); // (1 + 2X) * (3 + 4X)
+//!
+//! let c = ck.commit(rng, x, &params);
+//! let cp = ck.commit(rng, xp, &params);
+//! let cpp = ck.commit(rng, xpp, &params);
+//!
+//! let prover = ProductProofProver::new(ck.clone(), params.clone());
+//! let verifier = ProductProofVerifier::new(ck.clone(), params.clone());
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving the product relationship of the committed values.
+//! let (response_ctx, commitment) = prover.commit(rng, c, cp, cpp);
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::Zero;
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge_space::random_polynomial_from_challenge_set,
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of product. It is used to prove that the prover knows the
+/// openings of commitments to `x`, `x'` and `x''` such that `x'' = x * x'`.
+pub struct ProductProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> ProductProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> ProductProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> ProductProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create the commitment for proving that `x'' = x * x'`, given the commitments `c`, `c'` and `c''`
+    /// to `x`, `x'` and `x''`, together with their openings.
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the openings
+    /// of commitments to `x`, `x'` and `x''`.
+    ///
+    /// ## Panics
+    /// Panics if `x''` is not equal to `x * x'`.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        (opening, c): (Opening<I, N>, Commitment<I, N>),
+        (opening_p, cp): (Opening<I, N>, Commitment<I, N>),
+        (opening_pp, cpp): (Opening<I, N>, Commitment<I, N>),
+    ) -> (
+        ProductProofResponseContext<I, N>,
+        ProductProofCommitment<I, N>,
+    ) {
+        let x = Mat::<I, N>::from_vec(opening.x.clone());
+        let xp = Mat::<I, N>::from_vec(opening_p.x.clone());
+        assert!(x.hadamard(&xp) == Mat::from_vec(opening_pp.x.clone()));
+
+        // masks for x, x' and x''
+        let y = self.mask(rng);
+        let yp = self.mask(rng);
+        let ypp = self.mask(rng);
+
+        // a = A2 * y for each mask
+        let a = self.ck.a2.dot(&y);
+        let a_p = self.ck.a2.dot(&yp);
+        let a_pp = self.ck.a2.dot(&ypp);
+
+        // garbage g = a'' - a * x' - x * a', which is the coefficient of d in f * f' + d * f''
+        let g = a_pp.sub(&a.hadamard(&xp)).sub(&x.hadamard(&a_p));
+        let (opening_g, cg) = self.ck.commit(rng, g.one_d_mat_to_vec(), &self.params);
+        let yg = self.mask(rng);
+
+        // v = a * a' + A2 * yg
+        let v = a.hadamard(&a_p).add(&self.ck.a2.dot(&yg));
+
+        // t = A1 * y for each mask
+        let t = self.ck.a1.dot(&y).one_d_mat_to_vec();
+        let tp = self.ck.a1.dot(&yp).one_d_mat_to_vec();
+        let tpp = self.ck.a1.dot(&ypp).one_d_mat_to_vec();
+        let tg = self.ck.a1.dot(&yg).one_d_mat_to_vec();
+
+        (
+            ProductProofResponseContext {
+                opening,
+                opening_p,
+                opening_pp,
+                opening_g,
+                y,
+                yp,
+                ypp,
+                yg,
+            },
+            ProductProofCommitment {
+                c,
+                cp,
+                cpp,
+                cg,
+                t,
+                tp,
+                tpp,
+                tg,
+                v,
+            },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: ProductProofResponseContext<I, N>,
+        challenge: ProductProofChallenge<I, N>,
+    ) -> ProductProofResponse<I, N> {
+        // z = y + d * r for each mask
+        let respond =
+            |y: &Mat<I, N>, opening: &Opening<I, N>| y.add(&opening.r.scalar_mul(&challenge.d));
+        ProductProofResponse {
+            z: respond(&context.y, &context.opening),
+            zp: respond(&context.yp, &context.opening_p),
+            zpp: respond(&context.ypp, &context.opening_pp),
+            zg: respond(&context.yg, &context.opening_g),
+        }
+    }
+
+    /// Sample a masking polynomial vector y <- N^k_sigma.
+    fn mask(&self, rng: &mut impl RngExt) -> Mat<I, N> {
+        Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        })
+    }
+}
+
+/// The verifier for the proof of product. It is used to verify that the prover knows the
+/// openings of commitments to `x`, `x'` and `x''` such that `x'' = x * x'`.
+pub struct ProductProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+}
+
+impl<I, const N: usize> ProductProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        ProductProofVerifier { params, ck }
+    }
+
+    /// Generate the challenge for the prover, given the commitments that says the prover knows its
+    /// openings to the commitments to values `x`, `x'` and `x''` such that `x'' = x * x'`.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: ProductProofCommitment<I, N>,
+    ) -> (
+        ProductProofVerificationContext<I, N>,
+        ProductProofChallenge<I, N>,
+    ) {
+        let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        (
+            ProductProofVerificationContext {
+                commitment,
+                d: d.clone(),
+            },
+            ProductProofChallenge { d },
+        )
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: ProductProofResponse<I, N>,
+        context: ProductProofVerificationContext<I, N>,
+    ) -> bool {
+        let ProductProofVerificationContext { commitment, d } = context;
+        let (n, k, l) = (self.params.n, self.params.k, self.params.l);
+        let zs = [&response.z, &response.zp, &response.zpp, &response.zg];
+        let cs = [
+            &commitment.c,
+            &commitment.cp,
+            &commitment.cpp,
+            &commitment.cg,
+        ];
+        let ts = [
+            &commitment.t,
+            &commitment.tp,
+            &commitment.tpp,
+            &commitment.tg,
+        ];
+
+        // check dimensions
+        if commitment.v.dim() != (l, 1)
+            || cs.iter().any(|c| c.c.dim() != (n + l, 1))
+            || ts.iter().any(|t| t.len() != n)
+        {
+            return false;
+        }
+        if !zs
+            .iter()
+            .all(|z| z.dim() == (k, 1) && self.params.check_verify_constraint(z))
+        {
+            return false;
+        }
+        let cs = cs.map(|c| c.c1_c2(&self.params));
+
+        // A1 * z = t + c1 * d for each commitment
+        if !zs.iter().zip(cs.iter()).zip(ts).all(|((z, (c1, _)), t)| {
+            self.ck.a1.dot(z) == Mat::<I, N>::from_vec(t.clone()).add(&c1.scalar_mul(&d))
+        }) {
+            return false;
+        }
+
+        // f = A2 * z - c2 * d for each commitment
+        let [f, f_p, f_pp, f_g] =
+            [0, 1, 2, 3].map(|i| self.ck.a2.dot(zs[i]).sub(&cs[i].1.scalar_mul(&d)));
+
+        // f * f' + d * f'' + f_g = v
+        let lhs = f.hadamard(&f_p).add(&f_pp.scalar_mul(&d)).add(&f_g);
+        lhs == commitment.v
+    }
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of product. It contains the openings of commitments
+/// to `x`, `x'` and `x''` such that `x'' = x * x'`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment to `x` s.t. `x'' = x * x'`.
+    pub opening: Opening<I, N>,
+    /// The opening of the commitment to `x'` s.t. `x'' = x * x'`.
+    pub opening_p: Opening<I, N>,
+    /// The opening of the commitment to `x''` s.t. `x'' = x * x'`.
+    pub opening_pp: Opening<I, N>,
+    opening_g: Opening<I, N>, // opening of the garbage commitment
+    y: Mat<I, N>,             // k x 1 matrix
+    yp: Mat<I, N>,            // k x 1 matrix
+    ypp: Mat<I, N>,           // k x 1 matrix
+    yg: Mat<I, N>,            // k x 1 matrix
+}
+
+/// Contains the commitments to the values `x`, `x'` and `x''` such that `x'' = x * x'`, used in
+/// the proof of product.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x` s.t. `x'' = x * x'`.
+    pub c: Commitment<I, N>,
+    /// Commitment to value `x'` s.t. `x'' = x * x'`.
+    pub cp: Commitment<I, N>,
+    /// Commitment to value `x''` s.t. `x'' = x * x'`.
+    pub cpp: Commitment<I, N>,
+    cg: Commitment<I, N>,       // commitment to the garbage
+    t: Vec<Polynomial<I, N>>,   // n x 1 matrix
+    tp: Vec<Polynomial<I, N>>,  // n x 1 matrix
+    tpp: Vec<Polynomial<I, N>>, // n x 1 matrix
+    tg: Vec<Polynomial<I, N>>,  // n x 1 matrix
+    v: Mat<I, N>,               // l x 1 matrix
+}
+
+/// Contains the context for the verification phase of the proof of product.
+/// It is used to verify the response from the prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    commitment: ProductProofCommitment<I, N>,
+    d: Polynomial<I, N>,
+}
+
+/// The challenge created by the verifier in the protocol of proof of product.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductProofChallenge<I, const N: usize>
+where
+    I: Zero,
+{
+    d: Polynomial<I, N>,
+}
+
+/// The response from the prover to the verifier in the protocol of proof of product.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    z: Mat<I, N>,   // k x 1 matrix
+    zp: Mat<I, N>,  // k x 1 matrix
+    zpp: Mat<I, N>, // k x 1 matrix
+    zg: Mat<I, N>,  // k x 1 matrix
+}
@@ -0,0 +1,279 @@
+//! Implementation of Proof of Range.
+//!
+//! It is **not** defined in the paper. It proves that the committed scalar message `x` lies in the range
+//! `[0, 2^B)` without revealing it, e.g. an amount in a confidential transaction is non-negative and
+//! bounded. The prover commits to the bits `b_0, b_1, .., b_(B-1)` of `x` and proves that
+//! - `x = 2^0 * b_0 + 2^1 * b_1 + .. + 2^(B-1) * b_(B-1)`, which is linear in the committed values,
+//! - `b_i * (b_i - 1) = 0` for each bit, which is quadratic in the committed values, and
+//! - `sigma(b_i) = b_i` for each bit, where `sigma` is the automorphism `X -> X^(-1)` of `R_q`.
+//!
+//! The quadratic relations are proven by the technique of Attema, Lyubashevsky and Seiler (2020/517). For
+//! a committed value `m` with randomness `r` and masking polynomial `y`, the verifier computes
+//! `f = a2 * z - c2 * d = a2 * y - d * m`. Then `f_i * (f_i + d)` is a polynomial of degree 2 in the challenge
+//! `d`, where the coefficient of `d^2` is `b_i * (b_i - 1)`. The prover commits to the coefficient of `d`
+//! (the "garbage") for each bit beforehand, so that the verifier can cancel it.
+//!
+//! `X^N + 1` splits into two irreducible factors modulo `q'` (Lemma 1 of the paper), so `b_i * (b_i - 1) = 0`
+//! alone only proves that `b_i` is 0 or 1 modulo each factor, which is also satisfied by the two
+//! idempotents of the Chinese Remainder Theorem (non-constant polynomials). `sigma` swaps the two
+//! factors, so the polynomials satisfying both relations are exactly the constants 0 and 1. The challenge
+//! `d` is drawn from the challenges fixed by `sigma` (see
+//! [crate::challenge_space::random_invariant_polynomial_from_challenge_set]), so that
+//! `f_b_i - sigma(f_b_i) = (a_b_i - sigma(a_b_i)) - d * (b_i - sigma(b_i))`. The prover sends
+//! `w_i = a_b_i - sigma(a_b_i)` beforehand, and the verifier checks that `f_b_i - sigma(f_b_i) = w_i`.
+//!
+//! This modules contains struct [RangeProofProver] and [RangeProofVerifier] for proving and verifying
+//! the range of the committed value ([RangeProofCommitment]).
+//! The prover and verifier will exchange messages [RangeProofChallenge] and [RangeProofResponse] to
+//! complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [RangeProofResponseContext] which is created and used by prover in the
+//! protocol. The verifier generates the challenge and verifies the response by using the context
+//! [RangeProofVerificationContext].
+//!
+//! ## Constraints
+//! - The message `x` must be a vector (of length `l`) of constant polynomials, each of which is in `[0, 2^B)`.
+//!   The relations are proven for each of the `l` slots.
+//! - `2^B` must not exceed `q` defined in the `Params` struct (i.e. half of the modulus), otherwise the
+//!   bit-weighted sum wraps around modulo `q'` and a "negative" value is accepted. With the default
+//!   parameters, `B` must be at most 31.
+//! - The splitting parameter `d` defined in the `Params` struct must be 2, otherwise the verifier rejects.
+//! - The soundness relies on the differences of the challenges being invertible in `R_q`, and the challenges
+//!   are drawn from a subset of Challenge Space C, so the soundness error is larger than that of the proofs
+//!   drawing from the whole Challenge Space C.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{Params, RangeProofProver, RangeProofVerifier};
+//!
+//! const N: usize = 512;
+//! const B: usize = 8;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![200]]);
+//! let (opening, c) = ck.commit(rng, x, &params);
+//!
+//! let prover = RangeProofProver::new(ck.clone(), params.clone());
+//! let verifier = RangeProofVerifier::new(ck.clone(), params.clone(), B);
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving the range of the committed value.
+//! let (response_ctx, commitment) = prover.commit(rng, (opening, c), B);
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::{FromPrimitive, One, ToPrimitive, Zero};
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+
+use crate::{
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    params::Params,
+    prove::bits,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of range. It is used to prove that the prover knows the
+/// opening of the commitment to `x` such that `x` is in `[0, 2^B)`.
+pub struct RangeProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> RangeProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> RangeProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> RangeProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create commitments to the bits of `x` for proving that `x` is in `[0, 2^bits)`, given the commitment
+    /// `c` to `x` and its opening.
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the openings
+    /// of commitments to `x` and its bits.
+    ///
+    /// ## Panics
+    /// Panics if
+    /// - `bits` is zero, or `2^bits` exceeds `q` defined in the `Params` struct.
+    /// - any polynomial in `x` is not a constant in `[0, 2^bits)`.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        (opening, c): (Opening<I, N>, Commitment<I, N>),
+        bits: usize,
+    ) -> (RangeProofResponseContext<I, N>, RangeProofCommitment<I, N>) {
+        assert!(bits > 0 && check_bits(&self.params, bits));
+        let values = opening
+            .x
+            .iter()
+            .map(|x_j| {
+                assert!(x_j.iter().skip(1).all(Zero::is_zero));
+                let value = x_j.iter().next().map_or(0, |c| c.to_i128().unwrap());
+                assert!(value >= 0 && value < (1 << bits));
+                value
+            })
+            .collect::<Vec<_>>();
+
+        // b_i = i-th bit of x, for each slot
+        let bs = (0..bits)
+            .map(|i| {
+                values
+                    .iter()
+                    .map(|v| Polynomial::from_coeffs(vec![I::from_i128((v >> i) & 1).unwrap()]))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        bits::commit(
+            rng,
+            &self.ck,
+            &self.params,
+            &self.sampler,
+            (opening, c),
+            bs,
+            &powers_of_two(bits),
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: RangeProofResponseContext<I, N>,
+        challenge: RangeProofChallenge<I, N>,
+    ) -> RangeProofResponse<I, N> {
+        bits::create_response(context, challenge)
+    }
+}
+
+/// The verifier for the proof of range. It is used to verify that the prover knows the
+/// opening of the commitment to `x` such that `x` is in `[0, 2^B)`.
+pub struct RangeProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    bits: usize,
+}
+
+impl<I, const N: usize> RangeProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create the verifier for the range `[0, 2^bits)`.
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>, bits: usize) -> Self {
+        RangeProofVerifier { params, ck, bits }
+    }
+
+    /// Generate the challenge for the prover, given the commitments that says the prover knows its
+    /// opening to the commitment to `x` such that `x` is in `[0, 2^B)`.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: RangeProofCommitment<I, N>,
+    ) -> (
+        RangeProofVerificationContext<I, N>,
+        RangeProofChallenge<I, N>,
+    ) {
+        bits::generate_challenge(rng, &self.params, commitment)
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: RangeProofResponse<I, N>,
+        context: RangeProofVerificationContext<I, N>,
+    ) -> bool {
+        check_bits(&self.params, self.bits)
+            && bits::verify(
+                &self.ck,
+                &self.params,
+                &powers_of_two(self.bits),
+                response,
+                context,
+            )
+    }
+}
+
+/// Returns `true` if `2^bits` does not exceed `q` defined in the `Params` struct.
+fn check_bits<I>(params: &Params<I>, bits: usize) -> bool
+where
+    I: ToPrimitive,
+{
+    bits < 127 && (1_i128 << bits) <= params.q.to_i128().unwrap()
+}
+
+/// Returns the constant polynomials `2^0, 2^1, .., 2^(bits-1)`, which are the weights of the bits.
+fn powers_of_two<I, const N: usize>(bits: usize) -> Vec<Polynomial<I, N>>
+where
+    I: Clone + One + Zero + FromPrimitive,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    (0..bits)
+        .map(|i| Polynomial::from_coeffs(vec![I::from_i128(1 << i).unwrap()]))
+        .collect()
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of range. It contains the openings of commitments
+/// to `x` and its bits.
+pub type RangeProofResponseContext<I, const N: usize> = bits::BitsProofResponseContext<I, N>;
+
+/// Contains the commitments to the value `x` and its bits, used in the proof of range. The commitments to
+/// the bits (field `cbs`) start from the least significant bit.
+pub type RangeProofCommitment<I, const N: usize> = bits::BitsProofCommitment<I, N>;
+
+/// Contains the context for the verification phase of the proof of range.
+/// It is used to verify the response from the prover.
+pub type RangeProofVerificationContext<I, const N: usize> =
+    bits::BitsProofVerificationContext<I, N>;
+
+/// The challenge created by the verifier in the protocol of proof of range.
+pub type RangeProofChallenge<I, const N: usize> = bits::BitsProofChallenge<I, N>;
+
+/// The response from the prover to the verifier in the protocol of proof of range.
+pub type RangeProofResponse<I, const N: usize> = bits::BitsProofResponse<I, N>;
@@ -0,0 +1,383 @@
+//! Implementation of Proof of Set Membership.
+//!
+//! It is **not** defined in the paper. It proves that the committed value `x` is equal to one of the
+//! values in a public set `[v_1, v_2, ..., v_m]` without revealing which one. It is an OR-composition
+//! of the proofs of equality `x = v_i`, where each of them is a Sigma protocol proving the knowledge of
+//! `r` s.t. `[a1 a2] * r = [c1 c2] - [0 v_i]`.
+//!
+//! The composition follows the standard technique for disjunctions: the prover simulates the transcripts
+//! of the branches `x = v_i` which are false, and runs the protocol honestly for the true branch. The
+//! verifier sends a random 32-byte challenge `e`, and the prover splits it into `e_1, e_2, ..., e_m` s.t.
+//! `e = e_1 xor e_2 xor ... xor e_m`. The challenge polynomial `d_i` of each branch is derived from `e_i`,
+//! so that every `d_i` lies in the Challenge Space C.
+//!
+//! This modules contains struct [SetMembershipProofProver] and [SetMembershipProofVerifier] for proving
+//! and verifying the membership of the committed value ([SetMembershipProofCommitment]).
+//! The prover and verifier will exchange messages [SetMembershipProofChallenge] and [SetMembershipProofResponse]
+//! to complete the 3-phase Sigma Protocol.
+//! The opening is encapsulated in [SetMembershipProofResponseContext] which is created and used by prover in
+//! the protocol. The verifier generates the challenge and verifies the response by using the context
+//! [SetMembershipProofVerificationContext].
+//!
+//! ## Safety
+//! The simulated responses are sampled from the same distribution as the masking polynomials `y`, while the
+//! honest response is `y + d * r`. As no rejection sampling is applied on the responses in this library, the
+//! two distributions are close but not identical.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{Params, SetMembershipProofProver, SetMembershipProofVerifier};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let vs = vec![
+//!     params.prepare_value::<N>(vec![vec![1, 2]]),
+//!     params.prepare_value::<N>(vec![vec![3, 4]]),
+//! ];
+//! let x = params.prepare_value::<N>(vec![vec![3, 4]]);
+//!
+//! let prover = SetMembershipProofProver::new(ck.clone(), params.clone());
+//! let verifier = SetMembershipProofVerifier::new(ck.clone(), params.clone(), vs.clone());
+//!
+//! // 3-phase Sigma Protocol:
+//! // - First create commitment with information for proving the membership of the committed value.
+//! let (response_ctx, commitment) = prover.commit(rng, x, vs);
+//! // - Verifier receives commitment and then create a challenge.
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! // - Prover receives the challenge and then create a response.
+//! let response = prover.create_response(response_ctx, challenge);
+//! // - Verifier verifies the response.
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num::{One, Zero};
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    challenge_space::polynomial_from_challenge_set_with_seed,
+    commit::{Commitment, CommitmentKey, Opening},
+    integer::RingInteger,
+    mat::Mat,
+    params::Params,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+};
+
+/// The prover for the proof of set membership. It is used to prove that the prover knows the
+/// opening of commitment to `x` such that `x` is equal to one of the values `v_i` in a public set.
+pub struct SetMembershipProofProver<I, const N: usize, S = DiscreteGaussianSampler>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    sampler: S,
+}
+
+impl<I, const N: usize> SetMembershipProofProver<I, N>
+where
+    I: Zero,
+{
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> SetMembershipProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
+    }
+}
+
+impl<I, const N: usize, S> SetMembershipProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create commitment to `x` which is equal to one of the values in `vs`.
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response in a later phase of the protocol. Note that the context includes the opening
+    /// of commitment to `x`.
+    ///
+    /// ## Panics
+    /// Panics if
+    /// - `x` is not equal to any of the values in `vs`.
+    /// - the length of `x` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit(
+        &self,
+        rng: &mut impl RngExt,
+        x: Vec<Polynomial<I, N>>,
+        vs: Vec<Vec<Polynomial<I, N>>>,
+    ) -> (
+        SetMembershipProofResponseContext<I, N>,
+        SetMembershipProofCommitment<I, N>,
+    ) {
+        let index = vs
+            .iter()
+            .position(|v| *v == x)
+            .expect("value is not a member of the set");
+        let (opening, c) = self.ck.commit(rng, x, &self.params);
+        let a = self.ck.a();
+
+        // y <- N^k_sigma for the true branch, and z_i <- N^k_sigma for the simulated branches.
+        let ys = (0..vs.len())
+            .map(|_| {
+                Mat::<I, N>::new_with(self.params.k, 1, || {
+                    self.sampler
+                        .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut es = (0..vs.len())
+            .map(|_| rng.random::<[u8; 32]>())
+            .collect::<Vec<_>>();
+        es[index] = [0u8; 32]; // to be determined after receiving the challenge
+
+        // t = A * y for the true branch
+        // t_i = A * z_i - (c - [0 v_i]) * d_i for the simulated branches
+        let ts = vs
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if i == index {
+                    a.dot(&ys[i])
+                } else {
+                    let d = polynomial_from_challenge_set_with_seed(es[i], self.params.kappa);
+                    a.dot(&ys[i])
+                        .sub(&shifted_commitment(&c, v, &self.params).scalar_mul(&d))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (
+            SetMembershipProofResponseContext {
+                opening,
+                index,
+                ys,
+                es,
+            },
+            SetMembershipProofCommitment { c, vs, ts },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier. The response is created
+    /// using the context that was created during the commitment phase.
+    pub fn create_response(
+        &self,
+        context: SetMembershipProofResponseContext<I, N>,
+        challenge: SetMembershipProofChallenge,
+    ) -> SetMembershipProofResponse<I, N> {
+        let SetMembershipProofResponseContext {
+            opening,
+            index,
+            mut ys,
+            mut es,
+        } = context;
+
+        // e_index = e xor e_1 xor ... (except e_index)
+        es[index] = es
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .fold(challenge.e, |acc, (_, e)| xor(&acc, e));
+        let d = polynomial_from_challenge_set_with_seed(es[index], self.params.kappa);
+
+        // z = y + d * r for the true branch
+        ys[index] = ys[index].add(&opening.r.scalar_mul(&d));
+
+        SetMembershipProofResponse { es, zs: ys }
+    }
+}
+
+/// The verifier for the proof of set membership. It is used to verify that the prover knows the
+/// opening of commitment to `x` such that `x` is equal to one of the values `v_i` in a public set.
+pub struct SetMembershipProofVerifier<I, const N: usize>
+where
+    I: Zero,
+{
+    params: Params<I>,
+    ck: CommitmentKey<I, N>,
+    vs: Vec<Vec<Polynomial<I, N>>>,
+}
+
+impl<I, const N: usize> SetMembershipProofVerifier<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    /// Create the verifier for the public set `vs`. A commitment which claims membership of any other
+    /// set is rejected.
+    pub fn new(ck: CommitmentKey<I, N>, params: Params<I>, vs: Vec<Vec<Polynomial<I, N>>>) -> Self {
+        SetMembershipProofVerifier { params, ck, vs }
+    }
+
+    /// Generate the challenge for the prover, given the commitment that says the prover knows its
+    /// opening to the commitment to `x` such that `x` is equal to one of the values in the set.
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: SetMembershipProofCommitment<I, N>,
+    ) -> (
+        SetMembershipProofVerificationContext<I, N>,
+        SetMembershipProofChallenge,
+    ) {
+        let e = rng.random::<[u8; 32]>();
+        (
+            SetMembershipProofVerificationContext {
+                c: commitment.c,
+                vs: commitment.vs,
+                ts: commitment.ts,
+                e,
+            },
+            SetMembershipProofChallenge { e },
+        )
+    }
+
+    /// Verify the response from the prover. It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify(
+        &self,
+        response: SetMembershipProofResponse<I, N>,
+        context: SetMembershipProofVerificationContext<I, N>,
+    ) -> bool {
+        // the commitment must claim membership of the set of this verifier
+        if context.vs != self.vs {
+            return false;
+        }
+        // check lengths and dimensions
+        let (n, k, l) = (self.params.n, self.params.k, self.params.l);
+        let m = self.vs.len();
+        if m == 0 || response.es.len() != m || response.zs.len() != m || context.ts.len() != m {
+            return false;
+        }
+        if self.vs.iter().any(|v| v.len() != l)
+            || context.c.c.dim() != (n + l, 1)
+            || context.ts.iter().any(|t| t.dim() != (n + l, 1))
+        {
+            return false;
+        }
+        if !response
+            .zs
+            .iter()
+            .all(|z| z.dim() == (k, 1) && self.params.check_verify_constraint(z))
+        {
+            return false;
+        }
+        // e = e_1 xor e_2 xor ... xor e_m
+        if response.es.iter().fold([0u8; 32], |acc, e| xor(&acc, e)) != context.e {
+            return false;
+        }
+
+        // A * z_i = t_i + (c - [0 v_i]) * d_i for each branch
+        let a = self.ck.a();
+        response
+            .zs
+            .iter()
+            .zip(response.es.iter())
+            .zip(context.ts.iter().zip(self.vs.iter()))
+            .all(|((z, e), (t, v))| {
+                let d = polynomial_from_challenge_set_with_seed(*e, self.params.kappa);
+                let lhs = a.dot(z);
+                let rhs = t.add(&shifted_commitment(&context.c, v, &self.params).scalar_mul(&d));
+                lhs == rhs
+            })
+    }
+}
+
+/// The response created by the prover upon receiving the challenge from the verifier
+/// in the protocol of proof of set membership. It contains the opening of commitment
+/// to `x` which is equal to one of the values in the set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetMembershipProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// The opening of the commitment to `x`.
+    pub opening: Opening<I, N>,
+    index: usize,       // index of the value in the set that equals to x
+    ys: Vec<Mat<I, N>>, // vector of k x 1 matrices (y of the true branch, z_i of the simulated branches)
+    es: Vec<[u8; 32]>,  // challenges of the simulated branches
+}
+
+/// Contains the commitment to the value `x` which is equal to one of the values in the set,
+/// used in the proof of set membership.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetMembershipProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// Commitment to value `x`.
+    pub c: Commitment<I, N>,
+    /// The public set of values.
+    pub vs: Vec<Vec<Polynomial<I, N>>>,
+    ts: Vec<Mat<I, N>>, // vector of (n + l) x 1 matrices
+}
+
+/// Contains the context for the verification phase of the proof of set membership.
+/// It is used to verify the response from the prover.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetMembershipProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    c: Commitment<I, N>,
+    vs: Vec<Vec<Polynomial<I, N>>>,
+    ts: Vec<Mat<I, N>>, // vector of (n + l) x 1 matrices
+    e: [u8; 32],
+}
+
+/// The challenge created by the verifier in the protocol of proof of set membership.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetMembershipProofChallenge {
+    e: [u8; 32],
+}
+
+/// The response from the prover to the verifier in the protocol of proof of set membership.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetMembershipProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    es: Vec<[u8; 32]>,  // challenges of all branches
+    zs: Vec<Mat<I, N>>, // vector of k x 1 matrices
+}
+
+/// Returns `c - [0_n v]`, which is a commitment to zero if `c` is a commitment to `v`.
+fn shifted_commitment<I, const N: usize>(
+    c: &Commitment<I, N>,
+    v: &[Polynomial<I, N>],
+    params: &Params<I>,
+) -> Mat<I, N>
+where
+    I: Clone + One + Zero,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
+    let mut z = Mat::<I, N>::from_element(params.n, 1, Polynomial::<I, N>::zero());
+    z.extend_rows(Mat::<I, N>::from_vec(v.to_vec()));
+    c.c.sub(&z)
+}
+
+fn xor(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    core::array::from_fn(|i| a[i] ^ b[i])
+}
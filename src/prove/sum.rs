@@ -49,48 +49,77 @@
 //! assert!(verifier.verify(response, verification_ctx));
 //! ```
 
-use std::ops::{Add, Mul, Neg, Sub};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Neg, Sub};
 
-use num::{FromPrimitive, One, ToPrimitive, Zero};
+use num::Zero;
 use poly_ring_xnp1::Polynomial;
-use rand::RngExt;
-use rand_distr::uniform::SampleUniform;
+use rand::{rngs::ChaCha20Rng, RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     challenge_space::random_polynomial_from_challenge_set,
     commit::{Commitment, CommitmentKey, Opening},
+    error::VerifyError,
+    integer::RingInteger,
     mat::Mat,
     params::Params,
-    polynomial::random_polynomial_in_normal_distribution,
+    sampler::{DiscreteGaussianSampler, GaussianSampler},
+    transcript::{mat_to_bytes, polynomials_to_bytes},
 };
 
 /// The prover for the proof of sum. It is used to prove that the prover knows the
 /// opening of commitments to `x'` and a vector of `x_i` such that `x' = g_1 * x_1 + g_2 * x_2 + ...`,
 /// where `g_i` are scalars.
-pub struct SumProofProver<I, const N: usize>
+pub struct SumProofProver<I, const N: usize, S = DiscreteGaussianSampler>
 where
     I: Zero,
 {
     params: Params<I>,
     ck: CommitmentKey<I, N>,
+    sampler: S,
 }
 
 impl<I, const N: usize> SumProofProver<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
-    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+    I: Zero,
 {
     pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
-        Self { params, ck }
+        Self::with_sampler(ck, params, DiscreteGaussianSampler)
+    }
+}
+
+impl<I, const N: usize, S> SumProofProver<I, N, S>
+where
+    I: Zero,
+{
+    /// Create the prover which draws the masking polynomials through `sampler`, instead of the default
+    /// [DiscreteGaussianSampler]. See [crate::sampler].
+    pub fn with_sampler(ck: CommitmentKey<I, N>, params: Params<I>, sampler: S) -> Self {
+        Self {
+            params,
+            ck,
+            sampler,
+        }
     }
+}
 
+impl<I, const N: usize, S> SumProofProver<I, N, S>
+where
+    S: GaussianSampler,
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
+{
     /// Create commitments to `x'` and a vector (`xs`) of `x_i` such that `x' = g_1 * x_1 + g_2 * x_2 + ...`,
     /// where `g_i` are scalars (`gs`).
     /// It returns the response context and the commitment. The response context is used to create
     /// the response in a later phase of the protocol. Note that the context includes the openings
     /// of commitments to `x'` and the vector of `x_i`.
     ///
+    /// ## Safety
+    /// `rng` must be a cryptographically secure random number generator (e.g. `rand::rng()`), as the
+    /// randomness of the commitments and the masking polynomials hide the committed values. See [crate::rng].
+    ///
     /// ## Panics
     /// Panics if
     /// - the length of `gs` is not equal to the length of `xs`.
@@ -109,7 +138,7 @@ where
             .cloned()
             .map(Mat::<I, N>::from_vec)
             .zip(gs.iter())
-            .map(|(x, g)| x.componentwise_mul(g))
+            .map(|(x, g)| x.scalar_mul(g))
             .reduce(|acc, x| acc.add(&x))
             .unwrap()
             .one_d_mat_to_vec();
@@ -123,22 +152,16 @@ where
         let ys = (0..gs.len())
             .map(|_| {
                 Mat::<I, N>::new_with(self.params.k, 1, || {
-                    random_polynomial_in_normal_distribution::<I, N>(
-                        rng,
-                        I::zero().to_f64().unwrap(),
-                        self.params.standard_deviation(N) as f64,
-                    )
+                    self.sampler
+                        .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
                 })
             })
             .collect::<Vec<_>>();
 
         // yp <- N^k_sigma
         let yp = Mat::<I, N>::new_with(self.params.k, 1, || {
-            random_polynomial_in_normal_distribution::<I, N>(
-                rng,
-                I::zero().to_f64().unwrap(),
-                self.params.standard_deviation(N) as f64,
-            )
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
         });
 
         // t = A1 * y for each y_i
@@ -154,7 +177,7 @@ where
         let u = gs
             .iter()
             .zip(ys.iter())
-            .map(|(g, y)| self.ck.a2.dot(y).componentwise_mul(g))
+            .map(|(g, y)| self.ck.a2.dot(y).scalar_mul(g))
             .reduce(|acc, x| acc.add(&x))
             .unwrap()
             .sub(&self.ck.a2.dot(&yp));
@@ -177,6 +200,281 @@ where
         )
     }
 
+    /// Create commitments to `x'` and a vector (`xs`) of `x_i` such that `x' = g_1 * x_1 + g_2 * x_2 + ...`
+    /// as [SumProofProver::commit] does, with the randomness derived from the 32-byte `seed`.
+    ///
+    /// The randomness is drawn from [ChaCha20Rng] seeded by `seed`, so the same seed and inputs always produce
+    /// the same commitments, including `t_i`, `tp` and `u`. It is intended for reproducible test vectors. The
+    /// seed must be secret and never reused, as the masking polynomials derived from it hide the openings.
+    ///
+    /// ## Panics
+    /// Panics in the same cases as [SumProofProver::commit].
+    pub fn commit_with_seed(
+        &self,
+        seed: [u8; 32],
+        gs: Vec<Polynomial<I, N>>,
+        xs: Vec<Vec<Polynomial<I, N>>>,
+    ) -> (SumProofResponseContext<I, N>, SumProofCommitment<I, N>) {
+        self.commit(&mut ChaCha20Rng::from_seed(seed), gs, xs)
+    }
+
+    /// Create commitments to `x'` and a vector (`xs`) of `x_i` such that `x' = g_1 * x_1 + g_2 * x_2 + ...`
+    /// as [SumProofProver::commit] does, with the randomness from [crate::rng::secure_rng], i.e. a CSPRNG
+    /// seeded by the operating system.
+    ///
+    /// ## Panics
+    /// Panics in the same cases as [SumProofProver::commit], or if the random number generator of the
+    /// operating system fails.
+    #[cfg(feature = "os-rng")]
+    pub fn commit_secure(
+        &self,
+        gs: Vec<Polynomial<I, N>>,
+        xs: Vec<Vec<Polynomial<I, N>>>,
+    ) -> (SumProofResponseContext<I, N>, SumProofCommitment<I, N>) {
+        self.commit(&mut crate::rng::secure_rng(), gs, xs)
+    }
+
+    /// Create commitments to `x'` and a vector (`xs`) of `x_i` such that
+    /// `x' = g_1 * x_1 + .. + g_m * x_m + s_1 * x_(m+1) + .. + s_n * x_(m+n)`, where `g_i` are public scalars
+    /// (`public_terms`) and `s_j` are private scalars (`private_terms`). The first `m` values of `xs` are
+    /// multiplied by the public scalars, and the rest by the private scalars in order.
+    ///
+    /// The private scalars are committed as well (each one in every slot of a message). The verifier
+    /// equation then contains the public-linear part and the product part `s_j * x_(m+j)` of degree 2 in
+    /// the committed values, which is handled by a commitment to the cross terms (the "garbage") as in the
+    /// proof of quadratic relations by Attema, Lyubashevsky and Seiler (2020/517).
+    ///
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response (by [SumProofProver::create_response_mixed]) in a later phase of the protocol. Note
+    /// that the context includes the openings of commitments to `x'`, `x_i` and `s_j`.
+    ///
+    /// ## Panics
+    /// Panics if
+    /// - the total length of `public_terms` and `private_terms` is not equal to the length of `xs`.
+    /// - `xs` is empty.
+    /// - the length of `x_i` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit_mixed(
+        &self,
+        rng: &mut impl RngExt,
+        public_terms: Vec<Polynomial<I, N>>,
+        private_terms: Vec<Polynomial<I, N>>,
+        xs: Vec<Vec<Polynomial<I, N>>>,
+    ) -> (
+        MixedSumProofResponseContext<I, N>,
+        MixedSumProofCommitment<I, N>,
+    ) {
+        let num_public = public_terms.len();
+        assert!(!xs.is_empty() && num_public + private_terms.len() == xs.len());
+        let x_mats = xs
+            .iter()
+            .cloned()
+            .map(Mat::<I, N>::from_vec)
+            .collect::<Vec<_>>();
+
+        // xp = g_1 * x_1 + .. + s_1 * x_(m+1) + ..
+        let xp = public_terms
+            .iter()
+            .chain(private_terms.iter())
+            .zip(x_mats.iter())
+            .map(|(g, x)| x.scalar_mul(g))
+            .reduce(|acc, x| acc.add(&x))
+            .unwrap()
+            .one_d_mat_to_vec();
+        let (opening_p, cp) = self.ck.commit(rng, xp, &self.params);
+        let (openings, cs) = xs
+            .into_iter()
+            .map(|x| self.ck.commit(rng, x, &self.params))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let (openings_s, css) = private_terms
+            .iter()
+            .map(|s| {
+                self.ck
+                    .commit(rng, vec![s.clone(); self.params.l], &self.params)
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        // masks for x', x_i and s_j
+        let yp = self.mask(rng);
+        let ys = (0..cs.len()).map(|_| self.mask(rng)).collect::<Vec<_>>();
+        let yss = (0..css.len()).map(|_| self.mask(rng)).collect::<Vec<_>>();
+
+        // a = A2 * y for each mask
+        let a_p = self.ck.a2.dot(&yp);
+        let a_xs = ys.iter().map(|y| self.ck.a2.dot(y)).collect::<Vec<_>>();
+        let a_ss = yss.iter().map(|y| self.ck.a2.dot(y)).collect::<Vec<_>>();
+
+        // v0 = sum of a_s_j * a_x_(m+j)
+        let zero = Mat::<I, N>::from_element(self.params.l, 1, Polynomial::zero());
+        let v0 = a_ss
+            .iter()
+            .zip(a_xs.iter().skip(num_public))
+            .fold(zero, |acc, (a_s, a_x)| acc.add(&a_s.hadamard(a_x)));
+
+        // v1 = a_p - sum of g_i * a_x_i - sum of (s_j * a_x_(m+j) + x_(m+j) * a_s_j)
+        let v1 = public_terms
+            .iter()
+            .zip(a_xs.iter())
+            .fold(a_p, |acc, (g, a_x)| acc.sub(&a_x.scalar_mul(g)));
+        let v1 = private_terms
+            .iter()
+            .zip(a_xs.iter().skip(num_public))
+            .zip(x_mats.iter().skip(num_public))
+            .zip(a_ss.iter())
+            .fold(v1, |acc, (((s, a_x), x), a_s)| {
+                acc.sub(&a_x.scalar_mul(s)).sub(&x.hadamard(a_s))
+            });
+        let (opening_v, cv) = self.ck.commit(rng, v1.one_d_mat_to_vec(), &self.params);
+
+        // v = v0 + A2 * yv
+        let yv = self.mask(rng);
+        let v = v0.add(&self.ck.a2.dot(&yv));
+
+        // t = A1 * y for each mask
+        let tp = self.ck.a1.dot(&yp).one_d_mat_to_vec();
+        let ts = ys
+            .iter()
+            .map(|y| self.ck.a1.dot(y).one_d_mat_to_vec())
+            .collect();
+        let tss = yss
+            .iter()
+            .map(|y| self.ck.a1.dot(y).one_d_mat_to_vec())
+            .collect();
+        let tv = self.ck.a1.dot(&yv).one_d_mat_to_vec();
+
+        (
+            MixedSumProofResponseContext {
+                openings,
+                openings_s,
+                opening_p,
+                opening_v,
+                yp,
+                ys,
+                yss,
+                yv,
+            },
+            MixedSumProofCommitment {
+                cp,
+                cs,
+                css,
+                gs: public_terms,
+                cv,
+                tp,
+                ts,
+                tss,
+                tv,
+                v,
+            },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier, in the protocol started by
+    /// [SumProofProver::commit_mixed].
+    pub fn create_response_mixed(
+        &self,
+        context: MixedSumProofResponseContext<I, N>,
+        challenge: SumProofChallenge<I, N>,
+    ) -> MixedSumProofResponse<I, N> {
+        // z = y + d * r for each mask
+        let respond =
+            |y: &Mat<I, N>, opening: &Opening<I, N>| y.add(&opening.r.scalar_mul(&challenge.d));
+        MixedSumProofResponse {
+            zp: respond(&context.yp, &context.opening_p),
+            zs: context
+                .ys
+                .iter()
+                .zip(context.openings.iter())
+                .map(|(y, opening)| respond(y, opening))
+                .collect(),
+            zss: context
+                .yss
+                .iter()
+                .zip(context.openings_s.iter())
+                .map(|(y, opening)| respond(y, opening))
+                .collect(),
+            zv: respond(&context.yv, &context.opening_v),
+        }
+    }
+
+    /// Create commitments to a vector (`xs`) of `x_i` for proving `g_1 * x_1 + g_2 * x_2 + ... = t`, where
+    /// `g_i` are scalars (`gs`) and `t` is a public target known to the verifier (the `sum_to_public` mode).
+    ///
+    /// Unlike [SumProofProver::commit], the right-hand side is not committed. The verifier folds the target
+    /// into the verification equation instead (see [SumProofVerifier::verify_sum_to_public]), so the proof
+    /// does not contain the commitment `c'`, its masking commitment `t'` and its response `z'`, i.e. it is
+    /// smaller by one commitment, `n` polynomials of `t'` and `k` polynomials of `z'`.
+    ///
+    /// It returns the response context and the commitment. The response context is used to create
+    /// the response (by [SumProofProver::create_response_sum_to_public]) in a later phase of the protocol.
+    /// Note that the context includes the openings of commitments to the vector of `x_i`.
+    ///
+    /// ## Panics
+    /// Panics if
+    /// - the length of `gs` is not equal to the length of `xs`.
+    /// - `gs` is empty.
+    /// - the length of `x_i` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn commit_sum_to_public(
+        &self,
+        rng: &mut impl RngExt,
+        gs: Vec<Polynomial<I, N>>,
+        xs: Vec<Vec<Polynomial<I, N>>>,
+    ) -> (
+        PublicSumProofResponseContext<I, N>,
+        PublicSumProofCommitment<I, N>,
+    ) {
+        assert!(!gs.is_empty() && gs.len() == xs.len());
+        let (openings, cs) = xs
+            .into_iter()
+            .map(|x| self.ck.commit(rng, x, &self.params))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        // y <- N^k_sigma for y_i
+        let ys = (0..gs.len()).map(|_| self.mask(rng)).collect::<Vec<_>>();
+
+        // t = A1 * y for each y_i
+        let ts = ys
+            .iter()
+            .map(|y| self.ck.a1.dot(y).one_d_mat_to_vec())
+            .collect::<Vec<_>>();
+
+        // u = g_0 * A2 * y_0 + g_1 * A2 * y_1 + ...
+        let u = gs
+            .iter()
+            .zip(ys.iter())
+            .map(|(g, y)| self.ck.a2.dot(y).scalar_mul(g))
+            .reduce(|acc, x| acc.add(&x))
+            .unwrap();
+
+        (
+            PublicSumProofResponseContext { openings, ys },
+            PublicSumProofCommitment { cs, gs, ts, u },
+        )
+    }
+
+    /// Create the response for the challenge received from the verifier, in the protocol started by
+    /// [SumProofProver::commit_sum_to_public].
+    pub fn create_response_sum_to_public(
+        &self,
+        context: PublicSumProofResponseContext<I, N>,
+        challenge: SumProofChallenge<I, N>,
+    ) -> PublicSumProofResponse<I, N> {
+        // z = y + d * r for each y_i
+        let zs = context
+            .ys
+            .iter()
+            .zip(context.openings.iter())
+            .map(|(y, opening)| y.add(&opening.r.scalar_mul(&challenge.d)))
+            .collect();
+        PublicSumProofResponse { zs }
+    }
+
+    /// Sample a masking polynomial vector y <- N^k_sigma.
+    fn mask(&self, rng: &mut impl RngExt) -> Mat<I, N> {
+        Mat::<I, N>::new_with(self.params.k, 1, || {
+            self.sampler
+                .sample_polynomial::<I, N>(rng, self.params.sigma_exact::<N>())
+        })
+    }
+
     /// Create the response for the challenge received from the verifier. The response is created
     /// using the context that was created during the commitment phase.
     pub fn create_response(
@@ -189,12 +487,12 @@ where
             .ys
             .iter()
             .zip(context.openings.iter())
-            .map(|(y, opening)| y.add(&opening.r.componentwise_mul(&challenge.d)))
+            .map(|(y, opening)| y.add(&opening.r.scalar_mul(&challenge.d)))
             .collect::<Vec<_>>();
         // zp = yp + d * rp
         let zp = context
             .yp
-            .add(&context.opening_p.r.componentwise_mul(&challenge.d));
+            .add(&context.opening_p.r.scalar_mul(&challenge.d));
 
         SumProofResponse { zs, zp }
     }
@@ -213,7 +511,7 @@ where
 
 impl<I, const N: usize> SumProofVerifier<I, N>
 where
-    I: Clone + PartialOrd + Ord + One + Zero + FromPrimitive + ToPrimitive + SampleUniform,
+    I: RingInteger,
     for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Neg<Output = I> + Sub<Output = I>,
 {
     pub fn new(ck: CommitmentKey<I, N>, params: Params<I>) -> Self {
@@ -259,19 +557,42 @@ where
         response: SumProofResponse<I, N>,
         context: SumProofVerificationContext<I, N>,
     ) -> bool {
+        self.verify_detailed(response, context).is_ok()
+    }
+
+    /// Verify the response from the prover as [SumProofVerifier::verify] does, but tell the reason
+    /// if the response is invalid.
+    ///
+    /// ## Errors
+    /// - [VerifyError::NormConstraintViolated] if a response exceeds the norm bound.
+    /// - [VerifyError::LengthMismatch] if the number of responses is not equal to the number of commitments.
+    /// - [VerifyError::FirstEquationMismatch] if `A1 * z_i = t_i + c1_i * d` or `A1 * z' = t' + c1' * d`
+    ///   does not hold.
+    /// - [VerifyError::SecondEquationMismatch] if the sum relation on `A2` does not hold.
+    pub fn verify_detailed(
+        &self,
+        response: SumProofResponse<I, N>,
+        context: SumProofVerificationContext<I, N>,
+    ) -> Result<(), VerifyError> {
         if !response
             .zs
             .iter()
             .all(|z| self.params.check_verify_constraint(z))
         {
-            return false;
+            return Err(VerifyError::NormConstraintViolated);
         }
         if !self.params.check_verify_constraint(&response.zp) {
-            return false;
+            return Err(VerifyError::NormConstraintViolated);
         }
         // check lengths
-        if response.zs.len() != context.ts.len() && response.zs.len() != context.cs.len() {
-            return false;
+        if response.zs.len() != context.ts.len()
+            || response.zs.len() != context.cs.len()
+            || response.zs.len() != context.gs.len()
+        {
+            return Err(VerifyError::LengthMismatch {
+                expected: context.cs.len(),
+                got: response.zs.len(),
+            });
         }
 
         // A1 * z = t + c1 * d for each z_i
@@ -284,17 +605,17 @@ where
             .cs
             .iter()
             .zip(context.ts)
-            .map(|((c1, _), t)| Mat::<I, N>::from_vec(t).add(&c1.componentwise_mul(&context.d)))
+            .map(|((c1, _), t)| Mat::<I, N>::from_vec(t).add(&c1.scalar_mul(&context.d)))
             .collect::<Vec<_>>();
         if lhs != rhs {
-            return false;
+            return Err(VerifyError::FirstEquationMismatch);
         }
 
         // A1 * zp = tp + c1p * d
         let lhs = self.ck.a1.dot(&response.zp);
-        let rhs = Mat::<I, N>::from_vec(context.tp).add(&context.c1p.componentwise_mul(&context.d));
+        let rhs = Mat::<I, N>::from_vec(context.tp).add(&context.c1p.scalar_mul(&context.d));
         if lhs != rhs {
-            return false;
+            return Err(VerifyError::FirstEquationMismatch);
         }
 
         // g_0 * A2 * z_0 + g_1 * A2 * z_1 + ... - A2 * zp = (g_0 * c2_0 + g_1 * c2_1 + ... - c2p) * d + u
@@ -302,7 +623,7 @@ where
             .zs
             .iter()
             .zip(context.gs.iter())
-            .map(|(z, g)| self.ck.a2.dot(z).componentwise_mul(g))
+            .map(|(z, g)| self.ck.a2.dot(z).scalar_mul(g))
             .reduce(|acc, x| acc.add(&x))
             .unwrap()
             .sub(&self.ck.a2.dot(&response.zp));
@@ -310,12 +631,298 @@ where
             .cs
             .iter()
             .zip(context.gs.iter())
-            .map(|((_, c2), g)| c2.componentwise_mul(g))
+            .map(|((_, c2), g)| c2.scalar_mul(g))
             .reduce(|acc, x| acc.add(&x))
             .unwrap()
             .sub(&context.c2p)
-            .componentwise_mul(&context.d)
+            .scalar_mul(&context.d)
             .add(&context.u);
+        if lhs != rhs {
+            return Err(VerifyError::SecondEquationMismatch);
+        }
+        Ok(())
+    }
+
+    /// Verify the responses from multiple provers in a batch. It returns `true` only if all the responses
+    /// are valid, otherwise `false`.
+    ///
+    /// As [OpenProofVerifier::verify_batch](crate::OpenProofVerifier::verify_batch), the equations of all the
+    /// proofs are folded into two equations by fresh random integer weights in `[-q, q]`, so that `A1` and
+    /// `A2` are multiplied only once:
+    /// - `A1 * (w_1 * z_1 + ..) = w_1 * (t_1 + c1_1 * d) + ..` over all `z_i` and `z'` of all the proofs, and
+    /// - `A2 * (v * (g_1 * z_1 + g_2 * z_2 + .. - z') + ..) = v * ((g_1 * c2_1 + .. - c2') * d + u) + ..`
+    ///   with a weight `v` per proof, where the scalars `g_i` are multiplied to the responses before `A2`.
+    ///
+    /// If any of the equations does not hold, the combined one holds with probability at most `1/q`.
+    pub fn verify_many(
+        &self,
+        rng: &mut impl RngExt,
+        items: &[(SumProofResponse<I, N>, SumProofVerificationContext<I, N>)],
+    ) -> bool {
+        let Params { n, k, l, .. } = self.params;
+        let zero = Polynomial::<I, N>::zero();
+        let mut lhs1 = Mat::<I, N>::from_element(k, 1, zero.clone());
+        let mut rhs1 = Mat::<I, N>::from_element(n, 1, zero.clone());
+        let mut lhs2 = Mat::<I, N>::from_element(k, 1, zero.clone());
+        let mut rhs2 = Mat::<I, N>::from_element(l, 1, zero);
+        let bound = self.params.q.clone();
+        let mut weight = || rng.random_range(-&bound..=bound.clone());
+
+        for (response, context) in items {
+            if !response
+                .zs
+                .iter()
+                .chain([&response.zp])
+                .all(|z| z.dim() == (k, 1) && self.params.check_verify_constraint(z))
+            {
+                return false;
+            }
+            let m = response.zs.len();
+            if context.ts.len() != m || context.cs.len() != m || context.gs.len() != m {
+                return false;
+            }
+            if context.ts.iter().chain([&context.tp]).any(|t| t.len() != n)
+                || context
+                    .cs
+                    .iter()
+                    .chain([&(context.c1p.clone(), context.c2p.clone())])
+                    .any(|(c1, c2)| c1.dim() != (n, 1) || c2.dim() != (l, 1))
+                || context.u.dim() != (l, 1)
+            {
+                return false;
+            }
+
+            // w * z and w * (t + c1 * d) for each z_i and z'
+            let firsts = response
+                .zs
+                .iter()
+                .zip(context.ts.iter().zip(context.cs.iter().map(|(c1, _)| c1)))
+                .chain([(&response.zp, (&context.tp, &context.c1p))]);
+            for (z, (t, c1)) in firsts {
+                let w = weight();
+                lhs1 = lhs1.add(&z.scale(&w));
+                let t_i = Mat::<I, N>::from_vec(t.clone()).add(&c1.scalar_mul(&context.d));
+                rhs1 = rhs1.add(&t_i.scale(&w));
+            }
+
+            // v * (g_1 * z_1 + .. - z') and v * ((g_1 * c2_1 + .. - c2') * d + u)
+            let gz = response
+                .zs
+                .iter()
+                .zip(context.gs.iter())
+                .fold(response.zp.neg(), |acc, (z, g)| acc.add(&z.scalar_mul(g)));
+            let gc2 = context
+                .cs
+                .iter()
+                .zip(context.gs.iter())
+                .fold(context.c2p.neg(), |acc, ((_, c2), g)| {
+                    acc.add(&c2.scalar_mul(g))
+                });
+            let v = weight();
+            lhs2 = lhs2.add(&gz.scale(&v));
+            rhs2 = rhs2.add(&gc2.scalar_mul(&context.d).add(&context.u).scale(&v));
+        }
+        self.ck.a1.dot(&lhs1) == rhs1 && self.ck.a2.dot(&lhs2) == rhs2
+    }
+
+    /// Generate the challenge for the prover, given the commitments created by [SumProofProver::commit_mixed].
+    /// It returns the verification context and the challenge. The verification context is used to
+    /// verify the response in a later phase of the protocol.
+    pub fn generate_challenge_mixed(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: MixedSumProofCommitment<I, N>,
+    ) -> (
+        MixedSumProofVerificationContext<I, N>,
+        SumProofChallenge<I, N>,
+    ) {
+        let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        (
+            MixedSumProofVerificationContext {
+                commitment,
+                d: d.clone(),
+            },
+            SumProofChallenge { d },
+        )
+    }
+
+    /// Verify the response from the prover in the protocol started by [SumProofProver::commit_mixed].
+    /// It returns `true` if the response is valid, otherwise `false`.
+    /// The context was created during the challenge phase in the protocol.
+    pub fn verify_mixed(
+        &self,
+        response: MixedSumProofResponse<I, N>,
+        context: MixedSumProofVerificationContext<I, N>,
+    ) -> bool {
+        let MixedSumProofVerificationContext { commitment, d } = context;
+        let num_public = commitment.gs.len();
+        // check lengths
+        if response.zs.len() != commitment.cs.len()
+            || commitment.ts.len() != commitment.cs.len()
+            || response.zss.len() != commitment.css.len()
+            || commitment.tss.len() != commitment.css.len()
+            || num_public + commitment.css.len() != commitment.cs.len()
+            || commitment.v.dim() != (self.params.l, 1)
+        {
+            return false;
+        }
+
+        let zs = core::iter::once(&response.zp)
+            .chain(response.zs.iter())
+            .chain(response.zss.iter())
+            .chain(core::iter::once(&response.zv))
+            .collect::<Vec<_>>();
+        if !zs.iter().all(|z| self.params.check_verify_constraint(z)) {
+            return false;
+        }
+        let cs = core::iter::once(&commitment.cp)
+            .chain(commitment.cs.iter())
+            .chain(commitment.css.iter())
+            .chain(core::iter::once(&commitment.cv))
+            .map(|c| c.c1_c2(&self.params))
+            .collect::<Vec<_>>();
+        let ts = core::iter::once(&commitment.tp)
+            .chain(commitment.ts.iter())
+            .chain(commitment.tss.iter())
+            .chain(core::iter::once(&commitment.tv));
+
+        // A1 * z = t + c1 * d for each commitment
+        if !zs.iter().zip(cs.iter()).zip(ts).all(|((z, (c1, _)), t)| {
+            self.ck.a1.dot(z) == Mat::<I, N>::from_vec(t.clone()).add(&c1.scalar_mul(&d))
+        }) {
+            return false;
+        }
+
+        // f = A2 * z - c2 * d for each commitment
+        let fs = zs
+            .iter()
+            .zip(cs.iter())
+            .map(|(z, (_, c2))| self.ck.a2.dot(z).sub(&c2.scalar_mul(&d)))
+            .collect::<Vec<_>>();
+        let (f_p, fs) = fs.split_first().unwrap();
+        let (f_v, fs) = fs.split_last().unwrap();
+        let (f_xs, f_ss) = fs.split_at(commitment.cs.len());
+
+        // sum of f_s_j * f_x_(m+j) - d * sum of g_i * f_x_i + d * f_p + f_v = v
+        let public_part = commitment
+            .gs
+            .iter()
+            .zip(f_xs.iter())
+            .fold(f_p.clone(), |acc, (g, f_x)| acc.sub(&f_x.scalar_mul(g)))
+            .scalar_mul(&d);
+        let lhs = f_ss
+            .iter()
+            .zip(f_xs.iter().skip(num_public))
+            .fold(public_part, |acc, (f_s, f_x)| acc.add(&f_s.hadamard(f_x)))
+            .add(f_v);
+        lhs == commitment.v
+    }
+
+    /// Generate the challenge for the prover, given the commitments created by
+    /// [SumProofProver::commit_sum_to_public] and the public target `t` (the `sum_to_public` mode).
+    /// The target is given by the verifier, not by the prover, so that the proof is checked against the
+    /// expected value. It returns the verification context and the challenge.
+    ///
+    /// ## Panics
+    /// Panics if the length of `target` is not equal to the length of `l` defined in the `Params` struct.
+    pub fn generate_challenge_sum_to_public(
+        &self,
+        rng: &mut impl RngExt,
+        commitment: PublicSumProofCommitment<I, N>,
+        target: Vec<Polynomial<I, N>>,
+    ) -> (
+        PublicSumProofVerificationContext<I, N>,
+        SumProofChallenge<I, N>,
+    ) {
+        assert_eq!(target.len(), self.params.l);
+        let d = random_polynomial_from_challenge_set(rng, self.params.kappa);
+        let cs = commitment
+            .cs
+            .iter()
+            .map(|c| c.c1_c2(&self.params))
+            .collect();
+        (
+            PublicSumProofVerificationContext {
+                cs,
+                gs: commitment.gs,
+                ts: commitment.ts,
+                u: commitment.u,
+                target: Mat::from_vec(target),
+                d: d.clone(),
+            },
+            SumProofChallenge { d },
+        )
+    }
+
+    /// Verify the response from the prover in the protocol started by [SumProofProver::commit_sum_to_public].
+    /// It returns `true` if the response is valid, otherwise `false`.
+    ///
+    /// The relation `g_1 * x_1 + g_2 * x_2 + ... = t` is checked by the equations
+    /// - `A1 * z_i = t_i + c1_i * d` for each `z_i`, and
+    /// - `g_1 * A2 * z_1 + g_2 * A2 * z_2 + ... = (g_1 * c2_1 + g_2 * c2_2 + ... - t) * d + u`,
+    ///
+    /// where the public target `t` takes the place of the commitment `c2'` in [SumProofVerifier::verify].
+    /// If the weighted sum of the committed values differs from `t`, the second equation does not hold.
+    pub fn verify_sum_to_public(
+        &self,
+        response: PublicSumProofResponse<I, N>,
+        context: PublicSumProofVerificationContext<I, N>,
+    ) -> bool {
+        let PublicSumProofVerificationContext {
+            cs,
+            gs,
+            ts,
+            u,
+            target,
+            d,
+        } = context;
+        // check lengths
+        if gs.is_empty()
+            || response.zs.len() != cs.len()
+            || ts.len() != cs.len()
+            || gs.len() != cs.len()
+            || u.dim() != (self.params.l, 1)
+        {
+            return false;
+        }
+        if !response
+            .zs
+            .iter()
+            .all(|z| self.params.check_verify_constraint(z))
+        {
+            return false;
+        }
+
+        // A1 * z = t + c1 * d for each z_i
+        if !response
+            .zs
+            .iter()
+            .zip(cs.iter())
+            .zip(ts)
+            .all(|((z, (c1, _)), t)| {
+                self.ck.a1.dot(z) == Mat::<I, N>::from_vec(t).add(&c1.scalar_mul(&d))
+            })
+        {
+            return false;
+        }
+
+        // g_0 * A2 * z_0 + g_1 * A2 * z_1 + ... = (g_0 * c2_0 + g_1 * c2_1 + ... - t) * d + u
+        let lhs = response
+            .zs
+            .iter()
+            .zip(gs.iter())
+            .map(|(z, g)| self.ck.a2.dot(z).scalar_mul(g))
+            .reduce(|acc, x| acc.add(&x))
+            .unwrap();
+        let rhs = cs
+            .iter()
+            .zip(gs.iter())
+            .map(|((_, c2), g)| c2.scalar_mul(g))
+            .reduce(|acc, x| acc.add(&x))
+            .unwrap()
+            .sub(&target)
+            .scalar_mul(&d)
+            .add(&u);
         lhs == rhs
     }
 }
@@ -337,6 +944,23 @@ where
     ys: Vec<Mat<I, N>>, // vector of k x 1 matrices
 }
 
+impl<I, const N: usize> SumProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `yp` (`k x 1` matrix) for the commitment to `x'`, s.t. the
+    /// response is `z' = y' + d * r'`. It must be kept secret as the opening.
+    pub fn yp(&self) -> &Mat<I, N> {
+        &self.yp
+    }
+
+    /// The masking polynomials `y_i` (`k x 1` matrices) for the commitments to `x_i`, s.t. the responses are
+    /// `z_i = y_i + d * r_i`. They must be kept secret as the openings.
+    pub fn ys(&self) -> &[Mat<I, N>] {
+        &self.ys
+    }
+}
+
 /// Contains the commitments to the values `x'` and `x_i` such that `x' = g_0 * x_0 + g_1 * x_1 + ..`
 /// where `g_i` are scalars, used in the proof of sum.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -354,6 +978,32 @@ where
     u: Mat<I, N>,                   // l x 1 matrix
 }
 
+impl<I, const N: usize> SumProofCommitment<I, N>
+where
+    I: RingInteger,
+    for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+{
+    /// Returns the canonical byte encoding of the public statement, i.e. the parameters, the commitments
+    /// `c'` and `c_i`, the scalars `g_i`, `t'`, `t_i` and `u`, which are the bytes to be absorbed by a
+    /// Fiat-Shamir transcript for deriving the challenge.
+    pub fn statement_bytes(&self, params: &Params<I>) -> Vec<u8> {
+        let mut bytes = params.statement_bytes::<N>();
+        bytes.extend(mat_to_bytes(&self.cp.c));
+        bytes.extend((self.cs.len() as u64).to_le_bytes());
+        self.cs
+            .iter()
+            .for_each(|c| bytes.extend(mat_to_bytes(&c.c)));
+        bytes.extend(polynomials_to_bytes(&self.gs));
+        bytes.extend(polynomials_to_bytes(&self.tp));
+        bytes.extend((self.ts.len() as u64).to_le_bytes());
+        self.ts
+            .iter()
+            .for_each(|t| bytes.extend(polynomials_to_bytes(t)));
+        bytes.extend(mat_to_bytes(&self.u));
+        bytes
+    }
+}
+
 /// Contains the context for the verification phase of the proof of sum.
 /// It is used to verify the response from the prover.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -371,6 +1021,16 @@ where
     d: Polynomial<I, N>,
 }
 
+impl<I, const N: usize> SumProofVerificationContext<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` sent to the prover, from which the context was created.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
 /// The challenge created by the verifier in the protocol of proof of sum.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SumProofChallenge<I, const N: usize>
@@ -380,6 +1040,16 @@ where
     d: Polynomial<I, N>,
 }
 
+impl<I, const N: usize> SumProofChallenge<I, N>
+where
+    I: Zero,
+{
+    /// The challenge polynomial `d` in Challenge Space C.
+    pub fn challenge(&self) -> &Polynomial<I, N> {
+        &self.d
+    }
+}
+
 /// The response from the prover to the verifier in the protocol of proof of sum.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SumProofResponse<I, const N: usize>
@@ -389,3 +1059,130 @@ where
     zp: Mat<I, N>,      // k x 1 matrix
     zs: Vec<Mat<I, N>>, // vector of k x 1 matrices
 }
+
+/// The response context created by [SumProofProver::commit_mixed], for the proof of sum with public and
+/// private scalars. It contains the openings of commitments to `x'`, `x_i` and the private scalars `s_j`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedSumProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// vector of openings of x_i
+    pub openings: Vec<Opening<I, N>>,
+    /// vector of openings of the private scalars s_j
+    pub openings_s: Vec<Opening<I, N>>,
+    /// opening of x'
+    pub opening_p: Opening<I, N>,
+    opening_v: Opening<I, N>, // opening of the cross terms
+    yp: Mat<I, N>,            // k x 1 matrix
+    ys: Vec<Mat<I, N>>,       // vector of k x 1 matrices
+    yss: Vec<Mat<I, N>>,      // vector of k x 1 matrices
+    yv: Mat<I, N>,            // k x 1 matrix
+}
+
+/// Contains the commitments to the values `x'`, `x_i` and the private scalars `s_j` such that
+/// `x' = g_1 * x_1 + .. + g_m * x_m + s_1 * x_(m+1) + ..`, used in the proof of sum with public and
+/// private scalars.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedSumProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// commitment to x'
+    pub cp: Commitment<I, N>,
+    /// commitments to x_i
+    pub cs: Vec<Commitment<I, N>>,
+    /// commitments to the private scalars s_j
+    pub css: Vec<Commitment<I, N>>,
+    /// the public scalars g_i
+    pub gs: Vec<Polynomial<I, N>>,
+    cv: Commitment<I, N>,            // commitment to the cross terms
+    tp: Vec<Polynomial<I, N>>,       // n x 1 matrix
+    ts: Vec<Vec<Polynomial<I, N>>>,  // vector of n x 1 matrices
+    tss: Vec<Vec<Polynomial<I, N>>>, // vector of n x 1 matrices
+    tv: Vec<Polynomial<I, N>>,       // n x 1 matrix
+    v: Mat<I, N>,                    // l x 1 matrix
+}
+
+/// Contains the context for the verification phase of the proof of sum with public and private scalars.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedSumProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    commitment: MixedSumProofCommitment<I, N>,
+    d: Polynomial<I, N>,
+}
+
+/// The response from the prover to the verifier in the protocol of proof of sum with public and
+/// private scalars.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedSumProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    zp: Mat<I, N>,       // k x 1 matrix
+    zs: Vec<Mat<I, N>>,  // vector of k x 1 matrices
+    zss: Vec<Mat<I, N>>, // vector of k x 1 matrices
+    zv: Mat<I, N>,       // k x 1 matrix
+}
+
+/// The response context created by [SumProofProver::commit_sum_to_public], for the proof that the weighted
+/// sum of the committed values equals a public target. It contains the openings of commitments to `x_i`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicSumProofResponseContext<I, const N: usize>
+where
+    I: Zero,
+{
+    /// vector of openings of x_i where g_0 * x_0 + g_1 * x_1 + .. = t
+    pub openings: Vec<Opening<I, N>>,
+    ys: Vec<Mat<I, N>>, // vector of k x 1 matrices
+}
+
+impl<I, const N: usize> PublicSumProofResponseContext<I, N>
+where
+    I: Zero,
+{
+    /// The masking polynomials `y_i` (`k x 1` matrices) for the commitments to `x_i`, s.t. the responses are
+    /// `z_i = y_i + d * r_i`. They must be kept secret as the openings.
+    pub fn ys(&self) -> &[Mat<I, N>] {
+        &self.ys
+    }
+}
+
+/// Contains the commitments to the values `x_i` such that `g_0 * x_0 + g_1 * x_1 + .. = t` for a public
+/// target `t`, used in the proof of sum to a public target.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicSumProofCommitment<I, const N: usize>
+where
+    I: Zero,
+{
+    /// commitments to x_i where g_0 * x_0 + g_1 * x_1 + .. = t
+    pub cs: Vec<Commitment<I, N>>,
+    gs: Vec<Polynomial<I, N>>,      // vector of scalar g_i
+    ts: Vec<Vec<Polynomial<I, N>>>, // vector of n x 1 matrices
+    u: Mat<I, N>,                   // l x 1 matrix
+}
+
+/// Contains the context for the verification phase of the proof of sum to a public target.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicSumProofVerificationContext<I, const N: usize>
+where
+    I: Zero,
+{
+    cs: Vec<(Mat<I, N>, Mat<I, N>)>, // vector of (n x 1, l x 1) matrices
+    gs: Vec<Polynomial<I, N>>,       // vector of scalar g_i
+    ts: Vec<Vec<Polynomial<I, N>>>,  // vector of n x 1 matrices
+    u: Mat<I, N>,                    // l x 1 matrix
+    target: Mat<I, N>,               // l x 1 matrix
+    d: Polynomial<I, N>,
+}
+
+/// The response from the prover to the verifier in the protocol of proof of sum to a public target.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicSumProofResponse<I, const N: usize>
+where
+    I: Zero,
+{
+    zs: Vec<Mat<I, N>>, // vector of k x 1 matrices
+}
@@ -0,0 +1,59 @@
+//! Cryptographically secure randomness from the operating system, enabled by the feature `os-rng`.
+//!
+//! The randomness `r` of the commitments and the masking polynomials `y` of the proofs hide the committed
+//! values, so they must be drawn from a cryptographically secure random number generator (CSPRNG). The
+//! methods taking `rng: &mut impl RngExt` accept any generator so that tests can use a deterministic one
+//! (e.g. `StdRng::seed_from_u64`), which must never be used with secret values. `rand::rng()` is a CSPRNG
+//! seeded by the operating system and is fine for the protocols.
+//!
+//! The helpers here (e.g. [Params::generate_commitment_key_secure](crate::Params::generate_commitment_key_secure)
+//! and [OpenProofProver::commit_secure](crate::OpenProofProver::commit_secure)) do not take a generator, but
+//! draw the randomness from [ChaCha20Rng] seeded by the operating system, so that the source of the
+//! randomness cannot be chosen wrongly.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{OpenProofProver, OpenProofVerifier, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key_secure::<N>();
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//!
+//! let prover = OpenProofProver::new(ck.clone(), params.clone());
+//! let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+//!
+//! let (response_ctx, commitment) = prover.commit_secure(x);
+//! let (verification_ctx, challenge) = verifier.generate_challenge(&mut ring_zk::rng::secure_rng(), commitment);
+//! let response = prover.create_response(response_ctx, challenge);
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use rand::{
+    rngs::{ChaCha20Rng, SysRng},
+    SeedableRng,
+};
+
+/// Returns a [ChaCha20Rng] seeded by the random number generator of the operating system.
+///
+/// ## Panics
+/// Panics if the random number generator of the operating system fails.
+pub fn secure_rng() -> ChaCha20Rng {
+    ChaCha20Rng::try_from_rng(&mut SysRng).expect("the operating system RNG failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngExt;
+
+    use super::*;
+
+    #[test]
+    fn test_secure_rng_is_not_repeated() {
+        let a: [u8; 32] = secure_rng().random();
+        let b: [u8; 32] = secure_rng().random();
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,172 @@
+//! Sampling of the masking polynomials from the discrete Gaussian distribution.
+//!
+//! The masking polynomials `y` of the proofs must follow the discrete Gaussian distribution `D_{Z, sigma}`
+//! over the integers, i.e. the probability of `x` is proportional to `exp(-x^2 / (2 * sigma^2))`, so that
+//! the responses `z = y + d * r` do not leak the randomness `r` (section 4.4 of the paper). Sampling the
+//! continuous Gaussian and converting the sample to an integer is not the same distribution: the conversion
+//! truncates towards zero, so `0` collects the samples in `(-1, 1)` and is twice as likely as it should be,
+//! and rounding (instead of truncating) still shifts the probabilities of all the integers by the width of
+//! the intervals. The difference from `D_{Z, sigma}` is not accounted for by the analysis of zero-knowledge.
+//!
+//! The provers draw the masking polynomials through a [GaussianSampler], which is [DiscreteGaussianSampler]
+//! by default (e.g. [OpenProofProver::new](crate::OpenProofProver::new)). Another sampler, e.g. a faster or
+//! constant-time one, is selected by the constructors `with_sampler` of the provers.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use ring_zk::{DiscreteGaussianSampler, OpenProofProver, OpenProofVerifier, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//!
+//! let prover = OpenProofProver::with_sampler(ck.clone(), params.clone(), DiscreteGaussianSampler);
+//! let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+//!
+//! let (response_ctx, commitment) = prover.commit(rng, x);
+//! let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+//! let response = prover.create_response(response_ctx, challenge);
+//! assert!(verifier.verify(response, verification_ctx));
+//! ```
+
+use num::{Float, FromPrimitive, One, Zero};
+use poly_ring_xnp1::Polynomial;
+use rand::RngExt;
+
+/// The samples of [DiscreteGaussianSampler] are in `[-TAIL_CUT * sigma, TAIL_CUT * sigma]`. The probability of
+/// a sample of `D_{Z, sigma}` outside of it is less than `2^-100`.
+const TAIL_CUT: f64 = 12.0;
+
+/// A sampler of the discrete Gaussian distribution `D_{Z, sigma}` centered at zero. See the
+/// [module](crate::sampler) for why the distribution must be discrete.
+pub trait GaussianSampler {
+    /// Sample an integer from `D_{Z, sigma}`.
+    fn sample(&self, rng: &mut impl RngExt, sigma: f64) -> i64;
+
+    /// Sample a polynomial whose `N` coefficients are independent samples of `D_{Z, sigma}`.
+    fn sample_polynomial<I, const N: usize>(
+        &self,
+        rng: &mut impl RngExt,
+        sigma: f64,
+    ) -> Polynomial<I, N>
+    where
+        I: Clone + One + Zero + FromPrimitive,
+    {
+        Polynomial::new(
+            (0..N)
+                .map(|_| I::from_i64(self.sample(rng, sigma)).unwrap())
+                .collect(),
+        )
+    }
+}
+
+/// The default [GaussianSampler], which samples `D_{Z, sigma}` exactly (up to the floating-point precision of
+/// the probabilities) by rejection: a candidate `x` is drawn uniformly from `[-12 * sigma, 12 * sigma]`, and
+/// accepted with the probability `exp(-x^2 / (2 * sigma^2))`. It takes about 10 candidates per sample.
+///
+/// The number of candidates depends on the randomness only, not on the accepted sample, but the sampler is
+/// not constant-time w.r.t. the floating-point operations.
+///
+/// ## Example
+///
+/// ```rust
+/// use ring_zk::{DiscreteGaussianSampler, GaussianSampler};
+///
+/// let rng = &mut rand::rng();
+/// let x = DiscreteGaussianSampler.sample(rng, 3.0);
+/// assert!(x.abs() <= 36);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiscreteGaussianSampler;
+
+impl GaussianSampler for DiscreteGaussianSampler {
+    /// ## Panics
+    /// Panics if `sigma` is not positive.
+    fn sample(&self, rng: &mut impl RngExt, sigma: f64) -> i64 {
+        assert!(sigma > 0.0, "sigma must be positive");
+        let bound = Float::ceil(TAIL_CUT * sigma) as i64;
+        let denominator = 2.0 * sigma * sigma;
+        loop {
+            let x = rng.random_range(-bound..=bound);
+            let p = Float::exp(-((x as f64) * (x as f64)) / denominator);
+            if rng.random::<f64>() < p {
+                return x;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use poly_ring_xnp1::zq::ZqI64;
+
+    use super::*;
+
+    #[test]
+    fn test_discrete_gaussian_pmf() {
+        let rng = &mut rand::rng();
+        let sigma = 2.0;
+        let samples = 200_000;
+
+        // the counts of -12..=12, which cover all but a negligible fraction of the samples
+        let mut counts = [0usize; 25];
+        for _ in 0..samples {
+            let x = DiscreteGaussianSampler.sample(rng, sigma);
+            assert!(x.abs() <= 24);
+            if x.abs() <= 12 {
+                counts[(x + 12) as usize] += 1;
+            }
+        }
+
+        // the probabilities of D_{Z, sigma}
+        let weight = |x: i64| (-((x * x) as f64) / (2.0 * sigma * sigma)).exp();
+        let total = (-24..=24).map(weight).sum::<f64>();
+        for x in -6..=6i64 {
+            let expected = samples as f64 * weight(x) / total;
+            let got = counts[(x + 12) as usize] as f64;
+            // within 5 standard deviations of the binomial count
+            assert!(
+                (got - expected).abs() <= 5.0 * expected.sqrt(),
+                "x = {x}: expected {expected}, got {got}"
+            );
+        }
+        // unlike the truncated continuous Gaussian, 0 is not twice as likely as it should be
+        assert!((counts[12] as f64) < 1.2 * samples as f64 * weight(0) / total);
+    }
+
+    #[test]
+    fn test_discrete_gaussian_moments() {
+        let rng = &mut rand::rng();
+        let sigma = 1000.0;
+        let samples = 50_000;
+
+        let xs = (0..samples)
+            .map(|_| DiscreteGaussianSampler.sample(rng, sigma) as f64)
+            .collect::<alloc::vec::Vec<_>>();
+        let mean = xs.iter().sum::<f64>() / samples as f64;
+        let std_dev = (xs.iter().map(|x| x * x).sum::<f64>() / samples as f64).sqrt();
+        // the standard error of the mean is sigma / sqrt(samples) ~ 4.5
+        assert!(mean.abs() < 25.0);
+        assert!((std_dev - sigma).abs() < 0.02 * sigma);
+    }
+
+    #[test]
+    fn test_sample_polynomial() {
+        let mut rng = rand::rng();
+        let std_dev = 10.0;
+        let p = DiscreteGaussianSampler.sample_polynomial::<i64, 4>(&mut rng, std_dev);
+        p.iter().for_each(|c| {
+            assert!(c.abs() <= 3 * std_dev as i64); // 99.7% of the data
+        });
+        let p: Polynomial<ZqI64<3515337053>, 64> =
+            DiscreteGaussianSampler.sample_polynomial(&mut rng, 0.5);
+        assert!(p
+            .iter()
+            .all(|c| num::ToPrimitive::to_i64(c).unwrap().abs() <= 6));
+    }
+}
@@ -0,0 +1,185 @@
+//! Defines the transcript for deriving challenges by the Fiat-Shamir transform.
+//!
+//! A [Transcript] absorbs the messages sent by the prover (e.g. commitments and polynomials) and squeezes
+//! out challenge polynomials in the Challenge Space C. The transcript is domain-separated by a label, so
+//! that transcripts of different protocols never produce the same challenges for the same messages.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use poly_ring_xnp1::zq::ZqI64;
+//! use ring_zk::{transcript::Transcript, Params};
+//!
+//! const N: usize = 512;
+//!
+//! let rng = &mut rand::rng();
+//!
+//! let params = Params::default();
+//! let ck = params.generate_commitment_key::<N>(rng);
+//! let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+//! let (_, c) = ck.commit(rng, x, &params);
+//!
+//! let mut transcript = Transcript::new(b"my-protocol");
+//! transcript.append_commitment(&c);
+//! let d = transcript.challenge::<ZqI64<3515337053>, N>(params.kappa);
+//!
+//! // the same messages give the same challenge.
+//! let mut transcript = Transcript::new(b"my-protocol");
+//! transcript.append_commitment(&c);
+//! assert_eq!(d, transcript.challenge::<_, N>(params.kappa));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use num::{One, ToPrimitive, Zero};
+use poly_ring_xnp1::Polynomial;
+use rand::distr::uniform::SampleUniform;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    challenge_space::polynomial_from_challenge_set_with_seed, commit::Commitment, mat::Mat,
+    polynomial::polynomial_to_bytes,
+};
+
+/// The domain separation prefix of all transcripts in this library.
+const TRANSCRIPT_DOMAIN: &[u8] = b"ring-zk/transcript";
+
+/// The transcript of a non-interactive protocol. It hashes all absorbed messages with SHA-256.
+#[derive(Clone, Debug)]
+pub struct Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript {
+    /// Create a new transcript domain-separated by the `label`, e.g. the name of the protocol.
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self {
+            hasher: Sha256::new(),
+        };
+        transcript.append_message(TRANSCRIPT_DOMAIN);
+        transcript.append_message(label);
+        transcript
+    }
+
+    /// Absorb the message into the transcript. The message is prefixed by its length, so that the
+    /// encoding of a sequence of messages is unambiguous.
+    pub fn append_message(&mut self, message: &[u8]) {
+        self.hasher.update((message.len() as u64).to_le_bytes());
+        self.hasher.update(message);
+    }
+
+    /// Absorb the commitment into the transcript.
+    pub fn append_commitment<I, const N: usize>(&mut self, c: &Commitment<I, N>)
+    where
+        I: ToPrimitive + Zero,
+    {
+        self.append_message(&mat_to_bytes(&c.c));
+    }
+
+    /// Absorb the polynomial (e.g. a scalar, or an element of a message sent by the prover) into the transcript.
+    pub fn append_scalar<I, const N: usize>(&mut self, p: &Polynomial<I, N>)
+    where
+        I: ToPrimitive,
+    {
+        self.append_message(&polynomial_to_bytes(p));
+    }
+
+    /// Absorb the vector of polynomials into the transcript.
+    pub fn append_polynomials<I, const N: usize>(&mut self, ps: &[Polynomial<I, N>])
+    where
+        I: ToPrimitive,
+    {
+        self.append_message(&polynomials_to_bytes(ps));
+    }
+
+    /// Squeeze out a challenge polynomial in Challenge Space C, i.e. a polynomial with exactly `kappa`
+    /// coefficients equal to 1 or -1 and the rest equal to 0. The challenge is absorbed back into the
+    /// transcript, so that subsequent challenges are different.
+    pub fn challenge<I, const N: usize>(&mut self, kappa: usize) -> Polynomial<I, N>
+    where
+        I: Clone + One + Zero + SampleUniform,
+        for<'a> &'a I: Add<Output = I> + Mul<Output = I> + Sub<Output = I>,
+    {
+        let seed: [u8; 32] = self.hasher.clone().finalize().into();
+        self.append_message(&seed);
+        polynomial_from_challenge_set_with_seed(seed, kappa)
+    }
+}
+
+/// Returns the canonical byte encoding of the vector of polynomials. It is the number of polynomials (8 bytes)
+/// followed by the encoding of each polynomial.
+pub(crate) fn polynomials_to_bytes<I, const N: usize>(ps: &[Polynomial<I, N>]) -> Vec<u8>
+where
+    I: ToPrimitive,
+{
+    let mut bytes = (ps.len() as u64).to_le_bytes().to_vec();
+    ps.iter().for_each(|p| bytes.extend(polynomial_to_bytes(p)));
+    bytes
+}
+
+/// Returns the canonical byte encoding of the matrix. It is the number of rows and columns (8 bytes each)
+/// followed by the encoding of each polynomial, row by row.
+pub(crate) fn mat_to_bytes<I, const N: usize>(m: &Mat<I, N>) -> Vec<u8>
+where
+    I: ToPrimitive + Zero,
+{
+    let (rows, cols) = m.dim();
+    let mut bytes = (rows as u64).to_le_bytes().to_vec();
+    bytes.extend((cols as u64).to_le_bytes());
+    m.polynomials
+        .iter()
+        .flatten()
+        .for_each(|p| bytes.extend(polynomial_to_bytes(p)));
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use poly_ring_xnp1::zq::ZqI64;
+
+    use super::*;
+    use crate::polynomial::{norm_1, norm_infinity};
+
+    const N: usize = 64;
+    type I = ZqI64<3515337053>;
+
+    #[test]
+    fn test_transcript_deterministic() {
+        let p = Polynomial::<I, N>::new(vec![I::from(1), I::from(-2)]);
+
+        let mut t1 = Transcript::new(b"test");
+        t1.append_scalar(&p);
+        let mut t2 = Transcript::new(b"test");
+        t2.append_scalar(&p);
+        let d = t1.challenge::<I, N>(36);
+        assert_eq!(d, t2.challenge::<I, N>(36));
+        // subsequent challenges are different but still deterministic.
+        let (d1, d2) = (t1.challenge::<I, N>(36), t2.challenge::<I, N>(36));
+        assert_eq!(d1, d2);
+        assert_ne!(d, d1);
+    }
+
+    #[test]
+    fn test_transcript_domain_separation() {
+        let p = Polynomial::<I, N>::new(vec![I::from(1), I::from(-2)]);
+
+        let mut t1 = Transcript::new(b"ring-zk/sum-proof");
+        t1.append_scalar(&p);
+        let mut t2 = Transcript::new(b"ring-zk/linear-proof");
+        t2.append_scalar(&p);
+        assert_ne!(t1.challenge::<I, N>(36), t2.challenge::<I, N>(36));
+    }
+
+    #[test]
+    fn test_transcript_challenge_in_challenge_space() {
+        let mut t = Transcript::new(b"test");
+        for kappa in [1, 10, 36] {
+            let d = t.challenge::<I, N>(kappa);
+            assert_eq!(norm_1(&d).to_usize().unwrap(), kappa);
+            assert_eq!(norm_infinity(&d).to_usize().unwrap(), 1);
+        }
+    }
+}
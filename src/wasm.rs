@@ -0,0 +1,192 @@
+//! Bindings of the proof of opening a commitment for WebAssembly, enabled by the feature `wasm`.
+//!
+//! The generic parameters do not cross the FFI boundary, so the bindings use the default parameters
+//! ([Params::default]) with the degree `N = 512`. The messages exchanged in the protocol (the commitment,
+//! the challenge and the response) are serialized to JSON strings. The randomness is passed in as 32-byte
+//! seeds, which should be generated by a cryptographically secure source such as `crypto.getRandomValues`.
+//!
+//! ## Example
+//!
+//! ```js
+//! import { WasmParams, WasmOpenProofProver, WasmOpenProofVerifier } from "ring-zk";
+//!
+//! const seed = () => crypto.getRandomValues(new Uint8Array(32));
+//!
+//! const params = new WasmParams();
+//! const ck = params.generateCommitmentKey(seed());
+//! const prover = new WasmOpenProofProver(params, ck);
+//! const verifier = new WasmOpenProofVerifier(params, ck);
+//!
+//! // the coefficients of the message polynomial
+//! const commitment = prover.commit(seed(), new BigInt64Array([1n, 2n, 3n, 4n]));
+//! const challenge = verifier.generateChallenge(seed(), commitment);
+//! const response = prover.createResponse(challenge);
+//! console.log(verifier.verify(response)); // true
+//! ```
+
+use alloc::{string::String, vec, vec::Vec};
+
+use poly_ring_xnp1::zq::ZqI64;
+use rand::{rngs::ChaCha20Rng, SeedableRng};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    commit::CommitmentKey,
+    params::Params,
+    prove::open::{
+        OpenProofProver, OpenProofResponseContext, OpenProofVerificationContext, OpenProofVerifier,
+    },
+};
+
+/// The degree of the polynomials in the bindings.
+const N: usize = 512;
+
+type I = ZqI64<3515337053>;
+
+/// The default parameters ([Params::default]).
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct WasmParams {
+    params: Params<I>,
+}
+
+#[wasm_bindgen]
+impl WasmParams {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        WasmParams {
+            params: Params::default(),
+        }
+    }
+
+    /// Generate the commitment key from the 32-byte `seed`.
+    #[wasm_bindgen(js_name = generateCommitmentKey)]
+    pub fn generate_commitment_key(&self, seed: &[u8]) -> Result<WasmCommitmentKey, JsError> {
+        Ok(WasmCommitmentKey {
+            ck: self.params.generate_commitment_key(&mut seeded_rng(seed)?),
+        })
+    }
+}
+
+/// The commitment key for the degree `N = 512`.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct WasmCommitmentKey {
+    ck: CommitmentKey<I, N>,
+}
+
+/// The prover of the proof of opening a commitment. It keeps the response context between the
+/// phases of the protocol.
+#[wasm_bindgen]
+pub struct WasmOpenProofProver {
+    params: Params<I>,
+    prover: OpenProofProver<I, N>,
+    context: Option<OpenProofResponseContext<I, N>>,
+}
+
+#[wasm_bindgen]
+impl WasmOpenProofProver {
+    #[wasm_bindgen(constructor)]
+    pub fn new(params: &WasmParams, ck: &WasmCommitmentKey) -> Self {
+        WasmOpenProofProver {
+            params: params.params.clone(),
+            prover: OpenProofProver::new(ck.ck.clone(), params.params.clone()),
+            context: None,
+        }
+    }
+
+    /// Commit to the message polynomial with the coefficients `value`, with the randomness from the 32-byte
+    /// `seed`. It returns the commitment of the protocol in JSON.
+    pub fn commit(&mut self, seed: &[u8], value: Vec<i64>) -> Result<String, JsError> {
+        let x = self.params.try_prepare_value::<N>(vec![value])?;
+        let (context, commitment) = self.prover.commit(&mut seeded_rng(seed)?, x);
+        self.context = Some(context);
+        Ok(serde_json::to_string(&commitment)?)
+    }
+
+    /// Create the response in JSON to the `challenge` in JSON.
+    ///
+    /// ## Errors
+    /// Returns an error if [WasmOpenProofProver::commit] was not called before, or the challenge is malformed.
+    #[wasm_bindgen(js_name = createResponse)]
+    pub fn create_response(&mut self, challenge: &str) -> Result<String, JsError> {
+        let context = self
+            .context
+            .take()
+            .ok_or_else(|| JsError::new("commit must be called before createResponse"))?;
+        let response = self
+            .prover
+            .create_response(context, serde_json::from_str(challenge)?);
+        Ok(serde_json::to_string(&response)?)
+    }
+}
+
+/// The verifier of the proof of opening a commitment. It keeps the verification context between the
+/// phases of the protocol.
+#[wasm_bindgen]
+pub struct WasmOpenProofVerifier {
+    verifier: OpenProofVerifier<I, N>,
+    context: Option<OpenProofVerificationContext<I, N>>,
+}
+
+#[wasm_bindgen]
+impl WasmOpenProofVerifier {
+    #[wasm_bindgen(constructor)]
+    pub fn new(params: &WasmParams, ck: &WasmCommitmentKey) -> Self {
+        WasmOpenProofVerifier {
+            verifier: OpenProofVerifier::new(ck.ck.clone(), params.params.clone()),
+            context: None,
+        }
+    }
+
+    /// Generate the challenge in JSON for the `commitment` in JSON, with the randomness from the 32-byte `seed`.
+    #[wasm_bindgen(js_name = generateChallenge)]
+    pub fn generate_challenge(&mut self, seed: &[u8], commitment: &str) -> Result<String, JsError> {
+        let (context, challenge) = self
+            .verifier
+            .generate_challenge(&mut seeded_rng(seed)?, serde_json::from_str(commitment)?);
+        self.context = Some(context);
+        Ok(serde_json::to_string(&challenge)?)
+    }
+
+    /// Verify the `response` in JSON. It returns `true` if the response is valid, otherwise `false`.
+    ///
+    /// ## Errors
+    /// Returns an error if [WasmOpenProofVerifier::generate_challenge] was not called before, or the response
+    /// is malformed.
+    pub fn verify(&mut self, response: &str) -> Result<bool, JsError> {
+        let context = self
+            .context
+            .take()
+            .ok_or_else(|| JsError::new("generateChallenge must be called before verify"))?;
+        Ok(self
+            .verifier
+            .verify(serde_json::from_str(response)?, context))
+    }
+}
+
+fn seeded_rng(seed: &[u8]) -> Result<ChaCha20Rng, JsError> {
+    let seed = <[u8; 32]>::try_from(seed).map_err(|_| JsError::new("seed must be 32 bytes"))?;
+    Ok(ChaCha20Rng::from_seed(seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_proof_flow() {
+        let params = WasmParams::new();
+        let ck = params.generate_commitment_key(&[1u8; 32]).unwrap();
+        let mut prover = WasmOpenProofProver::new(&params, &ck);
+        let mut verifier = WasmOpenProofVerifier::new(&params, &ck);
+
+        let commitment = prover.commit(&[2u8; 32], vec![1, 2, 3, 4]).unwrap();
+        let challenge = verifier
+            .generate_challenge(&[3u8; 32], &commitment)
+            .unwrap();
+        let response = prover.create_response(&challenge).unwrap();
+        assert!(verifier.verify(&response).unwrap());
+    }
+}
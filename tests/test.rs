@@ -1,9 +1,21 @@
+use std::cell::Cell;
+
+use num::{FromPrimitive, One, Zero};
+use poly_ring_xnp1::zq::ZqI64;
 use poly_ring_xnp1::{rand::CoeffsRangeInclusive, Polynomial};
-use rand::RngExt;
+use rand::{rngs::StdRng, RngExt, SeedableRng};
 use ring_zk::{
-    LinearProofProver, LinearProofVerifier, OpenProofProver, OpenProofVerifier, Params,
-    SumProofProver, SumProofVerifier,
+    iop::PolynomialCommitmentScheme, prove_linear, verify_linear, BinaryProofProver,
+    BinaryProofVerifier, Commitment, CommitmentBuilder, CommitmentKey, DecodeError,
+    DiscreteGaussianSampler, EqualityProofProver, EqualityProofVerifier, GaussianSampler,
+    LinearProofProver, LinearProofVerifier, Mat, MatrixLinearProofProver,
+    MatrixLinearProofVerifier, NormKind, OffsetProofProver, OffsetProofVerifier, OpenProofProver,
+    OpenProofResponse, OpenProofVerifier, Opening, Params, ProductProofProver,
+    ProductProofVerifier, RangeProofProver, RangeProofVerifier, RetryConfig, RingZkError,
+    SetMembershipProofProver, SetMembershipProofVerifier, SumProofProver, SumProofResponse,
+    SumProofVerifier, VerifyError,
 };
+use serde::{de::DeserializeOwned, Serialize};
 
 const N: usize = 16; // power of two. Should be reasonably long.
 
@@ -30,6 +42,612 @@ fn test_open_proof() {
     }
 }
 
+#[test]
+fn test_open_proof_rerandomized() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let (open, com) = ck.commit(rng, x, &params);
+        // rerandomize twice, so that f is the product of two randomizers
+        let (com2, open2) = com.rerandomize(rng, &open, &ck, &params);
+        let (com3, open3) = com2.rerandomize(rng, &open2, &ck, &params);
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+        for (com, open) in [(com2, open2), (com3, open3)] {
+            let f = open.f().cloned();
+            assert!(f.is_some());
+
+            let (response_ctx, commitment) =
+                prover.commit_with_opening(rng, open.clone(), com.clone());
+            let (verification_ctx, challenge) =
+                verifier.generate_challenge_with_randomizer(rng, commitment, f.as_ref());
+            let response = prover.create_response(response_ctx, challenge);
+            assert!(verifier.verify(response, verification_ctx));
+
+            // the verifier assuming f = 1 rejects the proof
+            let (response_ctx, commitment) = prover.commit_with_opening(rng, open, com);
+            let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+            let response = prover.create_response(response_ctx, challenge);
+            assert!(!verifier.verify(response, verification_ctx));
+        }
+    }
+}
+
+#[test]
+fn test_open_proof_extract() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+        // rewind the prover to answer two different challenges with the same t
+        let (response_ctx, commitment) = prover.commit(rng, x.clone());
+        let (ctx1, challenge1) = verifier.generate_challenge(rng, commitment.clone());
+        let (ctx2, challenge2) = loop {
+            let (ctx2, challenge2) = verifier.generate_challenge(rng, commitment.clone());
+            if challenge2 != challenge1 {
+                break (ctx2, challenge2);
+            }
+        };
+        let response1 = prover.create_response(response_ctx.clone(), challenge1.clone());
+        let response2 = prover.create_response(response_ctx.clone(), challenge2);
+
+        let opening = verifier
+            .extract(&commitment.c, &response1, &ctx1, &response2, &ctx2)
+            .unwrap();
+        assert!(commitment.c.verify(&opening, &ck, &params));
+        assert_eq!(opening.x(), &x[..]);
+        assert_eq!(opening.r(), response_ctx.opening.r());
+
+        // the same challenge twice
+        assert!(verifier
+            .extract(&commitment.c, &response1, &ctx1, &response1, &ctx1)
+            .is_none());
+        // the transcripts of different first messages
+        let (response_ctx3, commitment3) =
+            prover.commit_with_opening(rng, opening, commitment.c.clone());
+        let (ctx3, challenge3) = verifier.generate_challenge(rng, commitment3);
+        let response3 = prover.create_response(response_ctx3, challenge3);
+        if ctx3.challenge() != ctx1.challenge() {
+            assert!(verifier
+                .extract(&commitment.c, &response1, &ctx1, &response3, &ctx3)
+                .is_none());
+        }
+    }
+}
+
+#[cfg(feature = "os-rng")]
+#[test]
+fn test_secure_commitments() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key_secure::<N>();
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment) =
+        prover.commit_secure(params.prepare_value::<N>(vec![random_value(rng, bound)]));
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+
+    let g = params
+        .prepare_value::<N>(vec![random_value(rng, bound)])
+        .remove(0);
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment) =
+        prover.commit_secure(g, params.prepare_value::<N>(vec![random_value(rng, bound)]));
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+}
+
+#[test]
+fn test_commitment_key_serde() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+
+    // the precomputed matrix [a1 a2] is not serialized, but recomputed on deserialization.
+    let json = serde_json::to_value(&ck).unwrap();
+    let fields = json.as_object().unwrap();
+    assert_eq!(fields.len(), 2);
+    assert!(fields.contains_key("a1") && fields.contains_key("a2"));
+
+    let decoded = serde_round_trip(&ck);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+    let (opening, c) = decoded.commit(rng, x, &params);
+    assert!(c.verify(&opening, &ck, &params));
+}
+
+#[test]
+fn test_opening_is_valid_norm() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+    let (opening, c) = ck.commit(rng, x, &params);
+    assert!(opening.is_valid_norm(&params));
+
+    // the norm of the randomness doubles in each addition of the opening to itself.
+    let (mut inflated, mut c_inflated) = (opening, c);
+    for _ in 0..16 {
+        inflated = inflated.add(&inflated);
+        c_inflated = c_inflated.add(&c_inflated);
+    }
+    assert!(!inflated.is_valid_norm(&params));
+    assert!(!c_inflated.verify(&inflated, &ck, &params));
+}
+
+#[test]
+fn test_opening_norm_diagnostics() {
+    let rng = &mut rand::rng();
+
+    for norm in [NormKind::Two, NormKind::Infinity] {
+        let params = Params {
+            norm,
+            ..Params::default()
+        };
+        let ck = params.generate_commitment_key::<N>(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+        let (opening, _) = ck.commit(rng, x, &params);
+
+        let diagnostics = opening.norm_diagnostics(&params);
+        assert_eq!(diagnostics.bound, params.commit_norm_bound::<N>());
+        assert!(diagnostics.max_norm > 0);
+        assert!(diagnostics.max_norm <= diagnostics.bound);
+        assert!(diagnostics.ratio() <= 1.0);
+
+        // the norm doubles in the addition of the opening to itself.
+        let doubled = opening.add(&opening).norm_diagnostics(&params);
+        assert!(doubled.max_norm >= 2 * diagnostics.max_norm - 1);
+        assert_eq!(
+            doubled.max_norm <= doubled.bound,
+            opening.add(&opening).is_valid_norm(&params)
+        );
+    }
+}
+
+#[test]
+fn test_commit_at_offset() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+
+    // the default layout [0_n x]
+    let (opening, c) = ck.commit_at_offset(rng, x.clone(), params.n, &params);
+    assert!(c.verify(&opening, &ck, &params));
+
+    // the layout [x 0_n], i.e. c1 = a1 * r + x and c2 = a2 * r
+    let (opening, c) = ck.commit_at_offset(rng, x.clone(), 0, &params);
+    assert!(!c.verify(&opening, &ck, &params));
+    let (c1, c2) = c.split(&params);
+    let c1 = vec![c1[0].clone() - x[0].clone()];
+    let c = Commitment::from_parts(c1, c2, &params).unwrap();
+    let zero = params.prepare_value::<N>(vec![vec![0]]);
+    assert!(c.verify_parts(&zero, opening.r(), None, &ck, &params));
+}
+
+#[test]
+#[should_panic]
+fn test_commit_at_offset_out_of_range() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![vec![1]]);
+    ck.commit_at_offset(rng, x, params.n + 1, &params);
+}
+
+#[test]
+fn test_commitment_verify_parts() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+    let (opening, c) = ck.commit(rng, x.clone(), &params);
+    assert_eq!(opening.x(), &x[..]);
+    assert!(c.verify_parts(opening.x(), opening.r(), opening.f(), &ck, &params));
+
+    // the randomness reconstructed from its serialized form
+    let r: Mat<_, N> = serde_round_trip(opening.r());
+    assert!(c.verify_parts(&x, &r, None, &ck, &params));
+
+    let x2 = params.prepare_value::<N>(vec![vec![1, 2, 3]]);
+    assert!(!c.verify_parts(&x2, &r, None, &ck, &params));
+    let f = params.prepare_scalar::<N>(vec![1, -1]);
+    assert!(!c.verify_parts(&x, &r, Some(&f), &ck, &params));
+}
+
+#[test]
+fn test_commitment_parts() {
+    let rng = &mut rand::rng();
+
+    // `set_128bit_secure` has n != l, so that c1 and c2 have different lengths.
+    for params in [
+        Params::default(),
+        Params::set_message_length_4(),
+        Params::set_128bit_secure(),
+    ] {
+        let ck = params.generate_commitment_key::<N>(rng);
+        let x = params.prepare_value::<N>(vec![vec![1, 2, 3]; params.l]);
+        let (opening, c) = ck.commit(rng, x, &params);
+
+        let (c1, c2) = c.parts(&params);
+        assert_eq!(c1.dim(), (params.n, 1));
+        assert_eq!(c2.dim(), (params.l, 1));
+        let (v1, v2) = c.split(&params);
+        assert_eq!(
+            (c1, c2),
+            (Mat::from_vec(v1.clone()), Mat::from_vec(v2.clone()))
+        );
+
+        let c2 = Commitment::from_parts(v1, v2, &params).unwrap();
+        assert_eq!(c, c2);
+        assert!(c2.verify(&opening, &ck, &params));
+    }
+}
+
+#[test]
+fn test_commitment_builder() {
+    let rng = &mut rand::rng();
+
+    let params = Params {
+        k: 6,
+        l: 4,
+        ..Params::default()
+    };
+    let ck = params.generate_commitment_key::<N>(rng);
+    let bound = params.q.clone().into();
+    let x = params.prepare_value::<N>((0..params.l).map(|_| random_value(rng, bound)).collect());
+
+    let mut builder = CommitmentBuilder::new(&ck);
+    assert!(builder.is_empty());
+    for xi in x.clone() {
+        builder.push(xi);
+    }
+    assert_eq!(builder.len(), params.l);
+    let streamed = builder.build(&mut StdRng::seed_from_u64(7), &params);
+    let batch = ck.commit(&mut StdRng::seed_from_u64(7), x, &params);
+    assert_eq!(streamed, batch);
+    assert!(streamed.1.verify(&streamed.0, &ck, &params));
+}
+
+#[test]
+#[should_panic]
+fn test_commitment_builder_too_many() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let mut builder = CommitmentBuilder::new(&ck);
+    for _ in 0..=params.l {
+        builder.push(Polynomial::one());
+    }
+}
+
+#[test]
+fn test_opening_message_coeffs() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let (opening, _) = ck.commit(rng, x, &params);
+    assert_eq!(
+        opening.message_coeffs(&params),
+        vec![vec![1, 2, 3, 4]
+            .into_iter()
+            .map(ZqI64::from)
+            .collect::<Vec<_>>()]
+    );
+
+    // the coefficients are reduced into [-q, q] for the integer type without modular reduction
+    let q = 3515337053 / 2;
+    let params = Params::<i64>::new(q, 1, 1, 3, 1, 36).unwrap();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, -2, 2 * q + 4, q + 1]]);
+    let (opening, _) = ck.commit(rng, x, &params);
+    assert_eq!(opening.message_coeffs(&params), vec![vec![1, -2, 3, -q]]);
+}
+
+/// Test canonicalizing the opening and the commitment whose coefficients are other representatives
+/// modulo q', for the integer type without modular reduction.
+#[test]
+fn test_canonicalize_shifted_representatives() {
+    let rng = &mut rand::rng();
+
+    let q = 3515337053 / 2;
+    let modulus = 2 * q + 1;
+    let params = Params::<i64>::new(q, 1, 1, 3, 1, 36).unwrap();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, -2, 3, 4]]);
+    let (opening, mut c) = ck.commit(rng, x, &params);
+
+    // the randomness is small, so it is already in the centered range.
+    let mut shifted: Opening<i64, N> = shift_representatives(&opening, "r", modulus);
+    assert_ne!(shifted, opening);
+    assert!(!c.verify(&shifted, &ck, &params));
+    shifted.canonicalize(&params);
+    assert_eq!(shifted, opening);
+    assert!(c.verify(&shifted, &ck, &params));
+
+    // the coefficients of the commitment are not reduced by the integer arithmetic of `commit`.
+    c.canonicalize(&params);
+    let mut shifted: Commitment<i64, N> = shift_representatives(&c, "c", modulus);
+    assert_ne!(shifted, c);
+    shifted.canonicalize(&params);
+    assert_eq!(shifted, c);
+}
+
+#[cfg(feature = "ct")]
+#[test]
+fn test_ct_eq() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    let mut commitments = Vec::new();
+    let mut responses = Vec::new();
+    for _ in 0..4 {
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let (response_ctx, commitment) = prover.commit(rng, x);
+        commitments.push(commitment.c.clone());
+        let (_, challenge) = verifier.generate_challenge(rng, commitment);
+        responses.push(prover.create_response(response_ctx, challenge));
+    }
+    // the commitment differing from the first one in a single coefficient
+    let (c1, c2) = commitments[0].parts(&params);
+    let mut c2 = c2.iter().cloned().collect::<Vec<_>>();
+    c2[0] = c2[0].clone() + Polynomial::new(vec![ZqI64::zero(), ZqI64::one()]);
+    commitments.push(Commitment::from_parts(c1.iter().cloned().collect(), c2, &params).unwrap());
+
+    for a in &commitments {
+        for b in &commitments {
+            assert_eq!(bool::from(a.ct_eq(b)), a == b);
+        }
+        assert!(bool::from(a.ct_eq(&a.clone())));
+    }
+    for a in &responses {
+        for b in &responses {
+            assert_eq!(bool::from(a.ct_eq(b)), a == b);
+        }
+        assert!(bool::from(a.ct_eq(&a.clone())));
+    }
+}
+
+#[test]
+fn test_commitment_check_binding() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let (open, com) = ck.commit(rng, x.clone(), &params);
+    let (open_same, com_same) = ck.commit(rng, x, &params);
+    let x2 = params.prepare_value::<N>(vec![vec![4, 5, 6, 7]]);
+    let (open2, com2) = ck.commit(rng, x2, &params);
+
+    assert!(!open.differs_from(&open_same));
+    assert!(open.differs_from(&open2));
+    assert!(open2.differs_from(&open));
+
+    // the openings of different messages do not open the same commitment.
+    assert!(!com.check_binding(&open, &open2, &ck, &params));
+    assert!(!com2.check_binding(&open, &open2, &ck, &params));
+    assert!(!com2.verify(&open, &ck, &params));
+    assert!(!com.verify(&open2, &ck, &params));
+    // the openings of the same message are not a break of the binding property.
+    assert!(!com.check_binding(&open, &open, &ck, &params));
+    assert!(!com_same.check_binding(&open, &open_same, &ck, &params));
+}
+
+#[test]
+fn test_commitment_concat() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let xs = (0..3)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect::<Vec<_>>();
+    let (openings, commitments) = xs
+        .iter()
+        .map(|x| ck.commit(rng, x.clone(), &params))
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    let concat_params = params.concat(3);
+    assert_eq!(
+        (concat_params.n, concat_params.k, concat_params.l),
+        (3 * params.n, 3 * params.k, 3 * params.l)
+    );
+    let concat_ck = ck.concat(3);
+    let c = Commitment::concat(&commitments, &params);
+    let opening = Opening::concat(&openings);
+    assert_eq!(opening.x(), xs.concat());
+    assert!(opening.is_valid_norm(&concat_params));
+    assert!(c.verify(&opening, &concat_ck, &concat_params));
+
+    // the openings in a different order do not open the commitment
+    let swapped = Opening::concat(&[
+        openings[1].clone(),
+        openings[0].clone(),
+        openings[2].clone(),
+    ]);
+    assert!(!c.verify(&swapped, &concat_ck, &concat_params));
+
+    // a single commitment is unchanged
+    let single = Commitment::concat(&commitments[..1], &params);
+    assert_eq!(single, commitments[0]);
+    assert_eq!(ck.concat(1), ck);
+}
+
+#[test]
+fn test_commitment_bytes_round_trip() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+    let (opening, c) = ck.commit(rng, x, &params);
+
+    let bytes = c.to_bytes(&params);
+    // 4 bytes per coefficient for q' < 2^32, plus the dimensions.
+    assert_eq!(bytes.len(), 8 + (params.n + params.l) * N * 4);
+    assert!(bytes.len() * 2 <= serde_json::to_vec(&c).unwrap().len());
+
+    let decoded = Commitment::from_bytes(&bytes, &params).unwrap();
+    assert_eq!(decoded, c);
+    assert!(decoded.verify(&opening, &ck, &params));
+}
+
+#[test]
+fn test_commitment_from_bytes_malformed() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+    let (_, c) = ck.commit(rng, x, &params);
+    let bytes = c.to_bytes(&params);
+
+    let decode = |bytes: &[u8]| Commitment::<_, N>::from_bytes(bytes, &params);
+    for len in 0..bytes.len() {
+        assert_eq!(decode(&bytes[..len]), Err(DecodeError::Truncated));
+    }
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        decode(&trailing),
+        Err(DecodeError::TrailingBytes { len: 1 })
+    );
+
+    let mut wrong_rows = bytes.clone();
+    wrong_rows[0] += 1;
+    assert_eq!(
+        decode(&wrong_rows),
+        Err(DecodeError::DimensionMismatch {
+            expected: (params.n + params.l, 1),
+            got: (params.n + params.l + 1, 1),
+        })
+    );
+
+    // the first coefficient is set to 0xffffffff >= q'
+    let mut out_of_range = bytes.clone();
+    out_of_range[8..12].copy_from_slice(&[0xff; 4]);
+    assert_eq!(
+        decode(&out_of_range),
+        Err(DecodeError::CoefficientOutOfRange)
+    );
+}
+
+#[test]
+fn test_commitment_key_bytes_round_trip() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key::<N>(rng);
+    let bytes = ck.to_bytes(&params);
+    // a1 and a2, each with the dimensions and 4 bytes per coefficient for q' < 2^32.
+    assert_eq!(bytes.len(), 16 + (params.n + params.l) * params.k * N * 4);
+    assert!(bytes.len() < serde_json::to_vec(&ck).unwrap().len());
+
+    let decoded = CommitmentKey::<_, N>::from_bytes(&bytes, &params).unwrap();
+    assert_eq!(decoded, ck);
+
+    // the commitment produced with the original key is verified with the decoded key
+    let x = params.prepare_value::<N>(vec![random_value(rng, params.q.clone().into())]);
+    let (opening, c) = ck.commit(rng, x, &params);
+    assert!(c.verify(&opening, &decoded, &params));
+
+    let decode = |bytes: &[u8]| CommitmentKey::<_, N>::from_bytes(bytes, &params);
+    assert_eq!(
+        decode(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::Truncated)
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        decode(&trailing),
+        Err(DecodeError::TrailingBytes { len: 1 })
+    );
+    // the number of columns of a1 is changed
+    let mut wrong_cols = bytes.clone();
+    wrong_cols[4] += 1;
+    assert_eq!(
+        decode(&wrong_cols),
+        Err(DecodeError::DimensionMismatch {
+            expected: (params.n, params.k),
+            got: (params.n, params.k + 1),
+        })
+    );
+}
+
+#[test]
+fn test_generate_commitment_key_from_seed() {
+    let params = Params::default();
+
+    let ck = params.generate_commitment_key_from_seed::<N>([1u8; 32]);
+    assert_eq!(ck, params.generate_commitment_key_from_seed::<N>([1u8; 32]));
+    assert_ne!(ck, params.generate_commitment_key_from_seed::<N>([2u8; 32]));
+}
+
+/// Test the open proof with the constraints in the infinity norm.
+#[test]
+fn test_open_proof_infinity_norm() {
+    let rng = &mut rand::rng();
+
+    let params = Params {
+        norm: NormKind::Infinity,
+        ..Params::default()
+    };
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit(rng, x);
+        assert!(commitment.c.verify(&response_ctx.opening, &ck, &params));
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
 /// Test the linear proof by generating random inputs over numerous iterations.
 #[test]
 fn test_linear_proof() {
@@ -55,45 +673,1692 @@ fn test_linear_proof() {
     }
 }
 
-/// Test the sum proof by generating random inputs over numerous iterations.
+/// Report the sizes of the proofs encoded by bincode at N = 512, and check that each polynomial costs 8 bytes per
+/// coefficient (ZqI64) plus a small overhead for the lengths.
+#[cfg(feature = "bincode")]
 #[test]
-fn test_sum_proof() {
+fn test_proof_size_bytes() {
+    const N: usize = 512;
+
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, 100)]);
+    let g = params.prepare_scalar::<N>(vec![3; N]);
+    // the number of bytes of `full` polynomials of N coefficients and `sparse` ones (e.g. the challenges), which
+    // are encoded without the trailing zero coefficients, with at most 32 bytes of lengths and other fields for
+    // each of them.
+    let within = |size: usize, full: usize, sparse: usize| {
+        8 * N * full <= size && size <= (8 * N + 32) * (full + sparse)
+    };
+
+    // c (n + l), t (n) and z (k), and d
+    let (_, open_proof) =
+        OpenProofProver::new(ck.clone(), params.clone()).prove_non_interactive(rng, x.clone());
+    let open_size = open_proof.proof_size_bytes();
+    assert_eq!(open_size, open_proof.to_bincode().len());
+    assert!(within(open_size, 6, 1));
+    assert_eq!(
+        ring_zk::OpenProof::from_bincode(&open_proof.to_bincode()),
+        Ok(open_proof)
+    );
+
+    // c and c' (2 * (n + l)), g, t and t' (2 * n), u (l), z and z' (2 * k), and d and h = 0
+    let linear_proof = prove_linear(rng, &ck, &params, g, x.clone());
+    let linear_size = linear_proof.proof_size_bytes();
+    assert_eq!(linear_size, linear_proof.to_bincode().len());
+    assert!(within(linear_size, 14, 2));
+
+    // c (n + l), 3 * t (n) and 3 * z (k), and 3 * d
+    let (_, repeated_proof) =
+        OpenProofProver::new(ck.clone(), params.clone()).prove_non_interactive_repeated(rng, x, 3);
+    let repeated_size = repeated_proof.proof_size_bytes();
+    assert!(within(repeated_size, 14, 3));
+
+    println!("open proof: {open_size} bytes");
+    println!("linear proof: {linear_size} bytes");
+    println!("repeated open proof (3 repetitions): {repeated_size} bytes");
+
+    let mut bytes = linear_proof.to_bincode();
+    bytes.pop();
+    assert_eq!(
+        ring_zk::LinearProof::<ZqI64<3515337053>, N>::from_bincode(&bytes),
+        Err(DecodeError::Truncated)
+    );
+    bytes.extend([0, 0]);
+    assert_eq!(
+        ring_zk::LinearProof::<ZqI64<3515337053>, N>::from_bincode(&bytes),
+        Err(DecodeError::Malformed)
+    );
+}
+
+#[test]
+fn test_linear_proof_combine() {
+    // the combined response is about sqrt(2) times larger, which exceeds the norm bound too often for a small N.
+    const N: usize = 256;
+
     let rng = &mut rand::rng();
 
     let params = Params::default();
     let bound = params.q.clone().into();
-    const VL: usize = 4;
 
-    for _ in 0..100 {
-        let ck = params.generate_commitment_key(rng);
+    let ck = params.generate_commitment_key(rng);
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
 
-        let xs = (0..VL)
-            .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
-            .collect::<Vec<_>>();
-        let gs = (0..VL)
-            .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
-            .collect::<Vec<_>>();
+    for _ in 0..5 {
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let g1 = params.prepare_scalar::<N>(random_value(rng, bound));
+        let g2 = params.prepare_scalar::<N>(random_value(rng, bound));
 
-        let prover = SumProofProver::new(ck.clone(), params.clone());
-        let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+        let (response_ctx_a, commitment_a) = prover.commit(rng, g1.clone(), x.clone());
+        let (response_ctx_b, commitment_b) =
+            prover.commit_with_shared_x(rng, g2.clone(), &response_ctx_a, &commitment_a);
+        assert_eq!(commitment_a.c, commitment_b.c);
+
+        let commitment = commitment_a.combine(&commitment_b).unwrap();
+        assert_eq!(commitment.g, g1.clone() + g2);
+        // c'_a + c'_b opens to x'_a + x'_b
+        let opening_p = response_ctx_a.opening_p.add(&response_ctx_b.opening_p);
+        assert!(commitment.cp.verify(&opening_p, &ck, &params));
 
-        let (response_ctx, commitment) = prover.commit(rng, gs, xs);
-        commitment.cp.verify(&response_ctx.opening_p, &ck, &params);
-        commitment
-            .cs
-            .iter()
-            .zip(response_ctx.openings.iter())
-            .for_each(|(c, o)| {
-                assert!(c.verify(o, &ck, &params));
-            });
         let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
-        let response = prover.create_response(response_ctx, challenge);
+        let response_a = prover.create_response(response_ctx_a.clone(), challenge.clone());
+        let response_b = prover.create_response(response_ctx_b, challenge);
+        let response = response_a.combine(&response_b).unwrap();
         assert!(verifier.verify(response, verification_ctx));
+
+        // the commitments on different x are not combined
+        let (_, commitment_c) = prover.commit(rng, g1, x);
+        assert!(commitment_a.combine(&commitment_c).is_none());
+
+        // the responses to different challenges are not combined
+        let (_, challenge_a) = verifier.generate_challenge(rng, commitment_a);
+        let (_, challenge_b) = verifier.generate_challenge(rng, commitment_b);
+        let response_a = prover.create_response(response_ctx_a.clone(), challenge_a);
+        let response_b = prover.create_response(response_ctx_a, challenge_b);
+        assert!(response_a.combine(&response_b).is_none());
     }
 }
 
-pub(crate) fn random_value(rng: &mut impl RngExt, bound: i64) -> Vec<i64> {
-    let range = CoeffsRangeInclusive::from(-bound..=bound);
-    let p: Polynomial<i64, N> = rng.random_range(range);
+#[test]
+fn test_linear_proof_with_opening() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let g = params.prepare_scalar::<N>(random_value(rng, bound));
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    let open_verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let (response_ctx, commitment) = prover.commit(rng, g.clone(), x);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+
+    // one response satisfies both the opening of `x` and the linear relation under the shared challenge.
+    let open_ctx = verification_ctx.open_proof_context();
+    assert_eq!(open_ctx.challenge(), verification_ctx.challenge());
+    assert!(open_verifier.verify(response.open_proof_response(), open_ctx.clone()));
+    assert!(verifier.verify(response.clone(), verification_ctx));
+
+    // the response does not open another commitment.
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let (_, commitment) = prover.commit(rng, g, x);
+    let (other_ctx, _) = verifier.generate_challenge(rng, commitment);
+    assert!(!open_verifier.verify(
+        response.open_proof_response(),
+        other_ctx.open_proof_context()
+    ));
+}
+
+#[test]
+fn test_linear_proof_verify_detailed() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let g = params.prepare_scalar::<N>(random_value(rng, bound));
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, g, x);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert_eq!(
+        verifier.verify_detailed(response.clone(), verification_ctx.clone()),
+        Ok(())
+    );
+
+    let ctx = serde_json::to_value(&verification_ctx).unwrap();
+    let tampered = |field: &str, value: &serde_json::Value| {
+        let mut ctx = ctx.clone();
+        ctx[field] = value.clone();
+        serde_json::from_value(ctx).unwrap()
+    };
+    // the commitment c2 has large coefficients, which exceed the norm bound as a response
+    let mut large_response = serde_json::to_value(&response).unwrap();
+    large_response["z"] = ctx["c2"].clone();
+    assert_eq!(
+        verifier.verify_detailed(
+            serde_json::from_value(large_response).unwrap(),
+            verification_ctx.clone()
+        ),
+        Err(VerifyError::NormConstraintViolated)
+    );
+    assert_eq!(
+        verifier.verify_detailed(response.clone(), tampered("t", &ctx["tp"])),
+        Err(VerifyError::FirstEquationMismatch)
+    );
+    assert_eq!(
+        verifier.verify_detailed(response.clone(), tampered("u", &ctx["c2"])),
+        Err(VerifyError::SecondEquationMismatch)
+    );
+    assert!(!verifier.verify(response, tampered("u", &ctx["c2"])));
+}
+
+/// Test the open, linear and sum proofs with messages of length 4.
+#[test]
+fn test_proofs_message_length_4() {
+    let rng = &mut rand::rng();
+
+    let params = Params::set_message_length_4();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let random_message = |rng: &mut rand::rngs::ThreadRng| {
+        params.prepare_value::<N>((0..params.l).map(|_| random_value(rng, bound)).collect())
+    };
+
+    // open proof
+    let x = random_message(rng);
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment) = prover.commit(rng, x);
+    assert!(commitment.c.verify(&response_ctx.opening, &ck, &params));
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+
+    // linear proof
+    let x = random_message(rng);
+    let g = params.prepare_scalar::<N>(random_value(rng, bound));
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment) = prover.commit(rng, g, x);
+    assert!(commitment.cp.verify(&response_ctx.opening_p, &ck, &params));
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+
+    // sum proof
+    let xs = (0..3).map(|_| random_message(rng)).collect::<Vec<_>>();
+    let gs = (0..3)
+        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+        .collect::<Vec<_>>();
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+    assert!(commitment.cp.verify(&response_ctx.opening_p, &ck, &params));
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+}
+
+/// Test that a message of length 1 is rejected by the parameters with message length 4.
+#[test]
+#[should_panic]
+fn test_message_length_4_wrong_length() {
+    let params = Params::set_message_length_4();
+    params.prepare_value::<N>(vec![vec![1, 2, 3]]);
+}
+
+/// Test the linear proof with the openings checked together with the response.
+#[test]
+fn test_linear_proof_verify_full() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+
+    let mut run = || {
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let g = params.prepare_scalar::<N>(random_value(rng, bound));
+        let (response_ctx, commitment) = prover.commit(rng, g, x);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment.clone());
+        let (opening, opening_p) = (response_ctx.opening.clone(), response_ctx.opening_p.clone());
+        let response = prover.create_response(response_ctx, challenge);
+        (commitment, response, verification_ctx, opening, opening_p)
+    };
+    let (commitment, response, verification_ctx, opening, opening_p) = run();
+    let (_, other_response, _, other_opening, _) = run();
+
+    assert!(verifier.verify_full(
+        &commitment,
+        response.clone(),
+        verification_ctx.clone(),
+        &opening,
+        &opening_p
+    ));
+
+    // tampered opening: the response alone still verifies.
+    assert!(verifier.verify(response.clone(), verification_ctx.clone()));
+    assert!(!verifier.verify_full(
+        &commitment,
+        response,
+        verification_ctx.clone(),
+        &other_opening,
+        &opening_p
+    ));
+
+    // tampered response: the openings alone still verify.
+    assert!(commitment.c.verify(&opening, &ck, &params));
+    assert!(commitment.cp.verify(&opening_p, &ck, &params));
+    assert!(!verifier.verify_full(
+        &commitment,
+        other_response,
+        verification_ctx,
+        &opening,
+        &opening_p
+    ));
+}
+
+/// Test the linear proof with all messages passed through serde_json.
+#[test]
+fn test_linear_proof_serde() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let g = params.prepare_scalar::<N>(vec![5, 6]);
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, g, x);
+    let response_ctx = serde_round_trip(&response_ctx);
+    let commitment = serde_round_trip(&commitment);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let verification_ctx = serde_round_trip(&verification_ctx);
+    let challenge = serde_round_trip(&challenge);
+    let response = prover.create_response(response_ctx, challenge);
+    let response = serde_round_trip(&response);
+    assert!(verifier.verify(response, verification_ctx));
+}
+
+/// Test the sum proof by generating random inputs over numerous iterations.
+#[test]
+fn test_sum_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    const VL: usize = 4;
+
+    for _ in 0..100 {
+        let ck = params.generate_commitment_key(rng);
+
+        let xs = (0..VL)
+            .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+            .collect::<Vec<_>>();
+        let gs = (0..VL)
+            .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+            .collect::<Vec<_>>();
+
+        let prover = SumProofProver::new(ck.clone(), params.clone());
+        let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+        commitment.cp.verify(&response_ctx.opening_p, &ck, &params);
+        commitment
+            .cs
+            .iter()
+            .zip(response_ctx.openings.iter())
+            .for_each(|(c, o)| {
+                assert!(c.verify(o, &ck, &params));
+            });
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that a response with a truncated `zs` vector is rejected instead of panicking.
+#[test]
+fn test_sum_proof_truncated_response() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let xs = (0..3)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect::<Vec<_>>();
+    let gs = (0..3)
+        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+        .collect::<Vec<_>>();
+
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+
+    // drop the last z_i from the response
+    let mut value = serde_json::to_value(&response).unwrap();
+    value["zs"].as_array_mut().unwrap().pop();
+    let response: SumProofResponse<_, N> = serde_json::from_value(value).unwrap();
+    assert!(!verifier.verify(response.clone(), verification_ctx.clone()));
+    assert_eq!(
+        verifier.verify_detailed(response, verification_ctx),
+        Err(VerifyError::LengthMismatch {
+            expected: 3,
+            got: 2
+        })
+    );
+}
+
+/// Test the sum proof with scalars in the challenge space (coefficients in {-1, 0, 1}). The responses do not
+/// involve the scalars, so the proof is the same as for arbitrary scalars.
+#[test]
+fn test_sum_proof_small_scalars() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let xs = (0..2)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect::<Vec<_>>();
+    let gs = vec![
+        params.prepare_scalar::<N>(vec![1, -1, 0, 1]),
+        params.prepare_scalar::<N>(vec![-1, 0, 1]),
+    ];
+
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+}
+
+/// Test the sum proof with two public scalars and one private scalar.
+#[test]
+fn test_sum_proof_mixed() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+
+        let xs = (0..3)
+            .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+            .collect::<Vec<_>>();
+        let gs = (0..2)
+            .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+            .collect::<Vec<_>>();
+        let ss = vec![params.prepare_scalar::<N>(random_value(rng, bound))];
+
+        let prover = SumProofProver::new(ck.clone(), params.clone());
+        let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit_mixed(rng, gs, ss, xs);
+        assert!(commitment.cp.verify(&response_ctx.opening_p, &ck, &params));
+        commitment
+            .css
+            .iter()
+            .zip(response_ctx.openings_s.iter())
+            .for_each(|(c, o)| {
+                assert!(c.verify(o, &ck, &params));
+            });
+        let (verification_ctx, challenge) = verifier.generate_challenge_mixed(rng, commitment);
+        let response = prover.create_response_mixed(response_ctx, challenge);
+        assert!(verifier.verify_mixed(response, verification_ctx));
+    }
+}
+
+/// Test that the mixed sum proof fails if the public scalars are changed after committing.
+#[test]
+fn test_sum_proof_mixed_wrong_public_term() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let xs = (0..3)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect::<Vec<_>>();
+    let gs = (0..2)
+        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+        .collect::<Vec<_>>();
+    let ss = vec![params.prepare_scalar::<N>(random_value(rng, bound))];
+    let wrong_g = params.prepare_scalar::<N>(random_value(rng, bound));
+
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, mut commitment) = prover.commit_mixed(rng, gs, ss, xs);
+    commitment.gs[0] = wrong_g;
+    let (verification_ctx, challenge) = verifier.generate_challenge_mixed(rng, commitment);
+    let response = prover.create_response_mixed(response_ctx, challenge);
+    assert!(!verifier.verify_mixed(response, verification_ctx));
+}
+
+/// Test the sum proof to a public target, and that it fails if the target differs from the weighted sum.
+#[test]
+fn test_sum_proof_to_public() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+
+        let xs = (0..3)
+            .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+            .collect::<Vec<_>>();
+        let gs = (0..3)
+            .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+            .collect::<Vec<_>>();
+        // t = g_0 * x_0 + g_1 * x_1 + g_2 * x_2
+        let target = gs
+            .iter()
+            .zip(xs.iter())
+            .map(|(g, x)| g.clone() * x[0].clone())
+            .fold(Polynomial::zero(), |acc, p| acc + p);
+
+        let prover = SumProofProver::new(ck.clone(), params.clone());
+        let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit_sum_to_public(rng, gs.clone(), xs.clone());
+        commitment
+            .cs
+            .iter()
+            .zip(response_ctx.openings.iter())
+            .for_each(|(c, o)| {
+                assert!(c.verify(o, &ck, &params));
+            });
+        let (verification_ctx, challenge) =
+            verifier.generate_challenge_sum_to_public(rng, commitment, vec![target.clone()]);
+        let response = prover.create_response_sum_to_public(response_ctx, challenge);
+        assert!(verifier.verify_sum_to_public(response, verification_ctx));
+
+        // the verifier expects a different target
+        let (response_ctx, commitment) = prover.commit_sum_to_public(rng, gs, xs);
+        let wrong_target = target + Polynomial::one();
+        let (verification_ctx, challenge) =
+            verifier.generate_challenge_sum_to_public(rng, commitment, vec![wrong_target]);
+        let response = prover.create_response_sum_to_public(response_ctx, challenge);
+        assert!(!verifier.verify_sum_to_public(response, verification_ctx));
+    }
+}
+
+/// Test the matrix linear proof with a 2x2 matrix `G` and `l = 2`.
+#[test]
+fn test_matrix_linear_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params {
+        n: 2,
+        k: 5,
+        l: 2,
+        ..Params::default()
+    };
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound), random_value(rng, bound)]);
+        let g = polynomial_grid(vec![
+            vec![random_value(rng, bound), random_value(rng, bound)],
+            vec![random_value(rng, bound), random_value(rng, bound)],
+        ]);
+
+        let prover = MatrixLinearProofProver::new(ck.clone(), params.clone());
+        let verifier = MatrixLinearProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit(rng, g, x);
+        assert!(commitment.c.verify(&response_ctx.opening, &ck, &params));
+        assert!(commitment.cp.verify(&response_ctx.opening_p, &ck, &params));
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that the matrix linear proof fails if `G` is changed after committing.
+#[test]
+fn test_matrix_linear_proof_wrong_matrix() {
+    let rng = &mut rand::rng();
+
+    let params = Params {
+        n: 2,
+        k: 5,
+        l: 2,
+        ..Params::default()
+    };
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    let g = polynomial_grid(vec![vec![vec![1], vec![2]], vec![vec![3], vec![4]]]);
+
+    let prover = MatrixLinearProofProver::new(ck.clone(), params.clone());
+    let verifier = MatrixLinearProofVerifier::new(ck.clone(), params.clone());
+
+    // the relation is proven for the transpose of G
+    let (response_ctx, mut commitment) = prover.commit(rng, g, x);
+    commitment.g = polynomial_grid(vec![vec![vec![1], vec![3]], vec![vec![2], vec![4]]]);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert_eq!(
+        verifier.verify_detailed(response, verification_ctx),
+        Err(VerifyError::SecondEquationMismatch)
+    );
+
+    // G of a wrong dimension
+    let g = polynomial_grid(vec![vec![vec![1], vec![2]], vec![vec![3], vec![4]]]);
+    let (response_ctx, mut commitment) =
+        prover.commit(rng, g, params.prepare_value::<N>(vec![vec![1], vec![2]]));
+    commitment.g = polynomial_grid(vec![vec![vec![1], vec![2]]]);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert_eq!(
+        verifier.verify_detailed(response, verification_ctx),
+        Err(VerifyError::LengthMismatch {
+            expected: 2,
+            got: 1
+        })
+    );
+}
+
+/// Test the equality proof by generating random inputs over numerous iterations.
+#[test]
+fn test_equality_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..100 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let (opening_a, c_a) = ck.commit(rng, x.clone(), &params);
+        let (opening_b, c_b) = ck.commit(rng, x, &params);
+
+        let prover = EqualityProofProver::new(ck.clone(), params.clone());
+        let verifier = EqualityProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit(rng, (opening_a, c_a), (opening_b, c_b));
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that the equality proof fails if one of the commitments is replaced by a commitment to another value.
+#[test]
+fn test_equality_proof_different_values() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let other = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let (opening_a, c_a) = ck.commit(rng, x.clone(), &params);
+    let (opening_b, c_b) = ck.commit(rng, x, &params);
+    let (_, c_other) = ck.commit(rng, other, &params);
+
+    let prover = EqualityProofProver::new(ck.clone(), params.clone());
+    let verifier = EqualityProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, mut commitment) = prover.commit(rng, (opening_a, c_a), (opening_b, c_b));
+    commitment.c_b = c_other;
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test the product proof by generating random inputs over numerous iterations.
+#[test]
+fn test_product_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let xp = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let xpp = vec![x[0].clone() * xp[0].clone()];
+
+        let c = ck.commit(rng, x, &params);
+        let cp = ck.commit(rng, xp, &params);
+        let cpp = ck.commit(rng, xpp, &params);
+
+        let prover = ProductProofProver::new(ck.clone(), params.clone());
+        let verifier = ProductProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit(rng, c, cp, cpp);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that the product proof fails if `x''` is replaced by a value which is not the product.
+#[test]
+fn test_product_proof_wrong_product() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2]]);
+    let xp = params.prepare_value::<N>(vec![vec![3, 4]]);
+    let xpp = params.prepare_value::<N>(vec![vec![3, 10, 8]]);
+    let wrong = params.prepare_value::<N>(vec![vec![3, 10, 9]]);
+
+    let c = ck.commit(rng, x, &params);
+    let cp = ck.commit(rng, xp, &params);
+    let cpp = ck.commit(rng, xpp, &params);
+    let (_, c_wrong) = ck.commit(rng, wrong, &params);
+
+    let prover = ProductProofProver::new(ck.clone(), params.clone());
+    let verifier = ProductProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, mut commitment) = prover.commit(rng, c, cp, cpp);
+    commitment.cpp = c_wrong;
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test that a product proof response with a wrong-length `z'` is rejected instead of panicking.
+#[test]
+fn test_product_proof_wrong_length_response() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let c = ck.commit(rng, params.prepare_value::<N>(vec![vec![2]]), &params);
+    let cp = ck.commit(rng, params.prepare_value::<N>(vec![vec![3]]), &params);
+    let cpp = ck.commit(rng, params.prepare_value::<N>(vec![vec![6]]), &params);
+
+    let prover = ProductProofProver::new(ck.clone(), params.clone());
+    let verifier = ProductProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, c, cp, cpp);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+
+    // drop the last row of z' from the response
+    let mut value = serde_json::to_value(&response).unwrap();
+    value["zp"]["polynomials"].as_array_mut().unwrap().pop();
+    let response = serde_json::from_value(value).unwrap();
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test that the prover cannot create a product proof if `x''` is not the product.
+#[test]
+#[should_panic]
+fn test_product_proof_not_product() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let c = ck.commit(rng, params.prepare_value::<N>(vec![vec![2]]), &params);
+    let cp = ck.commit(rng, params.prepare_value::<N>(vec![vec![3]]), &params);
+    let cpp = ck.commit(rng, params.prepare_value::<N>(vec![vec![7]]), &params);
+
+    let prover = ProductProofProver::new(ck.clone(), params.clone());
+    prover.commit(rng, c, cp, cpp);
+}
+
+/// Test the range proof with random values in the range.
+#[test]
+fn test_range_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    const B: usize = 16;
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![vec![rng.random_range(0..1 << B)]]);
+        let (opening, c) = ck.commit(rng, x, &params);
+
+        let prover = RangeProofProver::new(ck.clone(), params.clone());
+        let verifier = RangeProofVerifier::new(ck.clone(), params.clone(), B);
+
+        let (response_ctx, commitment) = prover.commit(rng, (opening, c), B);
+        assert_eq!(commitment.cbs.len(), B);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that the range proof fails if the verifier expects a smaller range.
+#[test]
+fn test_range_proof_wrong_bits() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![300]]);
+    let (opening, c) = ck.commit(rng, x, &params);
+
+    let prover = RangeProofProver::new(ck.clone(), params.clone());
+    let verifier = RangeProofVerifier::new(ck.clone(), params.clone(), 8);
+
+    let (response_ctx, commitment) = prover.commit(rng, (opening, c), 9);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test that the prover cannot create a range proof for a value out of the range.
+#[test]
+#[should_panic]
+fn test_range_proof_out_of_range() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![256]]);
+    let (opening, c) = ck.commit(rng, x, &params);
+
+    let prover = RangeProofProver::new(ck.clone(), params.clone());
+    prover.commit(rng, (opening, c), 8);
+}
+
+/// Test that the range proof fails if a bit commitment is replaced by a commitment to a non-bit value.
+#[test]
+fn test_range_proof_non_bit() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![5]]);
+    let (opening, c) = ck.commit(rng, x, &params);
+
+    let prover = RangeProofProver::new(ck.clone(), params.clone());
+    let verifier = RangeProofVerifier::new(ck.clone(), params.clone(), 4);
+
+    let (response_ctx, mut commitment) = prover.commit(rng, (opening, c), 4);
+    let (_, c_two) = ck.commit(rng, params.prepare_value::<N>(vec![vec![2]]), &params);
+    commitment.cbs[1] = c_two;
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test the proof of binary value for polynomials with coefficients 0 and 1.
+#[test]
+fn test_binary_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params {
+        k: 5,
+        l: 3,
+        ..Params::default()
+    };
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 0, 1, 1], vec![0], vec![0, 1]]);
+    let c = ck.commit(rng, x, &params);
+
+    let prover = BinaryProofProver::new(ck.clone(), params.clone());
+    let verifier = BinaryProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, c).unwrap();
+    assert_eq!(commitment.cbs.len(), N);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+}
+
+/// Test that the proof of binary value fails if the commitment is replaced by a commitment to a non-binary value.
+#[test]
+fn test_binary_proof_non_binary() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1]]);
+    let c = ck.commit(rng, x, &params);
+
+    let prover = BinaryProofProver::new(ck.clone(), params.clone());
+    let verifier = BinaryProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, mut commitment) = prover.commit(rng, c).unwrap();
+    let (_, c_two) = ck.commit(rng, params.prepare_value::<N>(vec![vec![2]]), &params);
+    commitment.c = c_two;
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test that the proof of binary value fails if the commitment to a coefficient is replaced.
+#[test]
+fn test_binary_proof_non_bit() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 0, 1]]);
+    let c = ck.commit(rng, x, &params);
+
+    let prover = BinaryProofProver::new(ck.clone(), params.clone());
+    let verifier = BinaryProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, mut commitment) = prover.commit(rng, c).unwrap();
+    let (_, c_two) = ck.commit(rng, params.prepare_value::<N>(vec![vec![2]]), &params);
+    commitment.cbs[2] = c_two;
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test that the prover cannot create a proof of binary value for a polynomial with a coefficient other than 0
+/// and 1.
+#[test]
+fn test_binary_proof_non_binary_coefficient() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let prover = BinaryProofProver::new(ck.clone(), params.clone());
+
+    for value in [vec![0, 2], vec![1, 0, -1]] {
+        let x = params.prepare_value::<N>(vec![value]);
+        let c = ck.commit(rng, x, &params);
+        assert_eq!(
+            prover.commit(rng, c).err(),
+            Some(RingZkError::InvalidWitness)
+        );
+    }
+}
+
+/// Test the proofs of opening, linear relation and sum with the 56-bit modulus and `ZqI128` coefficients.
+#[test]
+fn test_proofs_56bit_modulus() {
+    let rng = &mut rand::rng();
+
+    let params = Params::set_56bit_modulus();
+    assert!(
+        params.security_estimate::<1024>().bits
+            > Params::default().security_estimate::<1024>().bits
+    );
+    // messages beyond the 32-bit range of the default parameters
+    let bound = 1 << 54;
+    let ck = params.generate_commitment_key::<N>(rng);
+
+    for _ in 0..10 {
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+        let (response_ctx, commitment) = prover.commit(rng, x);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let g = params.prepare_scalar::<N>(random_value(rng, bound));
+        let proof = prove_linear(rng, &ck, &params, g, x);
+        assert!(verify_linear(&ck, &params, proof));
+
+        let xs = (0..2)
+            .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+            .collect::<Vec<_>>();
+        let gs = (0..2)
+            .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+            .collect::<Vec<_>>();
+        let prover = SumProofProver::new(ck.clone(), params.clone());
+        let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+        let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test the non-interactive open proof by generating random inputs over numerous iterations.
+#[test]
+fn test_open_proof_non_interactive() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+
+        let prover = OpenProofProver::new(ck.clone(), params.clone());
+        let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+        let (opening, proof) = prover.prove_non_interactive(rng, x);
+        assert!(proof.commitment.c.verify(&opening, &ck, &params));
+        assert!(verifier.verify_non_interactive(proof.clone()));
+
+        // the proof is bound to its commitment
+        let other_x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let (_, other_proof) = prover.prove_non_interactive(rng, other_x);
+        let mut tampered = proof.clone();
+        tampered.commitment = other_proof.commitment;
+        assert!(!verifier.verify_non_interactive(tampered));
+    }
+}
+
+/// Test the linear proof of the affine relation `x' = g * x + h`.
+#[test]
+fn test_linear_proof_affine() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+
+    for _ in 0..10 {
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let g = params.prepare_scalar::<N>(random_value(rng, bound));
+        let h = params.prepare_scalar::<N>(random_value(rng, bound));
+
+        let (response_ctx, commitment) = prover.commit_affine(rng, g.clone(), h.clone(), x.clone());
+        assert_eq!(commitment.h, h);
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+
+        // the committed x' = g * x does not satisfy x' = g * x + h for a non-zero h.
+        let (response_ctx, mut commitment) = prover.commit(rng, g, x);
+        commitment.h = h;
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert_eq!(
+            verifier.verify_detailed(response, verification_ctx),
+            Err(VerifyError::SecondEquationMismatch)
+        );
+    }
+}
+
+/// Test the one-shot linear proof by the Fiat-Shamir transform.
+#[test]
+fn test_linear_proof_non_interactive() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let g = params.prepare_scalar::<N>(random_value(rng, bound));
+
+        let proof = prove_linear(rng, &ck, &params, g.clone(), x.clone());
+        assert!(verify_linear(&ck, &params, proof.clone()));
+
+        let prover = LinearProofProver::new(ck.clone(), params.clone());
+        let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+        let (opening, opening_p, proof) = prover.prove_non_interactive(rng, g.clone(), x);
+        assert!(proof.commitment.c.verify(&opening, &ck, &params));
+        assert!(proof.commitment.cp.verify(&opening_p, &ck, &params));
+        assert!(verifier.verify_non_interactive(proof.clone()));
+
+        // the proof is bound to its commitment
+        let mut tampered = proof.clone();
+        tampered.commitment.g = params.prepare_scalar::<N>(random_value(rng, bound));
+        assert!(!verify_linear(&ck, &params, tampered));
+    }
+}
+
+/// Test that the challenges exposed by the messages, the contexts and the proofs agree.
+#[test]
+fn test_challenge_accessors() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let g = params.prepare_scalar::<N>(vec![5, 6]);
+    let norm_1 = |d: &Polynomial<ZqI64<3515337053>, N>| {
+        d.iter().map(|c| i64::from(c.clone()).abs()).sum::<i64>()
+    };
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    let (_, commitment) = prover.commit(rng, x.clone());
+    let (context, challenge) = verifier.generate_challenge(rng, commitment);
+    assert_eq!(challenge.challenge(), context.challenge());
+    assert_eq!(norm_1(challenge.challenge()), params.kappa.min(N) as i64);
+
+    // the Fiat-Shamir challenge is deterministic given the commitment.
+    let (_, proof) = prover.prove_non_interactive(rng, x.clone());
+    assert_eq!(proof.challenge(), &proof.recompute_challenge(&params));
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    let (_, commitment) = prover.commit(rng, g.clone(), x.clone());
+    let (context, challenge) = verifier.generate_challenge(rng, commitment);
+    assert_eq!(challenge.challenge(), context.challenge());
+    let proof = prove_linear(rng, &ck, &params, g.clone(), x.clone());
+    assert_eq!(norm_1(proof.challenge()), params.kappa.min(N) as i64);
+
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+    let (_, commitment) = prover.commit(rng, vec![g], vec![x]);
+    let (context, challenge) = verifier.generate_challenge(rng, commitment);
+    assert_eq!(challenge.challenge(), context.challenge());
+}
+
+/// Test that the non-interactive open proof is deterministic given the same randomness.
+#[test]
+fn test_open_proof_non_interactive_deterministic() {
+    let params = Params::default();
+    let ck = params.generate_commitment_key(&mut rand::rng());
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+
+    let (_, proof1) = prover.prove_non_interactive(&mut StdRng::seed_from_u64(7), x.clone());
+    let (_, proof2) = prover.prove_non_interactive(&mut StdRng::seed_from_u64(7), x);
+    assert_eq!(proof1, proof2);
+}
+
+/// Test that the commitments of the proofs are reproducible given the same seed, and the proofs still verify.
+#[test]
+fn test_commit_with_seed() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let g = params.prepare_scalar::<N>(vec![5, 6]);
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let (_, commitment1) = prover.commit_with_seed([1; 32], x.clone());
+    let (_, commitment2) = prover.commit_with_seed([1; 32], x.clone());
+    let (_, commitment3) = prover.commit_with_seed([2; 32], x.clone());
+    assert_eq!(commitment1, commitment2);
+    assert_ne!(commitment1, commitment3);
+
+    let prover = LinearProofProver::new(ck.clone(), params.clone());
+    let verifier = LinearProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment1) = prover.commit_with_seed([1; 32], g.clone(), x.clone());
+    let (_, commitment2) = prover.commit_with_seed([1; 32], g.clone(), x.clone());
+    assert_eq!(commitment1, commitment2);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment1);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(verifier.verify(response, verification_ctx));
+
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let (_, commitment1) = prover.commit_with_seed([1; 32], vec![g.clone()], vec![x.clone()]);
+    let (_, commitment2) = prover.commit_with_seed([1; 32], vec![g], vec![x]);
+    assert_eq!(commitment1, commitment2);
+}
+
+/// Test the non-interactive open proof with the retry loop.
+#[test]
+fn test_open_proof_repeated() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let (response_ctx, commitment) = prover.commit_repeated(rng, x.clone(), 3);
+    assert_eq!(commitment.repetitions(), 3);
+    assert!(commitment.c.verify(&response_ctx.opening, &ck, &params));
+    let (verification_ctx, challenge) =
+        verifier.generate_challenge_repeated(rng, serde_round_trip(&commitment));
+    assert_eq!(challenge.challenges().len(), 3);
+    assert_eq!(challenge.challenges(), verification_ctx.challenges());
+    let response = prover.create_response_repeated(response_ctx, serde_round_trip(&challenge));
+    let response = serde_round_trip(&response);
+    assert!(verifier.verify_repeated(response.clone(), verification_ctx.clone()));
+
+    // the responses do not answer other challenges.
+    let (other_ctx, _) = verifier.generate_challenge_repeated(rng, commitment);
+    assert!(!verifier.verify_repeated(response.clone(), other_ctx));
+
+    // every run must be answered.
+    let mut json = serde_json::to_value(&response).unwrap();
+    json["zs"].as_array_mut().unwrap().pop();
+    let truncated = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        verifier.verify_repeated_detailed(truncated, verification_ctx),
+        Err(VerifyError::LengthMismatch {
+            expected: 3,
+            got: 2
+        })
+    );
+
+    // non-interactive
+    let (opening, proof) = prover.prove_non_interactive_repeated(rng, x, 3);
+    assert!(proof.commitment.c.verify(&opening, &ck, &params));
+    assert_eq!(proof.challenges().len(), 3);
+    assert!(verifier.verify_non_interactive_repeated(serde_round_trip(&proof)));
+
+    // the challenges are bound to the commitment.
+    let mut json = serde_json::to_value(&proof).unwrap();
+    json["ds"].as_array_mut().unwrap().swap(0, 1);
+    let tampered = serde_json::from_value(json).unwrap();
+    assert!(!verifier.verify_non_interactive_repeated(tampered));
+}
+
+/// A sampler whose first `wide_polynomials` masking polynomials have a standard deviation 8 times larger,
+/// so that the responses using them exceed the norm bound and the prover has to retry.
+struct WideSampler {
+    wide_polynomials: Cell<usize>,
+}
+
+impl GaussianSampler for WideSampler {
+    fn sample(&self, rng: &mut impl RngExt, sigma: f64) -> i64 {
+        DiscreteGaussianSampler.sample(rng, sigma)
+    }
+
+    fn sample_polynomial<I, const N: usize>(
+        &self,
+        rng: &mut impl RngExt,
+        sigma: f64,
+    ) -> Polynomial<I, N>
+    where
+        I: Clone + One + Zero + FromPrimitive,
+    {
+        let wide = self.wide_polynomials.get();
+        self.wide_polynomials.set(wide.saturating_sub(1));
+        let sigma = if wide > 0 { 8.0 * sigma } else { sigma };
+        Polynomial::new(
+            (0..N)
+                .map(|_| I::from_i64(self.sample(rng, sigma)).unwrap())
+                .collect(),
+        )
+    }
+}
+
+#[test]
+fn test_open_proof_with_retry() {
+    let rng = &mut StdRng::seed_from_u64(3);
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    // the masking polynomials of the first attempt are too wide, so exactly one retry is needed.
+    let sampler = WideSampler {
+        wide_polynomials: Cell::new(params.k),
+    };
+    let prover = OpenProofProver::with_sampler(ck.clone(), params.clone(), sampler);
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let (opening, proof, attempts) = prover
+        .prove_with_retry(rng, x, &RetryConfig::default())
+        .unwrap();
+    assert_eq!(attempts, 2);
+    assert!(proof.commitment.c.verify(&opening, &ck, &params));
+    assert!(verifier.verify_non_interactive(proof));
+
+    // all the attempts are too wide.
+    let sampler = WideSampler {
+        wide_polynomials: Cell::new(usize::MAX),
+    };
+    let prover = OpenProofProver::with_sampler(ck.clone(), params.clone(), sampler);
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let config = RetryConfig { max_attempts: 3 };
+    assert_eq!(
+        prover.prove_with_retry(rng, x, &config),
+        Err(RingZkError::RetryExhausted { attempts: 3 })
+    );
+
+    // no attempt is allowed
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+    let config = RetryConfig { max_attempts: 0 };
+    assert_eq!(
+        prover.prove_with_retry(rng, x, &config),
+        Err(RingZkError::RetryExhausted { attempts: 0 })
+    );
+
+    // with the default sampler, the responses rarely exceed the norm bound.
+    for _ in 0..10 {
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let (opening, proof, attempts) = prover
+            .prove_with_retry(rng, x, &RetryConfig::default())
+            .unwrap();
+        assert!(attempts <= RetryConfig::default().max_attempts);
+        assert!(proof.commitment.c.verify(&opening, &ck, &params));
+        assert!(verifier.verify_non_interactive(proof));
+    }
+}
+
+/// Test the open proof with challenges generated for a batch of commitments.
+#[test]
+fn test_open_proof_generate_challenges() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctxs, commitments) = (0..5)
+        .map(|_| {
+            let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+            prover.commit(rng, x)
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let challenges = verifier.generate_challenges(rng, commitments);
+    assert_eq!(challenges.len(), 5);
+
+    response_ctxs.into_iter().zip(challenges).for_each(
+        |(response_ctx, (verification_ctx, challenge))| {
+            let response = prover.create_response(response_ctx, challenge);
+            assert!(verifier.verify(response, verification_ctx));
+        },
+    );
+}
+
+/// Test the open proof with responses verified in a batch.
+#[test]
+fn test_open_proof_verify_batch() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    let (responses, contexts) = (0..6)
+        .map(|_| {
+            let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+            let (response_ctx, commitment) = prover.commit(rng, x);
+            let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+            (
+                prover.create_response(response_ctx, challenge),
+                verification_ctx,
+            )
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    let items = responses
+        .iter()
+        .cloned()
+        .zip(contexts.iter().cloned())
+        .collect::<Vec<_>>();
+    assert!(verifier.verify_batch(rng, &items));
+    assert!(verifier.verify_batch(rng, &[]));
+
+    // responses at index 1 and 4 are swapped, so that they do not match their contexts.
+    let mut responses = responses;
+    responses.swap(1, 4);
+    let items = responses.into_iter().zip(contexts).collect::<Vec<_>>();
+    assert!(!verifier.verify_batch(rng, &items));
+}
+
+/// Test the sum proofs from multiple provers verified in a batch.
+#[test]
+fn test_sum_proof_verify_many() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = SumProofProver::new(ck.clone(), params.clone());
+    let verifier = SumProofVerifier::new(ck.clone(), params.clone());
+
+    // the proofs have different numbers of terms
+    let (responses, contexts) = (1..5)
+        .map(|vl| {
+            let xs = (0..vl)
+                .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+                .collect::<Vec<_>>();
+            let gs = (0..vl)
+                .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+                .collect::<Vec<_>>();
+            let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+            let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+            (
+                prover.create_response(response_ctx, challenge),
+                verification_ctx,
+            )
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    let items = responses
+        .iter()
+        .cloned()
+        .zip(contexts.iter().cloned())
+        .collect::<Vec<_>>();
+    assert!(verifier.verify_many(rng, &items));
+    assert!(verifier.verify_many(rng, &[]));
+
+    // a single invalid proof rejects the batch: the response of a proof of two terms is verified
+    // against the context of another proof of two terms.
+    let xs = (0..2)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect::<Vec<_>>();
+    let gs = (0..2)
+        .map(|_| params.prepare_scalar::<N>(random_value(rng, bound)))
+        .collect::<Vec<_>>();
+    let (response_ctx, commitment) = prover.commit(rng, gs, xs);
+    let (_, challenge) = verifier.generate_challenge(rng, commitment);
+    let other = prover.create_response(response_ctx, challenge);
+    let mut items = items;
+    items[1].0 = other;
+    assert!(!verifier.verify_many(rng, &items));
+
+    // a response with the wrong number of terms
+    items[1].0 = responses[2].clone();
+    assert!(!verifier.verify_many(rng, &items));
+}
+
+/// Test the open proof with responses verified in parallel.
+#[cfg(feature = "parallel")]
+#[test]
+fn test_open_proof_verify_all() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+
+    let (responses, contexts) = (0..6)
+        .map(|_| {
+            let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+            let (response_ctx, commitment) = prover.commit(rng, x);
+            let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+            (
+                prover.create_response(response_ctx, challenge),
+                verification_ctx,
+            )
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    // responses at index 1 and 4 are swapped, so that they do not match their contexts.
+    let mut responses = responses;
+    responses.swap(1, 4);
+    let items = responses.into_iter().zip(contexts).collect::<Vec<_>>();
+    assert_eq!(
+        verifier.verify_all(items),
+        vec![true, false, true, true, false, true]
+    );
+}
+
+/// Test that a response created under degree 256 is rejected by the verifier of degree 512.
+#[test]
+fn test_open_proof_verify_for_degree() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+
+    // proof under degree 256
+    let ck = params.generate_commitment_key::<256>(rng);
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    let (response_ctx, commitment) = prover.commit(rng, params.prepare_value(vec![vec![1, 2]]));
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert_eq!(response.degree(), 256);
+    assert_eq!(
+        verifier.verify_for_degree(response.clone(), verification_ctx),
+        Ok(true)
+    );
+    let bytes = bincode::serialize(&response).unwrap();
+
+    // verifier under degree 512
+    let ck = params.generate_commitment_key::<512>(rng);
+    let prover = OpenProofProver::new(ck.clone(), params.clone());
+    let verifier = OpenProofVerifier::new(ck.clone(), params.clone());
+    let (_, commitment) = prover.commit(rng, params.prepare_value(vec![vec![1, 2]]));
+    let (verification_ctx, _) = verifier.generate_challenge(rng, commitment);
+
+    let response: OpenProofResponse<_, 512> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(
+        verifier.verify_for_degree(response, verification_ctx),
+        Err(RingZkError::DegreeMismatch {
+            expected: 512,
+            got: 256
+        })
+    );
+}
+
+/// Test the set membership proof with a set of 4 values.
+#[test]
+fn test_set_membership_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+
+    let vs = (0..4)
+        .map(|_| params.prepare_value::<N>(vec![random_value(rng, bound)]))
+        .collect::<Vec<_>>();
+
+    let prover = SetMembershipProofProver::new(ck.clone(), params.clone());
+    let verifier = SetMembershipProofVerifier::new(ck.clone(), params.clone(), vs.clone());
+
+    for x in vs.iter().cloned() {
+        let (response_ctx, commitment) = prover.commit(rng, x, vs.clone());
+        assert!(commitment.c.verify(&response_ctx.opening, &ck, &params));
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that the set membership proof fails for a commitment to a non-member value.
+#[test]
+fn test_set_membership_proof_non_member() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+
+    let vs = (1..=4)
+        .map(|i| params.prepare_value::<N>(vec![vec![i, i + 1]]))
+        .collect::<Vec<_>>();
+    let non_member = params.prepare_value::<N>(vec![vec![5, 7]]);
+
+    let prover = SetMembershipProofProver::new(ck.clone(), params.clone());
+    let verifier = SetMembershipProofVerifier::new(ck.clone(), params.clone(), vs.clone());
+
+    let (response_ctx, mut commitment) = prover.commit(rng, vs[0].clone(), vs.clone());
+    // replace the commitment by a commitment to the non-member value
+    let (_, c) = ck.commit(rng, non_member, &params);
+    commitment.c = c;
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test that the set membership proof fails if the prover substitutes the public set by a set of
+/// their own, which contains the non-member value.
+#[test]
+fn test_set_membership_proof_substituted_set() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+
+    let vs = (1..=4)
+        .map(|i| params.prepare_value::<N>(vec![vec![i, i + 1]]))
+        .collect::<Vec<_>>();
+    let non_member = params.prepare_value::<N>(vec![vec![5, 7]]);
+    let substituted_vs = vec![vs[0].clone(), non_member.clone()];
+
+    let prover = SetMembershipProofProver::new(ck.clone(), params.clone());
+    let verifier = SetMembershipProofVerifier::new(ck.clone(), params.clone(), vs.clone());
+
+    let (response_ctx, commitment) = prover.commit(rng, non_member, substituted_vs);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test the offset proof by generating random inputs over numerous iterations.
+#[test]
+fn test_offset_proof() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+
+    for _ in 0..10 {
+        let ck = params.generate_commitment_key(rng);
+        let x = params.prepare_value::<N>(vec![random_value(rng, bound)]);
+        let mut h = random_value(rng, bound);
+        h[0] = 1; // nonzero offset
+        let h = params.prepare_value::<N>(vec![h]);
+
+        let prover = OffsetProofProver::new(ck.clone(), params.clone());
+        let verifier = OffsetProofVerifier::new(ck.clone(), params.clone());
+
+        let (response_ctx, commitment) = prover.commit(rng, h, x);
+        assert!(commitment.c.verify(&response_ctx.opening, &ck, &params));
+        assert!(commitment.cp.verify(&response_ctx.opening_p, &ck, &params));
+        let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+        let response = prover.create_response(response_ctx, challenge);
+        assert!(verifier.verify(response, verification_ctx));
+    }
+}
+
+/// Test that the offset proof fails if the difference of the committed values is not `h`.
+#[test]
+fn test_offset_proof_wrong_offset() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let ck = params.generate_commitment_key(rng);
+    let x = params.prepare_value::<N>(vec![vec![1, 2, 3, 4]]);
+    let h = params.prepare_value::<N>(vec![vec![1]]);
+
+    let prover = OffsetProofProver::new(ck.clone(), params.clone());
+    let verifier = OffsetProofVerifier::new(ck.clone(), params.clone());
+
+    let (response_ctx, mut commitment) = prover.commit(rng, h, x);
+    // claim a different offset
+    commitment.h = params.prepare_value::<N>(vec![vec![2]]);
+    let (verification_ctx, challenge) = verifier.generate_challenge(rng, commitment);
+    let response = prover.create_response(response_ctx, challenge);
+    assert!(!verifier.verify(response, verification_ctx));
+}
+
+/// Test evaluating a committed polynomial at a public point with the IOP adapter.
+#[test]
+fn test_iop_evaluation() {
+    let rng = &mut rand::rng();
+
+    let params = Params::default();
+    let bound = params.q.clone().into();
+    let ck = params.generate_commitment_key(rng);
+    let pcs = PolynomialCommitmentScheme::new(ck, params.clone());
+
+    for _ in 0..10 {
+        let coeffs = random_value(rng, bound);
+        let p = params.prepare_scalar::<N>(coeffs.clone());
+        let (opening, commitment) = pcs.commit(rng, p);
+
+        let point = ZqI64::from(rng.random_range(-bound..=bound));
+        let (eval, proof) = pcs.open(rng, &opening, &commitment, point.clone());
+        let expected = coeffs
+            .iter()
+            .rev()
+            .fold(ZqI64::zero(), |acc, &c| &(&acc * &point) + &ZqI64::from(c));
+        assert_eq!(eval, expected);
+        assert!(pcs.verify(&commitment, point.clone(), eval.clone(), proof.clone()));
+        let wrong_eval = &eval + &ZqI64::one();
+        assert!(!pcs.verify(&commitment, point.clone(), wrong_eval, proof.clone()));
+        let wrong_point = &point + &ZqI64::one();
+        assert!(!pcs.verify(&commitment, wrong_point, eval, proof));
+    }
+}
+
+fn serde_round_trip<T>(value: &T) -> T
+where
+    T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let decoded = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, &decoded);
+    decoded
+}
+
+/// Returns `value` with `modulus` added to and subtracted from its integers under the field `field`
+/// alternately, i.e. the coefficients are replaced by other representatives of the same residues.
+fn shift_representatives<T>(value: &T, field: &str, modulus: i64) -> T
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn shift(value: &mut serde_json::Value, modulus: i64, sign: &mut i64) {
+        match value {
+            serde_json::Value::Number(c) => {
+                *value = (c.as_i64().unwrap() + *sign * modulus).into();
+                *sign = -*sign;
+            }
+            serde_json::Value::Array(values) => {
+                values.iter_mut().for_each(|v| shift(v, modulus, sign))
+            }
+            serde_json::Value::Object(fields) => {
+                fields.values_mut().for_each(|v| shift(v, modulus, sign))
+            }
+            _ => {}
+        }
+    }
+
+    let mut json = serde_json::to_value(value).unwrap();
+    shift(&mut json[field], modulus, &mut 1);
+    serde_json::from_value(json).unwrap()
+}
+
+pub(crate) fn random_value(rng: &mut impl RngExt, bound: i64) -> Vec<i64> {
+    let range = CoeffsRangeInclusive::from(-bound..=bound);
+    let p: Polynomial<i64, N> = rng.random_range(range);
     p.iter().copied().take(rng.random_range(1..=N)).collect()
 }
+
+/// Returns the matrix of polynomials with the coefficients `grid`.
+fn polynomial_grid(grid: Vec<Vec<Vec<i64>>>) -> Mat<ZqI64<3515337053>, N> {
+    Mat::from_coeffs_grid(
+        grid.into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|p| p.into_iter().map(ZqI64::from).collect())
+                    .collect()
+            })
+            .collect(),
+    )
+}